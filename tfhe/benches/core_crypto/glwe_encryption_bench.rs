@@ -0,0 +1,80 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use tfhe::core_crypto::prelude::*;
+
+criterion_group!(glwe_encryption_group, glwe_encryption::<u64>);
+
+criterion_main!(glwe_encryption_group);
+
+// (GlweDimension, PolynomialSize) pairs found in the PBS parameters
+const BENCH_GLWE_PARAMS: [(GlweDimension, PolynomialSize); 6] = [
+    (GlweDimension(1), PolynomialSize(1024)),
+    (GlweDimension(1), PolynomialSize(2048)),
+    (GlweDimension(1), PolynomialSize(4096)),
+    (GlweDimension(1), PolynomialSize(8192)),
+    (GlweDimension(2), PolynomialSize(1024)),
+    (GlweDimension(3), PolynomialSize(512)),
+];
+
+fn glwe_encryption<Scalar: UnsignedTorus>(c: &mut Criterion) {
+    let bench_name = "core_crypto::glwe_encryption";
+    let mut bench_group = c.benchmark_group(bench_name);
+
+    // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    // computations
+    let glwe_noise_distribution = DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.00000000000000029403601535432533,
+    ));
+    let ciphertext_modulus = CiphertextModulus::new_native();
+
+    // Create the PRNG once, it is reused by all the encryptions
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut encryption_generator =
+        EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+
+    for (glwe_dimension, polynomial_size) in BENCH_GLWE_PARAMS {
+        let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut secret_generator,
+        );
+
+        let plaintext_list = PlaintextList::new(
+            Scalar::ONE << (Scalar::BITS - 5),
+            PlaintextCount(polynomial_size.0),
+        );
+
+        let mut glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        // Report the number of plaintexts encrypted per second
+        bench_group.throughput(Throughput::Elements(polynomial_size.0 as u64));
+
+        let id = format!(
+            "{bench_name}::u{}_glwe_dimension_{}_polynomial_size_{}",
+            Scalar::BITS,
+            glwe_dimension.0,
+            polynomial_size.0
+        );
+        bench_group.bench_function(&id, |b| {
+            b.iter(|| {
+                encrypt_glwe_ciphertext(
+                    &glwe_secret_key,
+                    &mut glwe,
+                    &plaintext_list,
+                    glwe_noise_distribution,
+                    &mut encryption_generator,
+                );
+                black_box(&mut glwe);
+            })
+        });
+    }
+
+    bench_group.finish();
+}
@@ -0,0 +1,122 @@
+//! Feed arbitrary byte streams to the TUniform sampling of every unsigned integer type and check
+//! the samples never leave the interval of the distribution.
+
+#![no_main]
+
+use concrete_csprng::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, RandomGenerator as ByteRandomGenerator,
+};
+use concrete_csprng::seeders::Seed;
+use libfuzzer_sys::fuzz_target;
+use std::cell::RefCell;
+use tfhe::core_crypto::commons::math::random::{RandomGenerable, RandomGenerator, TUniform};
+use tfhe::core_crypto::commons::numeric::UnsignedInteger;
+
+thread_local! {
+    // RandomGenerator builds its byte generator from a Seed only, the fuzzer input is handed to
+    // MockGenerator::new through this buffer
+    static FUZZ_BYTES: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A byte generator replaying the fuzzer input in place of the CSPRNG output.
+struct MockGenerator {
+    bytes: std::vec::IntoIter<u8>,
+}
+
+impl Iterator for MockGenerator {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        self.bytes.next()
+    }
+}
+
+impl ByteRandomGenerator for MockGenerator {
+    type ChildrenIter = std::vec::IntoIter<Self>;
+
+    fn new(_seed: Seed) -> Self {
+        let bytes = FUZZ_BYTES.with(|fuzz_bytes| fuzz_bytes.take());
+
+        Self {
+            bytes: bytes.into_iter(),
+        }
+    }
+
+    fn remaining_bytes(&self) -> ByteCount {
+        ByteCount(self.bytes.len() as u128)
+    }
+
+    fn try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ChildrenIter, ForkError> {
+        if n_children.0 == 0 {
+            return Err(ForkError::ZeroChildrenCount);
+        }
+        if n_bytes.0 == 0 {
+            return Err(ForkError::ZeroBytesPerChild);
+        }
+        match n_children.0.checked_mul(n_bytes.0) {
+            Some(total_bytes) if total_bytes <= self.bytes.len() => {}
+            _ => return Err(ForkError::ForkTooLarge),
+        }
+
+        let children: Vec<_> = (0..n_children.0)
+            .map(|_| Self {
+                bytes: self
+                    .bytes
+                    .by_ref()
+                    .take(n_bytes.0)
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            })
+            .collect();
+
+        Ok(children.into_iter())
+    }
+}
+
+fn check_t_uniform_samples_in_interval<T>(data: &[u8])
+where
+    T: UnsignedInteger + RandomGenerable<TUniform<T>>,
+{
+    // The first byte selects the bound, the other ones are the random stream
+    let Some((&bound_selector, random_bytes)) = data.split_first() else {
+        return;
+    };
+
+    let max_bound_log2 = T::BITS as u32 - 2;
+    let bound_log2 = u32::from(bound_selector) % (max_bound_log2 + 1);
+    let distribution = TUniform::<T>::new(bound_log2);
+    let (min_value, max_value) = distribution.interval();
+    let bytes_per_sample = distribution.bytes_per_sample();
+
+    FUZZ_BYTES.with(|fuzz_bytes| *fuzz_bytes.borrow_mut() = random_bytes.to_vec());
+    let mut generator = RandomGenerator::<MockGenerator>::new(Seed(0));
+
+    for _ in 0..random_bytes.len() / bytes_per_sample {
+        let sample = T::generate_one(&mut generator, distribution).into_signed();
+
+        assert!(
+            min_value <= sample && sample <= max_value,
+            "TUniform<u{}> sample {sample:?} with bound_log2 {bound_log2} is outside of \
+            [{min_value:?}, {max_value:?}]",
+            T::BITS,
+        );
+    }
+
+    // Each sample consumes exactly bytes_per_sample bytes
+    assert_eq!(
+        generator.remaining_bytes(),
+        Some(random_bytes.len() % bytes_per_sample)
+    );
+}
+
+fuzz_target!(|data: &[u8]| {
+    check_t_uniform_samples_in_interval::<u8>(data);
+    check_t_uniform_samples_in_interval::<u16>(data);
+    check_t_uniform_samples_in_interval::<u32>(data);
+    check_t_uniform_samples_in_interval::<u64>(data);
+    check_t_uniform_samples_in_interval::<u128>(data);
+});
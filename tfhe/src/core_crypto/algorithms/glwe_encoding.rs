@@ -0,0 +1,255 @@
+//! Module containing primitives pertaining to the encoding of messages in [`PlaintextList`] meant
+//! to be encrypted in a [`GLWE ciphertext`](`GlweCiphertext`), and to their decoding after
+//! decryption.
+
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, PlaintextCount,
+};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// An encoder placing messages in the most significant bits of a [`PlaintextList`] over the native
+/// `u64` modulus.
+///
+/// A message `m` is encoded as `m * delta`, decoding rounds the decrypted value to the closest
+/// multiple of `delta` before removing the scaling. Both `message_modulus` and `delta` are powers
+/// of two, the bits between `message_modulus * delta` and `2^64` are padding bits.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // 4 bits of message with one bit of padding
+/// let encoder = GlweEncoder::new_with_padding(16);
+///
+/// // Create the plaintext
+/// let messages: Vec<u64> = (0..polynomial_size.0 as u64).map(|x| x % 16).collect();
+/// let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// encoder.encode_into(&mut plaintext_list, &messages);
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &glwe, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// let decoded = encoder.decode(&output_plaintext_list);
+///
+/// // Check we recovered the original messages
+/// assert_eq!(decoded, messages);
+/// ```
+///
+/// # Noise bound
+///
+/// Decoding rounds with a [`SignedDecomposer`] keeping the `64 - delta.ilog2()` most significant
+/// bits, it recovers a message exactly as long as the decryption error is strictly smaller than
+/// `delta / 2` in absolute value.
+///
+/// A fresh encryption with a
+/// [`TUniform`](`crate::core_crypto::commons::math::random::TUniform`) noise distribution of
+/// `bound_log2` $b$ has an error in $\left[-2^b, 2^b\right]$, decoding is therefore guaranteed to
+/// be exact as long as `bound_log2 + 1 < delta.ilog2()`, i.e. `bound_log2 + 2 <= 64 - base_log`
+/// for the decomposer base log `base_log`. Contrary to a
+/// [`Gaussian`](`crate::core_crypto::commons::math::random::Gaussian`) noise this holds with
+/// probability 1.
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // 4 bits of message with one bit of padding, delta = 2^59
+/// let encoder = GlweEncoder::new_with_padding(16);
+/// let delta_log = encoder.delta.ilog2();
+///
+/// // Largest bound for which decoding is always exact
+/// let glwe_noise_distribution = TUniform::<u64>::new(delta_log - 2);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let messages: Vec<u64> = (0..polynomial_size.0 as u64).map(|x| x % 16).collect();
+/// let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// encoder.encode_into(&mut plaintext_list, &messages);
+///
+/// let mut glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &glwe, &mut output_plaintext_list);
+///
+/// // The decomposer used by decode works on the 64 - delta_log = 5 most significant bits
+/// let decomposer = SignedDecomposer::new(
+///     DecompositionBaseLog((u64::BITS - delta_log) as usize),
+///     DecompositionLevelCount(1),
+/// );
+/// for (decrypted, plaintext) in output_plaintext_list.iter().zip(plaintext_list.iter()) {
+///     assert_eq!(decomposer.closest_representable(*decrypted.0), *plaintext.0);
+/// }
+///
+/// assert_eq!(encoder.decode(&output_plaintext_list), messages);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GlweEncoder {
+    pub message_modulus: u64,
+    pub delta: u64,
+}
+
+impl GlweEncoder {
+    /// Create a new [`GlweEncoder`] from a message modulus and the scaling factor `delta`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `message_modulus` or `delta` is not a power of two, if `delta` is 1 as no rounding
+    /// would be possible or if `message_modulus * delta` does not fit in the native `u64` modulus.
+    pub fn new(message_modulus: u64, delta: u64) -> Self {
+        assert!(
+            message_modulus.is_power_of_two(),
+            "message_modulus must be a power of two, got {message_modulus}"
+        );
+        assert!(
+            delta.is_power_of_two() && delta > 1,
+            "delta must be a power of two greater than 1, got {delta}"
+        );
+        assert!(
+            message_modulus as u128 * delta as u128 <= 1u128 << u64::BITS,
+            "message_modulus ({message_modulus}) * delta ({delta}) does not fit in a u64"
+        );
+
+        Self {
+            message_modulus,
+            delta,
+        }
+    }
+
+    /// Create a new [`GlweEncoder`] for the given message modulus, keeping the most significant bit
+    /// as a padding bit.
+    pub fn new_with_padding(message_modulus: u64) -> Self {
+        Self::new(message_modulus, (1u64 << (u64::BITS - 1)) / message_modulus)
+    }
+
+    fn decomposer(&self) -> SignedDecomposer<u64> {
+        // Round on all the bits above delta, so that rounding also covers the padding bits
+        SignedDecomposer::new(
+            DecompositionBaseLog((u64::BITS - self.delta.ilog2()) as usize),
+            DecompositionLevelCount(1),
+        )
+    }
+
+    /// Encode `messages` in the `output` [`PlaintextList`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of messages differs from the [`PlaintextCount`] of `output` or if a
+    /// message is not smaller than the message modulus.
+    pub fn encode_into<OutputCont>(&self, output: &mut PlaintextList<OutputCont>, messages: &[u64])
+    where
+        OutputCont: ContainerMut<Element = u64>,
+    {
+        assert_eq!(
+            output.plaintext_count().0,
+            messages.len(),
+            "Mismatched PlaintextCount between output ({:?}) and messages ({:?})",
+            output.plaintext_count(),
+            PlaintextCount(messages.len()),
+        );
+
+        for (plaintext, &message) in output.iter_mut().zip(messages.iter()) {
+            assert!(
+                message < self.message_modulus,
+                "message ({message}) is not smaller than message_modulus ({})",
+                self.message_modulus
+            );
+            *plaintext.0 = message * self.delta;
+        }
+    }
+
+    /// Decode the messages stored in the `input` [`PlaintextList`], rounding to the closest encoded
+    /// value.
+    pub fn decode<InputCont>(&self, input: &PlaintextList<InputCont>) -> Vec<u64>
+    where
+        InputCont: Container<Element = u64>,
+    {
+        let decomposer = self.decomposer();
+        let delta_log = self.delta.ilog2();
+
+        input
+            .iter()
+            .map(|plaintext| {
+                (decomposer.closest_representable(*plaintext.0) >> delta_log) % self.message_modulus
+            })
+            .collect()
+    }
+}
@@ -1,15 +1,83 @@
 //! Module containing primitives pertaining to [`GLWE ciphertext
 //! encryption`](`GlweCiphertext#glwe-encryption`).
+//!
+//! # Dimension checks
+//!
+//! The checks on the dimensions of the keys, ciphertexts and plaintexts passed to the functions of
+//! this module are always performed, unless the `skip-dimension-checks` feature is enabled, in
+//! which case they are only done in debug builds and the caller must make sure that:
+//!
+//! - the [`GlweDimension`] and [`PolynomialSize`] of the [`GlweSecretKey`] match the ones of the
+//!   ciphertexts;
+//! - the [`PlaintextCount`] of the plaintext lists, or the length of raw input slices, match the
+//!   [`PolynomialSize`] (times the [`GlweCiphertextCount`] for lists) of the ciphertexts.
+//!
+//! Breaking these invariants does not cause undefined behavior but yields wrong results or panics
+//! further down the computation. The checks on the ciphertext moduli are always performed.
+//!
+//! # Keyswitching
+//!
+//! There is no GLWE to GLWE keyswitch in this crate. Packing keyswitches, producing a
+//! [`GlweCiphertext`] from LWE ciphertexts, are provided by
+//! [`keyswitch_lwe_ciphertext_into_glwe_ciphertext`](`super::lwe_packing_keyswitch::keyswitch_lwe_ciphertext_into_glwe_ciphertext`)
+//! and
+//! [`keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext`](`super::lwe_packing_keyswitch::keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext`).
 
+use crate::core_crypto::algorithms::glwe_encoding::GlweEncoder;
+use crate::core_crypto::algorithms::glwe_linear_algebra::glwe_ciphertext_add_assign;
+use crate::core_crypto::algorithms::glwe_sample_extraction::extract_lwe_sample_from_glwe_ciphertext;
 use crate::core_crypto::algorithms::polynomial_algorithms::*;
 use crate::core_crypto::algorithms::slice_algorithms::{
-    slice_wrapping_scalar_div_assign, slice_wrapping_scalar_mul_assign,
+    slice_wrapping_add_assign_custom_mod, slice_wrapping_scalar_div_assign,
+    slice_wrapping_scalar_mul_assign, slice_wrapping_sub, slice_wrapping_sub_custom_mod,
 };
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
-use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Distribution, Uniform};
+use crate::core_crypto::commons::math::random::{
+    ActivatedRandomGenerator, Distribution, RandomGenerable, Uniform,
+};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::utils::dimension_assert;
 use crate::core_crypto::entities::*;
+use rayon::prelude::*;
+
+/// Add the multisum of the mask and the secret key polynomials to the body of a GLWE ciphertext.
+///
+/// With the `parallel-glwe-multisum` feature the products are computed across threads for large
+/// parameter sets, where they dominate the cost of the encryption.
+fn glwe_body_wrapping_add_mask_key_multisum_assign<Scalar, BodyCont, MaskCont, KeyCont>(
+    output_body: &mut GlweBody<BodyCont>,
+    mask: &GlweMask<MaskCont>,
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+) where
+    Scalar: UnsignedInteger,
+    BodyCont: ContainerMut<Element = Scalar>,
+    MaskCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    #[cfg(feature = "parallel-glwe-multisum")]
+    {
+        const PAR_MULTISUM_MIN_GLWE_DIMENSION: GlweDimension = GlweDimension(4);
+        const PAR_MULTISUM_MIN_POLYNOMIAL_SIZE: PolynomialSize = PolynomialSize(2048);
+
+        if glwe_secret_key.glwe_dimension().0 >= PAR_MULTISUM_MIN_GLWE_DIMENSION.0
+            && glwe_secret_key.polynomial_size().0 >= PAR_MULTISUM_MIN_POLYNOMIAL_SIZE.0
+        {
+            par_polynomial_wrapping_add_multisum_assign(
+                &mut output_body.as_mut_polynomial(),
+                &mask.as_polynomial_list(),
+                &glwe_secret_key.as_polynomial_list(),
+            );
+            return;
+        }
+    }
+
+    polynomial_wrapping_add_multisum_assign(
+        &mut output_body.as_mut_polynomial(),
+        &mask.as_polynomial_list(),
+        &glwe_secret_key.as_polynomial_list(),
+    );
+}
 
 /// Convenience function to share the core logic of the GLWE assign encryption between all functions
 /// needing it.
@@ -60,11 +128,7 @@ pub fn fill_glwe_mask_and_body_for_encryption_assign<
         slice_wrapping_scalar_mul_assign(output_body.as_mut(), torus_scaling);
     }
 
-    polynomial_wrapping_add_multisum_assign(
-        &mut output_body.as_mut_polynomial(),
-        &output_mask.as_polynomial_list(),
-        &glwe_secret_key.as_polynomial_list(),
-    );
+    glwe_body_wrapping_add_mask_key_multisum_assign(output_body, output_mask, glwe_secret_key);
 }
 
 /// Variant of [`encrypt_glwe_ciphertext`] which assumes that the plaintexts to encrypt are already
@@ -154,14 +218,14 @@ pub fn encrypt_glwe_ciphertext_assign<Scalar, NoiseDistribution, KeyCont, Output
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
-    assert!(
+    dimension_assert!(
         output.glwe_size().to_glwe_dimension() == glwe_secret_key.glwe_dimension(),
         "Mismatch between GlweDimension of output ciphertext and input secret key. \
         Got {:?} in output, and {:?} in secret key.",
         output.glwe_size().to_glwe_dimension(),
         glwe_secret_key.glwe_dimension()
     );
-    assert!(
+    dimension_assert!(
         output.polynomial_size() == glwe_secret_key.polynomial_size(),
         "Mismatch between PolynomialSize of output ciphertext and input secret key. \
         Got {:?} in output, and {:?} in secret key.",
@@ -180,6 +244,184 @@ pub fn encrypt_glwe_ciphertext_assign<Scalar, NoiseDistribution, KeyCont, Output
     );
 }
 
+/// Variant of [`encrypt_glwe_ciphertext_assign`] using the provided `input_mask` instead of sampling
+/// a fresh one.
+///
+/// The plaintexts to encrypt are expected to already be loaded in the body of the output
+/// [`GLWE ciphertext`](`GlweCiphertext`), only the noise is sampled from the generator before the
+/// mask and secret key multisum is added to the body. The mask is copied as is, it must therefore
+/// be given in the same representation as the mask of a [`GlweCiphertext`] with the same
+/// [`CiphertextModulus`], e.g. taken from another ciphertext.
+///
+/// No random bytes are consumed for the mask, which matters when the generator is shared with
+/// seeded encryptions.
+///
+/// WARNING: the security of the encryption relies on the mask being uniformly random and never
+/// reused to encrypt a different message under the same secret key.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let encoded_msg = msg << 60;
+///
+/// // Take the public mask of a reference encryption
+/// let mut reference_glwe =
+///     GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+/// encrypt_glwe_ciphertext_assign(
+///     &glwe_secret_key,
+///     &mut reference_glwe,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// // Manually fill the body with the encoded message
+/// glwe.get_mut_body().as_mut().fill(encoded_msg);
+///
+/// encrypt_glwe_ciphertext_with_mask(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &reference_glwe.get_mask(),
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// assert_eq!(glwe.get_mask().as_ref(), reference_glwe.get_mask().as_ref());
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &glwe, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0));
+///
+/// // Get the raw vector
+/// let mut cleartext_list = output_plaintext_list.into_container();
+/// // Remove the encoding
+/// cleartext_list.iter_mut().for_each(|elt| *elt = *elt >> 60);
+/// // Get the list immutably
+/// let cleartext_list = cleartext_list;
+///
+/// // Check we recovered the original message for each plaintext we encrypted
+/// cleartext_list.iter().for_each(|&elt| assert_eq!(elt, msg));
+/// ```
+pub fn encrypt_glwe_ciphertext_with_mask<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    OutputCont,
+    MaskCont,
+    Gen,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    input_mask: &GlweMask<MaskCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    MaskCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    dimension_assert!(
+        output.glwe_size().to_glwe_dimension() == glwe_secret_key.glwe_dimension(),
+        "Mismatch between GlweDimension of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output.glwe_size().to_glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    dimension_assert!(
+        output.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "Mismatch between PolynomialSize of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+    dimension_assert!(
+        input_mask.glwe_dimension() == glwe_secret_key.glwe_dimension(),
+        "Mismatch between GlweDimension of input mask and input secret key. \
+        Got {:?} in mask, and {:?} in secret key.",
+        input_mask.glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    dimension_assert!(
+        input_mask.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "Mismatch between PolynomialSize of input mask and input secret key. \
+        Got {:?} in mask, and {:?} in secret key.",
+        input_mask.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+    assert_eq!(
+        input_mask.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input_mask ({:?}) and output ({:?})",
+        input_mask.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    let ciphertext_modulus = output.ciphertext_modulus();
+
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    let (mut output_mask, mut output_body) = output.get_mut_mask_and_body();
+
+    output_mask.as_mut().copy_from_slice(input_mask.as_ref());
+    generator.unsigned_integer_slice_wrapping_add_random_noise_from_distribution_custom_mod_assign(
+        output_body.as_mut(),
+        noise_distribution,
+        ciphertext_modulus,
+    );
+
+    if !ciphertext_modulus.is_native_modulus() {
+        let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+        slice_wrapping_scalar_mul_assign(output_body.as_mut(), torus_scaling);
+    }
+
+    glwe_body_wrapping_add_mask_key_multisum_assign(
+        &mut output_body,
+        &output_mask,
+        glwe_secret_key,
+    );
+}
+
 /// Convenience function to share the core logic of the seeded GLWE assign encryption between all
 /// functions needing it.
 ///
@@ -203,14 +445,14 @@ pub fn encrypt_seeded_glwe_ciphertext_assign_with_existing_generator<
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
-    assert!(
+    dimension_assert!(
         output.glwe_size().to_glwe_dimension() == glwe_secret_key.glwe_dimension(),
         "Mismatch between GlweDimension of output ciphertext and input secret key. \
         Got {:?} in output, and {:?} in secret key.",
         output.glwe_size().to_glwe_dimension(),
         glwe_secret_key.glwe_dimension()
     );
-    assert!(
+    dimension_assert!(
         output.polynomial_size() == glwe_secret_key.polynomial_size(),
         "Mismatch between PolynomialSize of output ciphertext and input secret key. \
         Got {:?} in output, and {:?} in secret key.",
@@ -294,15 +536,115 @@ pub fn fill_glwe_mask_and_body_for_encryption<
         slice_wrapping_scalar_mul_assign(output_body.as_mut(), torus_scaling);
     }
 
-    polynomial_wrapping_add_multisum_assign(
+    glwe_body_wrapping_add_mask_key_multisum_assign(output_body, output_mask, glwe_secret_key);
+}
+
+/// Convenience function to share the core logic of the GLWE encryption between all functions
+/// needing it, for ciphertext moduli which are not compatible with the native modulus.
+pub fn fill_glwe_mask_and_body_for_encryption_other_mod<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    InputCont,
+    BodyCont,
+    MaskCont,
+    Gen,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    output_mask: &mut GlweMask<MaskCont>,
+    output_body: &mut GlweBody<BodyCont>,
+    encoded: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    BodyCont: ContainerMut<Element = Scalar>,
+    MaskCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert_eq!(
+        output_mask.ciphertext_modulus(),
+        output_body.ciphertext_modulus(),
+        "Mismatched moduli between mask ({:?}) and body ({:?})",
+        output_mask.ciphertext_modulus(),
+        output_body.ciphertext_modulus()
+    );
+
+    let ciphertext_modulus = output_body.ciphertext_modulus();
+
+    assert!(!ciphertext_modulus.is_compatible_with_native_modulus());
+
+    // generate a randomly uniform mask
+    generator
+        .fill_slice_with_random_uniform_mask_custom_mod(output_mask.as_mut(), ciphertext_modulus);
+
+    // generate an error from the given noise_distribution
+    generator.fill_slice_with_random_noise_from_distribution_custom_mod(
+        output_body.as_mut(),
+        noise_distribution,
+        ciphertext_modulus,
+    );
+
+    let ciphertext_modulus_as_scalar: Scalar = ciphertext_modulus.get_custom_modulus().cast_into();
+
+    slice_wrapping_add_assign_custom_mod(
+        output_body.as_mut(),
+        encoded.as_ref(),
+        ciphertext_modulus_as_scalar,
+    );
+
+    // Store sum(ai * si) + delta * m + e in the body
+    polynomial_wrapping_add_multisum_assign_custom_mod(
         &mut output_body.as_mut_polynomial(),
         &output_mask.as_polynomial_list(),
-        &glwe_secret_key.as_polynomial_list(),
+        &glwe_secret_key_polynomial_list_custom_mod(glwe_secret_key, ciphertext_modulus_as_scalar),
+        ciphertext_modulus_as_scalar,
     );
 }
 
+/// Return the polynomials of a [`GLWE secret key`](`GlweSecretKey`) with their coefficients
+/// reduced in `[0; custom_modulus[`.
+///
+/// Secret keys store negative coefficients with the native wrapping representation, e.g. a
+/// ternary -1 is `Scalar::MAX`, while the custom modulus arithmetic needs `custom_modulus - 1`.
+fn glwe_secret_key_polynomial_list_custom_mod<Scalar, KeyCont>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    custom_modulus: Scalar,
+) -> PolynomialListOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+{
+    let coefficients = glwe_secret_key
+        .as_ref()
+        .iter()
+        .map(|&coefficient| {
+            // Coefficients in the upper half of the native range represent negative values
+            if coefficient > Scalar::MAX >> 1 {
+                let abs_coefficient = coefficient.wrapping_neg() % custom_modulus;
+                custom_modulus.wrapping_sub(abs_coefficient) % custom_modulus
+            } else {
+                coefficient % custom_modulus
+            }
+        })
+        .collect();
+
+    PolynomialList::from_container(coefficients, glwe_secret_key.polynomial_size())
+}
+
 /// Encrypt a (scalar) plaintext list in a [`GLWE ciphertext`](`GlweCiphertext`).
 ///
+/// The secret key is not required to be binary, keys with ternary coefficients e.g. generated
+/// with [`allocate_and_generate_new_ternary_glwe_secret_key`] are supported as well.
+///
+/// `noise_distribution` can be a
+/// [`DynamicDistribution`](`crate::core_crypto::commons::math::random::DynamicDistribution`),
+/// as stored in parameter sets, the noise is then sampled from the Gaussian or TUniform
+/// distribution it holds.
+///
 /// # Formal Definition
 ///
 /// See this [`formal definition`](`GlweCiphertext#glwe-encryption`) for the definition
@@ -389,21 +731,21 @@ pub fn encrypt_glwe_ciphertext<Scalar, NoiseDistribution, KeyCont, InputCont, Ou
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
-    assert!(
+    dimension_assert!(
         output_glwe_ciphertext.polynomial_size().0 == input_plaintext_list.plaintext_count().0,
         "Mismatch between PolynomialSize of output ciphertext PlaintextCount of input. \
     Got {:?} in output, and {:?} in input.",
         output_glwe_ciphertext.polynomial_size(),
         input_plaintext_list.plaintext_count()
     );
-    assert!(
+    dimension_assert!(
         output_glwe_ciphertext.glwe_size().to_glwe_dimension() == glwe_secret_key.glwe_dimension(),
         "Mismatch between GlweDimension of output ciphertext and input secret key. \
         Got {:?} in output, and {:?} in secret key.",
         output_glwe_ciphertext.glwe_size().to_glwe_dimension(),
         glwe_secret_key.glwe_dimension()
     );
-    assert!(
+    dimension_assert!(
         output_glwe_ciphertext.polynomial_size() == glwe_secret_key.polynomial_size(),
         "Mismatch between PolynomialSize of output ciphertext and input secret key. \
         Got {:?} in output, and {:?} in secret key.",
@@ -411,23 +753,33 @@ pub fn encrypt_glwe_ciphertext<Scalar, NoiseDistribution, KeyCont, InputCont, Ou
         glwe_secret_key.polynomial_size()
     );
 
-    let (mut mask, mut body) = output_glwe_ciphertext.get_mut_mask_and_body();
-
-    fill_glwe_mask_and_body_for_encryption(
+    fill_glwe_mask_for_encryption(output_glwe_ciphertext, generator);
+    finalize_glwe_body_for_encryption(
         glwe_secret_key,
-        &mut mask,
-        &mut body,
+        output_glwe_ciphertext,
         input_plaintext_list,
         noise_distribution,
         generator,
     );
 }
 
-/// Encrypt a (scalar) plaintext list in [`GLWE ciphertexts`](`GlweCiphertext`) of the output
-/// [`GLWE ciphertext list`](`GlweCiphertextList`).
+/// Fill the mask of a [`GLWE ciphertext`](`GlweCiphertext`) with uniform random values, the first
+/// phase of [`encrypt_glwe_ciphertext`].
 ///
-/// See this [`formal definition`](`GlweCiphertext#glwe-encryption`) for the definition
-/// of the GLWE encryption algorithm.
+/// The mask only depends on the mask generator of `generator` and not on the secret key or the
+/// message, it can therefore be generated ahead of time or in another place than the body, which
+/// is then computed by [`finalize_glwe_body_for_encryption`]. The body of `output_glwe_ciphertext`
+/// is left untouched.
+///
+/// Calling both functions in that order with the same generator yields the same ciphertext as
+/// [`encrypt_glwe_ciphertext`]. When they are given different generators, the mask comes from the
+/// generator passed to this function and the noise from the one passed to
+/// [`finalize_glwe_body_for_encryption`], the caller is responsible for the randomness of both.
+///
+/// # Panics
+///
+/// Panics if the ciphertext modulus of `output_glwe_ciphertext` is not compatible with the native
+/// modulus, see [`encrypt_glwe_ciphertext_custom_mod`] for other moduli.
 ///
 /// # Example
 ///
@@ -441,7 +793,6 @@ pub fn encrypt_glwe_ciphertext<Scalar, NoiseDistribution, KeyCont, InputCont, Ou
 /// let polynomial_size = PolynomialSize(1024);
 /// let glwe_noise_distribution =
 ///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
-/// let glwe_count = GlweCiphertextCount(2);
 /// let ciphertext_modulus = CiphertextModulus::new_native();
 ///
 /// // Create the PRNG
@@ -462,9 +813,1661 @@ pub fn encrypt_glwe_ciphertext<Scalar, NoiseDistribution, KeyCont, InputCont, Ou
 /// // Create the plaintext
 /// let msg = 3u64;
 /// let encoded_msg = msg << 60;
-/// let plaintext_list = PlaintextList::new(
-///     encoded_msg,
-///     PlaintextCount(polynomial_size.0 * glwe_count.0),
+/// let plaintext_list = PlaintextList::new(encoded_msg, PlaintextCount(polynomial_size.0));
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// // First phase: generate the mask
+/// fill_glwe_mask_for_encryption(&mut glwe, &mut encryption_generator);
+///
+/// // Second phase: compute the body from the mask, the key, the message and the noise
+/// finalize_glwe_body_for_encryption(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &glwe, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// // Check we recovered the original message for each plaintext we encrypted
+/// output_plaintext_list
+///     .iter()
+///     .for_each(|elt| assert_eq!(decomposer.closest_representable(*elt.0) >> 60, msg));
+/// ```
+pub fn fill_glwe_mask_for_encryption<Scalar, OutputCont, Gen>(
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedInteger + RandomGenerable<Uniform, CustomModulus = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let ciphertext_modulus = output_glwe_ciphertext.ciphertext_modulus();
+
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "This function only supports ciphertext moduli compatible with the native modulus, \
+        got {ciphertext_modulus:?}"
+    );
+
+    let mut mask = output_glwe_ciphertext.get_mut_mask();
+
+    generator.fill_slice_with_random_uniform_mask_custom_mod(mask.as_mut(), ciphertext_modulus);
+
+    if !ciphertext_modulus.is_native_modulus() {
+        let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+        slice_wrapping_scalar_mul_assign(mask.as_mut(), torus_scaling);
+    }
+}
+
+/// Compute the body of a [`GLWE ciphertext`](`GlweCiphertext`) whose mask was filled by
+/// [`fill_glwe_mask_for_encryption`], the second phase of [`encrypt_glwe_ciphertext`].
+///
+/// The body is overwritten with the encryption of `input_plaintext_list` under the mask already
+/// stored in `output_glwe_ciphertext`, with noise sampled from the noise generator of `generator`.
+///
+/// # Panics
+///
+/// Panics if the ciphertext modulus of `output_glwe_ciphertext` is not compatible with the native
+/// modulus, see [`encrypt_glwe_ciphertext_custom_mod`] for other moduli.
+///
+/// See [`fill_glwe_mask_for_encryption`] for usage.
+pub fn finalize_glwe_body_for_encryption<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    Gen,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    dimension_assert!(
+        output_glwe_ciphertext.polynomial_size().0 == input_plaintext_list.plaintext_count().0,
+        "Mismatch between PolynomialSize of output ciphertext PlaintextCount of input. \
+    Got {:?} in output, and {:?} in input.",
+        output_glwe_ciphertext.polynomial_size(),
+        input_plaintext_list.plaintext_count()
+    );
+    dimension_assert!(
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension() == glwe_secret_key.glwe_dimension(),
+        "Mismatch between GlweDimension of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    dimension_assert!(
+        output_glwe_ciphertext.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "Mismatch between PolynomialSize of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output_glwe_ciphertext.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+
+    let ciphertext_modulus = output_glwe_ciphertext.ciphertext_modulus();
+
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "This function only supports ciphertext moduli compatible with the native modulus, \
+        got {ciphertext_modulus:?}"
+    );
+
+    let (mask, mut body) = output_glwe_ciphertext.get_mut_mask_and_body();
+
+    generator.fill_slice_with_random_noise_from_distribution_custom_mod(
+        body.as_mut(),
+        noise_distribution,
+        ciphertext_modulus,
+    );
+
+    polynomial_wrapping_add_assign(
+        &mut body.as_mut_polynomial(),
+        &input_plaintext_list.as_polynomial(),
+    );
+
+    if !ciphertext_modulus.is_native_modulus() {
+        let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+        slice_wrapping_scalar_mul_assign(body.as_mut(), torus_scaling);
+    }
+
+    glwe_body_wrapping_add_mask_key_multisum_assign(&mut body, &mask, glwe_secret_key);
+}
+
+/// Encrypt a (scalar) plaintext list in a [`GLWE ciphertext`](`GlweCiphertext`) using the given
+/// error polynomial instead of sampling it.
+///
+/// The mask is sampled from the mask generator of `generator` exactly like
+/// [`encrypt_glwe_ciphertext`] does, the noise generator is not used. Given a fixed mask seed and
+/// fixed noise the output ciphertext is therefore fully determined, which allows known-answer
+/// tests.
+///
+/// `input_noise_list` uses the same representation as the input plaintexts, i.e. before any
+/// scaling to the native torus for non native power of 2 moduli, like the noise returned by
+/// `encrypt_glwe_ciphertext_and_return_noise` with the `noise-analysis` feature.
+///
+/// This is not a secure encryption unless the noise is sampled from an appropriate distribution,
+/// it is only meant for testing.
+///
+/// # Panics
+///
+/// Panics if the ciphertext modulus of `output_glwe_ciphertext` is not compatible with the native
+/// modulus, see [`encrypt_glwe_ciphertext_custom_mod`] for other moduli.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let encoded_msg = msg << 60;
+/// let plaintext_list = PlaintextList::new(encoded_msg, PlaintextCount(polynomial_size.0));
+///
+/// // The error polynomial to use, e.g. taken from a test vector
+/// let noise_list = PlaintextList::from_container(
+///     (0..polynomial_size.0 as u64)
+///         .map(|i| (i % 16).wrapping_sub(8))
+///         .collect::<Vec<_>>(),
+/// );
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext_with_noise(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     &noise_list,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &glwe, &mut output_plaintext_list);
+///
+/// // The decryption error is exactly the provided noise
+/// for ((decrypted, plaintext), noise) in output_plaintext_list
+///     .iter()
+///     .zip(plaintext_list.iter())
+///     .zip(noise_list.iter())
+/// {
+///     assert_eq!(decrypted.0.wrapping_sub(*plaintext.0), *noise.0);
+/// }
+/// ```
+pub fn encrypt_glwe_ciphertext_with_noise<Scalar, KeyCont, InputCont, NoiseCont, OutputCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    input_noise_list: &PlaintextList<NoiseCont>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedInteger + RandomGenerable<Uniform, CustomModulus = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    NoiseCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    dimension_assert!(
+        output_glwe_ciphertext.polynomial_size().0 == input_plaintext_list.plaintext_count().0,
+        "Mismatch between PolynomialSize of output ciphertext PlaintextCount of input. \
+    Got {:?} in output, and {:?} in input.",
+        output_glwe_ciphertext.polynomial_size(),
+        input_plaintext_list.plaintext_count()
+    );
+    dimension_assert!(
+        output_glwe_ciphertext.polynomial_size().0 == input_noise_list.plaintext_count().0,
+        "Mismatch between PolynomialSize of output ciphertext PlaintextCount of input noise. \
+        Got {:?} in output, and {:?} in input noise.",
+        output_glwe_ciphertext.polynomial_size(),
+        input_noise_list.plaintext_count()
+    );
+    dimension_assert!(
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension() == glwe_secret_key.glwe_dimension(),
+        "Mismatch between GlweDimension of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    dimension_assert!(
+        output_glwe_ciphertext.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "Mismatch between PolynomialSize of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output_glwe_ciphertext.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+
+    fill_glwe_mask_for_encryption(output_glwe_ciphertext, generator);
+
+    let ciphertext_modulus = output_glwe_ciphertext.ciphertext_modulus();
+
+    let (mask, mut body) = output_glwe_ciphertext.get_mut_mask_and_body();
+
+    body.as_mut().copy_from_slice(input_noise_list.as_ref());
+
+    polynomial_wrapping_add_assign(
+        &mut body.as_mut_polynomial(),
+        &input_plaintext_list.as_polynomial(),
+    );
+
+    if !ciphertext_modulus.is_native_modulus() {
+        let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+        slice_wrapping_scalar_mul_assign(body.as_mut(), torus_scaling);
+    }
+
+    glwe_body_wrapping_add_mask_key_multisum_assign(&mut body, &mask, glwe_secret_key);
+}
+
+/// Encrypt a (scalar) plaintext list and add the resulting [`GLWE ciphertext`](`GlweCiphertext`)
+/// to an existing one, updating it in-place.
+///
+/// The fresh encryption is computed with [`encrypt_glwe_ciphertext`] in a temporary ciphertext
+/// allocated by this function, which is then added to `accumulator_glwe_ciphertext` with
+/// [`glwe_ciphertext_add_assign`]. The accumulator then decrypts to the sum of its previous
+/// plaintexts and of `input_plaintext_list`, with the noise of the fresh encryption added to its
+/// own.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the accumulator encrypting a first message
+/// let msg_1 = 3u64;
+/// let plaintext_list_1 = PlaintextList::new(msg_1 << 60, PlaintextCount(polynomial_size.0));
+/// let mut accumulator = allocate_and_encrypt_new_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &plaintext_list_1,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Encrypt a second message directly into the accumulator
+/// let msg_2 = 4u64;
+/// let plaintext_list_2 = PlaintextList::new(msg_2 << 60, PlaintextCount(polynomial_size.0));
+/// encrypt_glwe_ciphertext_add_into(
+///     &glwe_secret_key,
+///     &mut accumulator,
+///     &plaintext_list_2,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list_1.plaintext_count());
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &accumulator, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// // Check we recovered the sum of the messages for each plaintext
+/// output_plaintext_list.iter().for_each(|elt| {
+///     assert_eq!(decomposer.closest_representable(*elt.0) >> 60, msg_1 + msg_2)
+/// });
+/// ```
+pub fn encrypt_glwe_ciphertext_add_into<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    Gen,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    accumulator_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut fresh_glwe_ciphertext = GlweCiphertext::new(
+        Scalar::ZERO,
+        accumulator_glwe_ciphertext.glwe_size(),
+        accumulator_glwe_ciphertext.polynomial_size(),
+        accumulator_glwe_ciphertext.ciphertext_modulus(),
+    );
+
+    encrypt_glwe_ciphertext(
+        glwe_secret_key,
+        &mut fresh_glwe_ciphertext,
+        input_plaintext_list,
+        noise_distribution,
+        generator,
+    );
+
+    glwe_ciphertext_add_assign(accumulator_glwe_ciphertext, &fresh_glwe_ciphertext);
+}
+
+/// Allocate a new [`GLWE ciphertext`](`GlweCiphertext`) and encrypt an input plaintext list in it.
+///
+/// The [`GlweSize`] of the output is derived from the [`GlweDimension`] of the [`GlweSecretKey`]
+/// and its [`PolynomialSize`] from the [`PlaintextCount`] of the input.
+///
+/// See [`encrypt_glwe_ciphertext`] for usage.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let encoded_msg = msg << 60;
+/// let plaintext_list = PlaintextList::new(encoded_msg, PlaintextCount(polynomial_size.0));
+///
+/// // Create a new GlweCiphertext
+/// let glwe = allocate_and_encrypt_new_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// assert_eq!(glwe.glwe_size(), glwe_size);
+/// assert_eq!(glwe.polynomial_size(), polynomial_size);
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &glwe, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0));
+///
+/// // Get the raw vector
+/// let mut cleartext_list = output_plaintext_list.into_container();
+/// // Remove the encoding
+/// cleartext_list.iter_mut().for_each(|elt| *elt = *elt >> 60);
+/// // Get the list immutably
+/// let cleartext_list = cleartext_list;
+///
+/// // Check we recovered the original message for each plaintext we encrypted
+/// cleartext_list.iter().for_each(|&elt| assert_eq!(elt, msg));
+/// ```
+pub fn allocate_and_encrypt_new_glwe_ciphertext<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    InputCont,
+    Gen,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GlweCiphertextOwned<Scalar>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut new_ct = GlweCiphertextOwned::new(
+        Scalar::ZERO,
+        glwe_secret_key.glwe_dimension().to_glwe_size(),
+        PolynomialSize(input_plaintext_list.plaintext_count().0),
+        ciphertext_modulus,
+    );
+
+    encrypt_glwe_ciphertext(
+        glwe_secret_key,
+        &mut new_ct,
+        input_plaintext_list,
+        noise_distribution,
+        generator,
+    );
+
+    new_ct
+}
+
+/// Encrypt an input plaintext list in a temporary [`GLWE ciphertext`](`GlweCiphertext`) and
+/// sample extract the coefficient at [`MonomialDegree`] `nth` in a new
+/// [`LWE ciphertext`](`LweCiphertext`).
+///
+/// This is [`allocate_and_encrypt_new_glwe_ciphertext`] followed by
+/// [`extract_lwe_sample_from_glwe_ciphertext`]. The output is encrypted under the
+/// [`LweSecretKey`] obtained with [`GlweSecretKey::into_lwe_secret_key`] and has the equivalent
+/// [`LweDimension`] of the [`GlweSecretKey`].
+///
+/// # Panics
+///
+/// Panics if `nth` is not smaller than the [`PlaintextCount`] of the input.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext with a special value at index 42
+/// let mut plaintext_list = PlaintextList::new(3u64 << 60, PlaintextCount(polynomial_size.0));
+/// let special_value = 15;
+/// *plaintext_list.get_mut(42).0 = special_value << 60;
+///
+/// let lwe = encrypt_glwe_and_sample_extract(
+///     &glwe_secret_key,
+///     &plaintext_list,
+///     MonomialDegree(42),
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // The extracted sample decrypts under the equivalent LweSecretKey
+/// let equivalent_lwe_sk = glwe_secret_key.into_lwe_secret_key();
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&equivalent_lwe_sk, &lwe);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// let recovered_message = decomposer.closest_representable(decrypted_plaintext.0) >> 60;
+///
+/// assert_eq!(special_value, recovered_message);
+/// ```
+pub fn encrypt_glwe_and_sample_extract<Scalar, NoiseDistribution, KeyCont, InputCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    nth: MonomialDegree,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> LweCiphertextOwned<Scalar>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        nth.0 < input_plaintext_list.plaintext_count().0,
+        "Cannot extract the coefficient {nth:?} from a GlweCiphertext with {:?}",
+        PolynomialSize(input_plaintext_list.plaintext_count().0)
+    );
+
+    let glwe = allocate_and_encrypt_new_glwe_ciphertext(
+        glwe_secret_key,
+        input_plaintext_list,
+        noise_distribution,
+        ciphertext_modulus,
+        generator,
+    );
+
+    let mut lwe = LweCiphertextOwned::new(
+        Scalar::ZERO,
+        glwe_secret_key
+            .glwe_dimension()
+            .to_equivalent_lwe_dimension(glwe.polynomial_size())
+            .to_lwe_size(),
+        ciphertext_modulus,
+    );
+
+    extract_lwe_sample_from_glwe_ciphertext(&glwe, &mut lwe, nth);
+
+    lwe
+}
+
+/// Encrypt a (scalar) plaintext list in a [`GLWE ciphertext`](`GlweCiphertext`) for any
+/// ciphertext modulus, including moduli which are not compatible with the native modulus.
+///
+/// The provided `ciphertext_modulus` must match the modulus of the output ciphertext. For moduli
+/// compatible with the native modulus this is the same as [`encrypt_glwe_ciphertext`], otherwise
+/// the mask, noise and multisum are all reduced modulo the custom modulus.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let delta = (ciphertext_modulus.get_custom_modulus() / 16) as u64;
+/// let encoded_msg = msg * delta;
+/// let plaintext_list = PlaintextList::new(encoded_msg, PlaintextCount(polynomial_size.0));
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext_custom_mod(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// decrypt_glwe_ciphertext_custom_mod(
+///     &glwe_secret_key,
+///     &glwe,
+///     &mut output_plaintext_list,
+///     ciphertext_modulus,
+/// );
+///
+/// // Round and remove encoding
+/// let cleartext_list: Vec<_> = output_plaintext_list
+///     .iter()
+///     .map(|elt| (*elt.0 + delta / 2) / delta)
+///     .collect();
+///
+/// // Check we recovered the original message for each plaintext we encrypted
+/// cleartext_list.iter().for_each(|&elt| assert_eq!(elt, msg));
+/// ```
+pub fn encrypt_glwe_ciphertext_custom_mod<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    Gen,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert_eq!(
+        output_glwe_ciphertext.ciphertext_modulus(),
+        ciphertext_modulus,
+        "Mismatch between CiphertextModulus of output ciphertext ({:?}) \
+        and the provided CiphertextModulus ({:?})",
+        output_glwe_ciphertext.ciphertext_modulus(),
+        ciphertext_modulus,
+    );
+
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        encrypt_glwe_ciphertext(
+            glwe_secret_key,
+            output_glwe_ciphertext,
+            input_plaintext_list,
+            noise_distribution,
+            generator,
+        );
+        return;
+    }
+
+    dimension_assert!(
+        output_glwe_ciphertext.polynomial_size().0 == input_plaintext_list.plaintext_count().0,
+        "Mismatch between PolynomialSize of output ciphertext PlaintextCount of input. \
+    Got {:?} in output, and {:?} in input.",
+        output_glwe_ciphertext.polynomial_size(),
+        input_plaintext_list.plaintext_count()
+    );
+    dimension_assert!(
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension() == glwe_secret_key.glwe_dimension(),
+        "Mismatch between GlweDimension of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    dimension_assert!(
+        output_glwe_ciphertext.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "Mismatch between PolynomialSize of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output_glwe_ciphertext.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+
+    let (mut mask, mut body) = output_glwe_ciphertext.get_mut_mask_and_body();
+
+    fill_glwe_mask_and_body_for_encryption_other_mod(
+        glwe_secret_key,
+        &mut mask,
+        &mut body,
+        input_plaintext_list,
+        noise_distribution,
+        generator,
+    );
+}
+
+/// Panic message of the GLWE ciphertext list primitives when the input [`PlaintextList`] does not
+/// hold one plaintext per coefficient of the output [`GlweCiphertextList`].
+fn plaintext_count_mismatch_message(
+    polynomial_size: PolynomialSize,
+    glwe_ciphertext_count: GlweCiphertextCount,
+    plaintext_count: PlaintextCount,
+) -> String {
+    format!(
+        "Mismatch between the input PlaintextList and the output GlweCiphertextList: \
+        expected PlaintextCount({}) = {polynomial_size:?} * {glwe_ciphertext_count:?}, \
+        got {plaintext_count:?}. The input must hold PolynomialSize plaintexts for each \
+        GlweCiphertext of the output list, pad or split it accordingly.",
+        polynomial_size.0 * glwe_ciphertext_count.0,
+    )
+}
+
+/// Encrypt a (scalar) plaintext list in [`GLWE ciphertexts`](`GlweCiphertext`) of the output
+/// [`GLWE ciphertext list`](`GlweCiphertextList`).
+///
+/// See this [`formal definition`](`GlweCiphertext#glwe-encryption`) for the definition
+/// of the GLWE encryption algorithm.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let glwe_count = GlweCiphertextCount(2);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let encoded_msg = msg << 60;
+/// let plaintext_list = PlaintextList::new(
+///     encoded_msg,
+///     PlaintextCount(polynomial_size.0 * glwe_count.0),
+/// );
+///
+/// // Create a new GlweCiphertextList
+/// let mut glwe_list = GlweCiphertextList::new(
+///     0u64,
+///     glwe_size,
+///     polynomial_size,
+///     glwe_count,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext_list(
+///     &glwe_secret_key,
+///     &mut glwe_list,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// decrypt_glwe_ciphertext_list(&glwe_secret_key, &glwe_list, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0));
+///
+/// // Get the raw vector
+/// let mut cleartext_list = output_plaintext_list.into_container();
+/// // Remove the encoding
+/// cleartext_list.iter_mut().for_each(|elt| *elt = *elt >> 60);
+/// // Get the list immutably
+/// let cleartext_list = cleartext_list;
+///
+/// // Check we recovered the original message for each plaintext we encrypted
+/// cleartext_list.iter().for_each(|&elt| assert_eq!(elt, msg));
+/// ```
+pub fn encrypt_glwe_ciphertext_list<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    Gen,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    output_glwe_ciphertext_list: &mut GlweCiphertextList<OutputCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    dimension_assert!(
+        output_glwe_ciphertext_list.polynomial_size().0
+            * output_glwe_ciphertext_list.glwe_ciphertext_count().0
+            == input_plaintext_list.plaintext_count().0,
+        "{}",
+        plaintext_count_mismatch_message(
+            output_glwe_ciphertext_list.polynomial_size(),
+            output_glwe_ciphertext_list.glwe_ciphertext_count(),
+            input_plaintext_list.plaintext_count()
+        )
+    );
+    dimension_assert!(
+        output_glwe_ciphertext_list.glwe_size().to_glwe_dimension()
+            == glwe_secret_key.glwe_dimension(),
+        "Mismatch between GlweDimension of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output_glwe_ciphertext_list.glwe_size().to_glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    dimension_assert!(
+        output_glwe_ciphertext_list.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "Mismatch between PolynomialSize of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output_glwe_ciphertext_list.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+
+    let polynomial_size = output_glwe_ciphertext_list.polynomial_size();
+    for (mut ciphertext, encoded) in output_glwe_ciphertext_list
+        .iter_mut()
+        .zip(input_plaintext_list.chunks_exact(polynomial_size.0))
+    {
+        encrypt_glwe_ciphertext(
+            glwe_secret_key,
+            &mut ciphertext,
+            &encoded,
+            noise_distribution,
+            generator,
+        );
+    }
+}
+
+/// Error returned by [`encrypt_glwe_ciphertext_list_from_iter`] when the input iterator yields
+/// fewer plaintexts than the output [`GLWE ciphertext list`](`GlweCiphertextList`) requires.
+///
+/// When it is returned the output list is only partially encrypted, see the `Errors` section of
+/// [`encrypt_glwe_ciphertext_list_from_iter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotEnoughPlaintextsError {
+    /// Number of plaintexts required, i.e. the polynomial size times the ciphertext count
+    pub expected: PlaintextCount,
+    /// Number of plaintexts yielded by the iterator
+    pub got: PlaintextCount,
+}
+
+impl std::error::Error for NotEnoughPlaintextsError {}
+
+impl std::fmt::Display for NotEnoughPlaintextsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The input iterator yielded {:?} while the output GlweCiphertextList requires {:?}",
+            self.got, self.expected,
+        )
+    }
+}
+
+/// Encrypt (scalar) plaintexts yielded by an iterator in [`GLWE ciphertexts`](`GlweCiphertext`)
+/// of the output [`GLWE ciphertext list`](`GlweCiphertextList`).
+///
+/// This is the same as [`encrypt_glwe_ciphertext_list`] without requiring the whole input to be
+/// stored in a [`PlaintextList`]: the iterator is consumed one ciphertext at a time, only a
+/// buffer of `polynomial_size` plaintexts is allocated. Given the same generator state and
+/// plaintexts the output is the same.
+///
+/// Exactly `polynomial_size * glwe_ciphertext_count` plaintexts are taken from the iterator.
+///
+/// # Surplus plaintexts
+///
+/// The iterator is not consumed past the plaintexts of the last ciphertext and no error is
+/// returned if it has more items. As it is taken by value, the remaining items are dropped with
+/// it: pass [`Iterator::by_ref`] to keep them, e.g. to encrypt them in another list.
+///
+/// # Errors
+///
+/// Returns a [`NotEnoughPlaintextsError`] if the iterator yields fewer plaintexts than required.
+/// The output is then partially written: the first ciphertexts, for which all the plaintexts were
+/// available, are encrypted and the following ones, including the one whose plaintexts were
+/// incomplete, are left untouched. The output list must not be used as a whole in that case.
+///
+/// See this [`formal definition`](`GlweCiphertext#glwe-encryption`) for the definition
+/// of the GLWE encryption algorithm.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let glwe_count = GlweCiphertextCount(2);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create a new GlweCiphertextList
+/// let mut glwe_list = GlweCiphertextList::new(
+///     0u64,
+///     glwe_size,
+///     polynomial_size,
+///     glwe_count,
+///     ciphertext_modulus,
+/// );
+///
+/// // The encoded messages are generated on the fly
+/// let messages = (0..polynomial_size.0 * glwe_count.0).map(|i| (i as u64 % 16) << 60);
+///
+/// encrypt_glwe_ciphertext_list_from_iter(
+///     &glwe_secret_key,
+///     messages,
+///     &mut glwe_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// )
+/// .unwrap();
+///
+/// let mut output_plaintext_list =
+///     PlaintextList::new(0u64, PlaintextCount(polynomial_size.0 * glwe_count.0));
+///
+/// decrypt_glwe_ciphertext_list(&glwe_secret_key, &glwe_list, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// // Check we recovered the original message for each plaintext we encrypted
+/// output_plaintext_list
+///     .iter()
+///     .enumerate()
+///     .for_each(|(i, elt)| {
+///         assert_eq!(decomposer.closest_representable(*elt.0) >> 60, i as u64 % 16)
+///     });
+///
+/// // An iterator yielding too few messages is reported
+/// let result = encrypt_glwe_ciphertext_list_from_iter(
+///     &glwe_secret_key,
+///     std::iter::repeat(3u64 << 60).take(polynomial_size.0),
+///     &mut glwe_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// assert_eq!(
+///     result,
+///     Err(NotEnoughPlaintextsError {
+///         expected: PlaintextCount(polynomial_size.0 * glwe_count.0),
+///         got: PlaintextCount(polynomial_size.0),
+///     })
+/// );
+/// ```
+pub fn encrypt_glwe_ciphertext_list_from_iter<Scalar, NoiseDistribution, KeyCont, OutputCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    messages: impl Iterator<Item = Scalar>,
+    output_glwe_ciphertext_list: &mut GlweCiphertextList<OutputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> Result<(), NotEnoughPlaintextsError>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    dimension_assert!(
+        output_glwe_ciphertext_list.glwe_size().to_glwe_dimension()
+            == glwe_secret_key.glwe_dimension(),
+        "Mismatch between GlweDimension of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output_glwe_ciphertext_list.glwe_size().to_glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    dimension_assert!(
+        output_glwe_ciphertext_list.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "Mismatch between PolynomialSize of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output_glwe_ciphertext_list.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+
+    let polynomial_size = output_glwe_ciphertext_list.polynomial_size();
+    let expected =
+        PlaintextCount(polynomial_size.0 * output_glwe_ciphertext_list.glwe_ciphertext_count().0);
+
+    let mut messages = messages;
+    let mut plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    let mut consumed = 0;
+
+    for mut ciphertext in output_glwe_ciphertext_list.iter_mut() {
+        // The buffer is iterated first so that no message is consumed once it is full
+        let mut filled = 0;
+        for (plaintext, message) in plaintext_list.iter_mut().zip(messages.by_ref()) {
+            *plaintext.0 = message;
+            filled += 1;
+        }
+        consumed += filled;
+
+        if filled != polynomial_size.0 {
+            return Err(NotEnoughPlaintextsError {
+                expected,
+                got: PlaintextCount(consumed),
+            });
+        }
+
+        encrypt_glwe_ciphertext(
+            glwe_secret_key,
+            &mut ciphertext,
+            &plaintext_list,
+            noise_distribution,
+            generator,
+        );
+    }
+
+    Ok(())
+}
+
+/// Parallel variant of [`encrypt_glwe_ciphertext_list`] using rayon.
+///
+/// Each [`GLWE ciphertext`](`GlweCiphertext`) is encrypted with its own generator deterministically
+/// forked from the input generator, the output is therefore independent of the number of threads.
+///
+/// See this [`formal definition`](`GlweCiphertext#glwe-encryption`) for the definition
+/// of the GLWE encryption algorithm.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let glwe_count = GlweCiphertextCount(2);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let encoded_msg = msg << 60;
+/// let plaintext_list = PlaintextList::new(
+///     encoded_msg,
+///     PlaintextCount(polynomial_size.0 * glwe_count.0),
+/// );
+///
+/// // Create a new GlweCiphertextList
+/// let mut glwe_list = GlweCiphertextList::new(
+///     0u64,
+///     glwe_size,
+///     polynomial_size,
+///     glwe_count,
+///     ciphertext_modulus,
+/// );
+///
+/// par_encrypt_glwe_ciphertext_list(
+///     &glwe_secret_key,
+///     &mut glwe_list,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// decrypt_glwe_ciphertext_list(&glwe_secret_key, &glwe_list, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0));
+///
+/// // Get the raw vector
+/// let mut cleartext_list = output_plaintext_list.into_container();
+/// // Remove the encoding
+/// cleartext_list.iter_mut().for_each(|elt| *elt = *elt >> 60);
+/// // Get the list immutably
+/// let cleartext_list = cleartext_list;
+///
+/// // Check we recovered the original message for each plaintext we encrypted
+/// cleartext_list.iter().for_each(|&elt| assert_eq!(elt, msg));
+/// ```
+pub fn par_encrypt_glwe_ciphertext_list<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    Gen,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    output_glwe_ciphertext_list: &mut GlweCiphertextList<OutputCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution> + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    KeyCont: Container<Element = Scalar> + Sync,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+{
+    dimension_assert!(
+        output_glwe_ciphertext_list.polynomial_size().0
+            * output_glwe_ciphertext_list.glwe_ciphertext_count().0
+            == input_plaintext_list.plaintext_count().0,
+        "{}",
+        plaintext_count_mismatch_message(
+            output_glwe_ciphertext_list.polynomial_size(),
+            output_glwe_ciphertext_list.glwe_ciphertext_count(),
+            input_plaintext_list.plaintext_count()
+        )
+    );
+    dimension_assert!(
+        output_glwe_ciphertext_list.glwe_size().to_glwe_dimension()
+            == glwe_secret_key.glwe_dimension(),
+        "Mismatch between GlweDimension of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output_glwe_ciphertext_list.glwe_size().to_glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    dimension_assert!(
+        output_glwe_ciphertext_list.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "Mismatch between PolynomialSize of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output_glwe_ciphertext_list.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+
+    let polynomial_size = output_glwe_ciphertext_list.polynomial_size();
+    let gen_iter = generator
+        .par_fork_glwe_list_to_glwe::<Scalar>(
+            output_glwe_ciphertext_list.glwe_ciphertext_count(),
+            output_glwe_ciphertext_list.glwe_size(),
+            polynomial_size,
+        )
+        .unwrap();
+
+    output_glwe_ciphertext_list
+        .par_iter_mut()
+        .zip(input_plaintext_list.par_chunks_exact(polynomial_size.0))
+        .zip(gen_iter)
+        .for_each(|((mut ciphertext, encoded), mut generator)| {
+            encrypt_glwe_ciphertext(
+                glwe_secret_key,
+                &mut ciphertext,
+                &encoded,
+                noise_distribution,
+                &mut generator,
+            );
+        });
+}
+
+/// Decrypt a [`GLWE ciphertext`](`GlweCiphertext`) in a (scalar) plaintext list.
+///
+/// See [`encrypt_glwe_ciphertext`] for usage.
+///
+/// # Formal Definition
+///
+/// See this [`formal definition`](`GlweCiphertext#glwe-decryption`) for the definition
+/// of the GLWE decryption algorithm.
+pub fn decrypt_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_plaintext_list: &mut PlaintextList<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    dimension_assert!(
+        output_plaintext_list.plaintext_count().0 == input_glwe_ciphertext.polynomial_size().0,
+        "Mismatched output PlaintextCount {:?} and input PolynomialSize {:?}",
+        output_plaintext_list.plaintext_count(),
+        input_glwe_ciphertext.polynomial_size()
+    );
+    dimension_assert!(
+        glwe_secret_key.glwe_dimension() == input_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        "Mismatched GlweDimension between glwe_secret_key {:?} and input_glwe_ciphertext {:?}",
+        glwe_secret_key.glwe_dimension(),
+        input_glwe_ciphertext.glwe_size().to_glwe_dimension()
+    );
+    dimension_assert!(
+        glwe_secret_key.polynomial_size() == input_glwe_ciphertext.polynomial_size(),
+        "Mismatched PolynomialSize between glwe_secret_key {:?} and input_glwe_ciphertext {:?}",
+        glwe_secret_key.polynomial_size(),
+        input_glwe_ciphertext.polynomial_size()
+    );
+
+    let ciphertext_modulus = input_glwe_ciphertext.ciphertext_modulus();
+
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    let (mask, body) = input_glwe_ciphertext.get_mask_and_body();
+    output_plaintext_list
+        .as_mut()
+        .copy_from_slice(body.as_ref());
+    polynomial_wrapping_sub_multisum_assign(
+        &mut output_plaintext_list.as_mut_polynomial(),
+        &mask.as_polynomial_list(),
+        &glwe_secret_key.as_polynomial_list(),
+    );
+
+    if !ciphertext_modulus.is_native_modulus() {
+        slice_wrapping_scalar_div_assign(
+            output_plaintext_list.as_mut(),
+            ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
+        );
+    }
+}
+
+/// Decrypt a [`GLWE ciphertext`](`GlweCiphertext`) and decode the resulting plaintexts using the
+/// shortint encoding, i.e. with one bit of padding followed by the carry and message bits.
+///
+/// Returns the decoded messages, reduced modulo `message_modulus`.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let message_modulus = 4u64;
+/// let carry_modulus = 4u64;
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext, the message 3 with a carry of 1
+/// let msg = 3u64;
+/// let delta = (1u64 << 63) / (message_modulus * carry_modulus);
+/// let plaintext_list = PlaintextList::new(
+///     (msg + message_modulus) * delta,
+///     PlaintextCount(polynomial_size.0),
+/// );
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let decrypted =
+///     decrypt_and_decode_glwe_ciphertext(&glwe_secret_key, &glwe, message_modulus, carry_modulus);
+///
+/// // Check we recovered the message, without the carry
+/// assert!(decrypted.iter().all(|&x| x == msg));
+/// ```
+pub fn decrypt_and_decode_glwe_ciphertext<KeyCont, InputCont>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    message_modulus: u64,
+    carry_modulus: u64,
+) -> Vec<u64>
+where
+    KeyCont: Container<Element = u64>,
+    InputCont: Container<Element = u64>,
+{
+    let encoder = GlweEncoder::new_with_padding(message_modulus * carry_modulus);
+
+    let mut plaintext_list = PlaintextList::new(
+        0u64,
+        PlaintextCount(input_glwe_ciphertext.polynomial_size().0),
+    );
+
+    decrypt_glwe_ciphertext(glwe_secret_key, input_glwe_ciphertext, &mut plaintext_list);
+
+    let mut decoded = encoder.decode(&plaintext_list);
+    for x in decoded.iter_mut() {
+        *x %= message_modulus;
+    }
+    decoded
+}
+
+/// Decrypt a [`GLWE ciphertext`](`GlweCiphertext`) in a (scalar) plaintext list for any
+/// ciphertext modulus, including moduli which are not compatible with the native modulus.
+///
+/// The provided `ciphertext_modulus` must match the modulus of the input ciphertext.
+///
+/// See [`encrypt_glwe_ciphertext_custom_mod`] for usage.
+pub fn decrypt_glwe_ciphertext_custom_mod<Scalar, KeyCont, InputCont, OutputCont>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_plaintext_list: &mut PlaintextList<OutputCont>,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        input_glwe_ciphertext.ciphertext_modulus(),
+        ciphertext_modulus,
+        "Mismatch between CiphertextModulus of input ciphertext ({:?}) \
+        and the provided CiphertextModulus ({:?})",
+        input_glwe_ciphertext.ciphertext_modulus(),
+        ciphertext_modulus,
+    );
+
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        decrypt_glwe_ciphertext(
+            glwe_secret_key,
+            input_glwe_ciphertext,
+            output_plaintext_list,
+        );
+        return;
+    }
+
+    dimension_assert!(
+        output_plaintext_list.plaintext_count().0 == input_glwe_ciphertext.polynomial_size().0,
+        "Mismatched output PlaintextCount {:?} and input PolynomialSize {:?}",
+        output_plaintext_list.plaintext_count(),
+        input_glwe_ciphertext.polynomial_size()
+    );
+    dimension_assert!(
+        glwe_secret_key.glwe_dimension() == input_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        "Mismatched GlweDimension between glwe_secret_key {:?} and input_glwe_ciphertext {:?}",
+        glwe_secret_key.glwe_dimension(),
+        input_glwe_ciphertext.glwe_size().to_glwe_dimension()
+    );
+    dimension_assert!(
+        glwe_secret_key.polynomial_size() == input_glwe_ciphertext.polynomial_size(),
+        "Mismatched PolynomialSize between glwe_secret_key {:?} and input_glwe_ciphertext {:?}",
+        glwe_secret_key.polynomial_size(),
+        input_glwe_ciphertext.polynomial_size()
+    );
+
+    let ciphertext_modulus_as_scalar: Scalar = ciphertext_modulus.get_custom_modulus().cast_into();
+
+    let (mask, body) = input_glwe_ciphertext.get_mask_and_body();
+    output_plaintext_list
+        .as_mut()
+        .copy_from_slice(body.as_ref());
+    polynomial_wrapping_sub_multisum_assign_custom_mod(
+        &mut output_plaintext_list.as_mut_polynomial(),
+        &mask.as_polynomial_list(),
+        &glwe_secret_key_polynomial_list_custom_mod(glwe_secret_key, ciphertext_modulus_as_scalar),
+        ciphertext_modulus_as_scalar,
+    );
+}
+
+/// Decrypt a [`GLWE ciphertext list`](`GlweCiphertextList`) in a (scalar) plaintext list.
+///
+/// See [`encrypt_glwe_ciphertext_list`] for usage.
+pub fn decrypt_glwe_ciphertext_list<Scalar, KeyCont, InputCont, OutputCont>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    input_glwe_ciphertext_list: &GlweCiphertextList<InputCont>,
+    output_plaintext_list: &mut PlaintextList<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    dimension_assert!(
+        output_plaintext_list.plaintext_count().0
+            == input_glwe_ciphertext_list.polynomial_size().0
+                * input_glwe_ciphertext_list.glwe_ciphertext_count().0,
+        "Mismatched output PlaintextCount {:?} and input PolynomialSize ({:?}) * \
+        GlweCiphertextCount ({:?}) = {:?}",
+        output_plaintext_list.plaintext_count(),
+        input_glwe_ciphertext_list.polynomial_size(),
+        input_glwe_ciphertext_list.glwe_ciphertext_count(),
+        input_glwe_ciphertext_list.polynomial_size().0
+            * input_glwe_ciphertext_list.glwe_ciphertext_count().0
+    );
+    dimension_assert!(
+        glwe_secret_key.glwe_dimension()
+            == input_glwe_ciphertext_list.glwe_size().to_glwe_dimension(),
+        "Mismatched GlweDimension between glwe_secret_key {:?} and input_glwe_ciphertext_list {:?}",
+        glwe_secret_key.glwe_dimension(),
+        input_glwe_ciphertext_list.glwe_size().to_glwe_dimension()
+    );
+    dimension_assert!(
+        glwe_secret_key.polynomial_size() == input_glwe_ciphertext_list.polynomial_size(),
+        "Mismatched PolynomialSize between glwe_secret_key {:?} and input_glwe_ciphertext_list {:?}",
+        glwe_secret_key.polynomial_size(),
+        input_glwe_ciphertext_list.polynomial_size()
+    );
+
+    for (ciphertext, mut output_sublist) in input_glwe_ciphertext_list
+        .iter()
+        .zip(output_plaintext_list.chunks_exact_mut(input_glwe_ciphertext_list.polynomial_size().0))
+    {
+        decrypt_glwe_ciphertext(glwe_secret_key, &ciphertext, &mut output_sublist);
+    }
+}
+
+/// Parallel variant of [`decrypt_glwe_ciphertext_list`] using rayon.
+///
+/// Each [`GLWE ciphertext`](`GlweCiphertext`) of the list is decrypted independently, the output
+/// is the same as the one of the sequential variant.
+///
+/// See [`par_encrypt_glwe_ciphertext_list`] for usage.
+pub fn par_decrypt_glwe_ciphertext_list<Scalar, KeyCont, InputCont, OutputCont>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    input_glwe_ciphertext_list: &GlweCiphertextList<InputCont>,
+    output_plaintext_list: &mut PlaintextList<OutputCont>,
+) where
+    Scalar: UnsignedTorus + Sync + Send,
+    KeyCont: Container<Element = Scalar> + Sync,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    dimension_assert!(
+        output_plaintext_list.plaintext_count().0
+            == input_glwe_ciphertext_list.polynomial_size().0
+                * input_glwe_ciphertext_list.glwe_ciphertext_count().0,
+        "Mismatched output PlaintextCount {:?} and input PolynomialSize ({:?}) * \
+        GlweCiphertextCount ({:?}) = {:?}",
+        output_plaintext_list.plaintext_count(),
+        input_glwe_ciphertext_list.polynomial_size(),
+        input_glwe_ciphertext_list.glwe_ciphertext_count(),
+        input_glwe_ciphertext_list.polynomial_size().0
+            * input_glwe_ciphertext_list.glwe_ciphertext_count().0
+    );
+    dimension_assert!(
+        glwe_secret_key.glwe_dimension()
+            == input_glwe_ciphertext_list.glwe_size().to_glwe_dimension(),
+        "Mismatched GlweDimension between glwe_secret_key {:?} and input_glwe_ciphertext_list {:?}",
+        glwe_secret_key.glwe_dimension(),
+        input_glwe_ciphertext_list.glwe_size().to_glwe_dimension()
+    );
+    dimension_assert!(
+        glwe_secret_key.polynomial_size() == input_glwe_ciphertext_list.polynomial_size(),
+        "Mismatched PolynomialSize between glwe_secret_key {:?} and input_glwe_ciphertext_list {:?}",
+        glwe_secret_key.polynomial_size(),
+        input_glwe_ciphertext_list.polynomial_size()
+    );
+
+    let polynomial_size = input_glwe_ciphertext_list.polynomial_size();
+
+    input_glwe_ciphertext_list
+        .par_iter()
+        .zip(output_plaintext_list.par_chunks_exact_mut(polynomial_size.0))
+        .for_each(|(ciphertext, mut output_sublist)| {
+            decrypt_glwe_ciphertext(glwe_secret_key, &ciphertext, &mut output_sublist);
+        });
+}
+
+/// Decrypt a [`GLWE ciphertext list`](`GlweCiphertextList`) one ciphertext at a time.
+///
+/// `f` is called with the index of each [`GLWE ciphertext`](`GlweCiphertext`) in the list and its
+/// decrypted [`PlaintextList`].
+///
+/// A single [`PlaintextList`] of `polynomial_size` elements is allocated and reused for every
+/// ciphertext, contrary to [`decrypt_glwe_ciphertext_list`] which requires an output holding the
+/// plaintexts of the whole list. The [`PlaintextList`] passed to `f` is overwritten by the next
+/// decryption.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_count = GlweCiphertextCount(4);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Encrypt the index of each ciphertext in all its coefficients
+/// let plaintext_list = PlaintextList::from_container(
+///     (0..glwe_count.0 as u64)
+///         .flat_map(|idx| std::iter::repeat(idx << 60).take(polynomial_size.0))
+///         .collect::<Vec<_>>(),
 /// );
 ///
 /// // Create a new GlweCiphertextList
@@ -484,29 +2487,382 @@ pub fn encrypt_glwe_ciphertext<Scalar, NoiseDistribution, KeyCont, InputCont, Ou
 ///     &mut encryption_generator,
 /// );
 ///
-/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
-///
-/// decrypt_glwe_ciphertext_list(&glwe_secret_key, &glwe_list, &mut output_plaintext_list);
-///
 /// // Round and remove encoding
 /// // First create a decomposer working on the high 4 bits corresponding to our encoding.
 /// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
 ///
+/// let mut decrypted_count = 0;
+/// decrypt_glwe_ciphertext_list_into_each(
+///     &glwe_secret_key,
+///     &glwe_list,
+///     |glwe_index, decrypted_plaintext_list| {
+///         // Check each coefficient decrypts to the index of its ciphertext
+///         decrypted_plaintext_list.iter().for_each(|elt| {
+///             assert_eq!(
+///                 decomposer.closest_representable(*elt.0) >> 60,
+///                 glwe_index as u64
+///             )
+///         });
+///         decrypted_count += 1;
+///     },
+/// );
+///
+/// assert_eq!(decrypted_count, glwe_count.0);
+/// ```
+pub fn decrypt_glwe_ciphertext_list_into_each<Scalar, KeyCont, InputCont, F>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    input_glwe_ciphertext_list: &GlweCiphertextList<InputCont>,
+    mut f: F,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    F: FnMut(usize, &PlaintextList<&[Scalar]>),
+{
+    dimension_assert!(
+        glwe_secret_key.glwe_dimension()
+            == input_glwe_ciphertext_list.glwe_size().to_glwe_dimension(),
+        "Mismatched GlweDimension between glwe_secret_key {:?} and input_glwe_ciphertext_list {:?}",
+        glwe_secret_key.glwe_dimension(),
+        input_glwe_ciphertext_list.glwe_size().to_glwe_dimension()
+    );
+    dimension_assert!(
+        glwe_secret_key.polynomial_size() == input_glwe_ciphertext_list.polynomial_size(),
+        "Mismatched PolynomialSize between glwe_secret_key {:?} and input_glwe_ciphertext_list {:?}",
+        glwe_secret_key.polynomial_size(),
+        input_glwe_ciphertext_list.polynomial_size()
+    );
+
+    let mut plaintext_list = PlaintextList::new(
+        Scalar::ZERO,
+        PlaintextCount(input_glwe_ciphertext_list.polynomial_size().0),
+    );
+
+    for (glwe_index, ciphertext) in input_glwe_ciphertext_list.iter().enumerate() {
+        decrypt_glwe_ciphertext(glwe_secret_key, &ciphertext, &mut plaintext_list);
+        f(glwe_index, &plaintext_list.as_view());
+    }
+}
+
+/// Decrypt a [`GLWE ciphertext`](`GlweCiphertext`) and return the signed error of each coefficient
+/// with respect to the expected (encoded) plaintext list.
+///
+/// The error is centered around zero, i.e. the torus values are interpreted as signed integers,
+/// for a `u64` ciphertext this returns a `Vec<i64>`. For non native power of 2 moduli the error is
+/// centered with respect to the ciphertext modulus.
+///
+/// This requires the secret key and the expected plaintexts and is therefore only meant for noise
+/// analysis.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let encoded_msg = msg << 60;
+/// let plaintext_list = PlaintextList::new(encoded_msg, PlaintextCount(polynomial_size.0));
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let noise = glwe_ciphertext_noise_polynomial(&glwe_secret_key, &glwe, &plaintext_list);
+///
+/// // The noise is small compared to the encoding
+/// assert!(noise.iter().all(|&x| x.unsigned_abs() < 1 << 59));
+/// ```
+#[cfg(feature = "noise-analysis")]
+pub fn glwe_ciphertext_noise_polynomial<Scalar, KeyCont, InputCont, PlaintextCont>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    expected_plaintext_list: &PlaintextList<PlaintextCont>,
+) -> Vec<Scalar::Signed>
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    PlaintextCont: Container<Element = Scalar>,
+{
+    dimension_assert!(
+        expected_plaintext_list.plaintext_count().0 == input_glwe_ciphertext.polynomial_size().0,
+        "Mismatched expected PlaintextCount {:?} and input PolynomialSize {:?}",
+        expected_plaintext_list.plaintext_count(),
+        input_glwe_ciphertext.polynomial_size()
+    );
+
+    let ciphertext_modulus = input_glwe_ciphertext.ciphertext_modulus();
+
+    let mut decrypted_plaintext_list = PlaintextList::new(
+        Scalar::ZERO,
+        PlaintextCount(input_glwe_ciphertext.polynomial_size().0),
+    );
+
+    decrypt_glwe_ciphertext(
+        glwe_secret_key,
+        input_glwe_ciphertext,
+        &mut decrypted_plaintext_list,
+    );
+
+    // Decrypted values for non native moduli are in [0; q[, move them in the MSBs to use the native
+    // signed arithmetic for the centering
+    let centering_shift = if ciphertext_modulus.is_native_modulus() {
+        0
+    } else {
+        Scalar::BITS - ciphertext_modulus.get_custom_modulus().ilog2() as usize
+    };
+
+    decrypted_plaintext_list
+        .iter()
+        .zip(expected_plaintext_list.iter())
+        .map(|(decrypted, expected)| {
+            let diff = (*decrypted.0).wrapping_sub(*expected.0) << centering_shift;
+            diff.into_signed() >> centering_shift
+        })
+        .collect()
+}
+
+/// Decrypt a [`GLWE ciphertext`](`GlweCiphertext`) and return the noise of each coefficient.
+///
+/// The decryption is done like [`decrypt_glwe_ciphertext_custom_mod`] in a (scalar) plaintext
+/// list, the noise is the difference between the decrypted plaintexts and the expected (encoded)
+/// plaintext list.
+///
+/// All ciphertext moduli are supported. The noise uses the same representation as the decrypted
+/// plaintexts: it is computed modulo the ciphertext modulus and is not centered. With the
+/// `noise-analysis` feature, `glwe_ciphertext_noise_polynomial` returns signed values instead and
+/// for a given encryption the noise is equal to the error returned by
+/// `encrypt_glwe_ciphertext_and_return_noise`.
+///
+/// This is a diagnostic variant of the decryption, e.g. to check the noise left in ciphertexts
+/// after homomorphic operations. It requires the secret key and the expected plaintexts.
+///
+/// # Panics
+///
+/// Panics if `expected_plaintext_list` or `output_plaintext_list` does not have as many
+/// plaintexts as the polynomial size of `input_glwe_ciphertext`.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let encoded_msg = msg << 60;
+/// let plaintext_list = PlaintextList::new(encoded_msg, PlaintextCount(polynomial_size.0));
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// let noise = decrypt_glwe_ciphertext_with_noise_estimate(
+///     &glwe_secret_key,
+///     &glwe,
+///     &plaintext_list,
+///     &mut output_plaintext_list,
+/// );
+///
+/// // The decrypted plaintexts are the encoded message plus the noise
 /// output_plaintext_list
-///     .iter_mut()
-///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0));
+///     .iter()
+///     .zip(noise.iter())
+///     .for_each(|(decrypted, noise)| {
+///         assert_eq!(*decrypted.0, encoded_msg.wrapping_add(*noise.0))
+///     });
+///
+/// // The noise is far smaller than the encoding
+/// assert!(noise
+///     .iter()
+///     .all(|noise| (*noise.0 as i64).unsigned_abs() < 1 << 59));
+/// ```
+pub fn decrypt_glwe_ciphertext_with_noise_estimate<
+    Scalar,
+    KeyCont,
+    InputCont,
+    PlaintextCont,
+    OutputCont,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    expected_plaintext_list: &PlaintextList<PlaintextCont>,
+    output_plaintext_list: &mut PlaintextList<OutputCont>,
+) -> PlaintextListOwned<Scalar>
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    PlaintextCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    dimension_assert!(
+        expected_plaintext_list.plaintext_count().0 == input_glwe_ciphertext.polynomial_size().0,
+        "Mismatched expected PlaintextCount {:?} and input PolynomialSize {:?}",
+        expected_plaintext_list.plaintext_count(),
+        input_glwe_ciphertext.polynomial_size()
+    );
+
+    let ciphertext_modulus = input_glwe_ciphertext.ciphertext_modulus();
+
+    decrypt_glwe_ciphertext_custom_mod(
+        glwe_secret_key,
+        input_glwe_ciphertext,
+        output_plaintext_list,
+        ciphertext_modulus,
+    );
+
+    let mut noise = PlaintextList::new(Scalar::ZERO, expected_plaintext_list.plaintext_count());
+
+    // Decrypted values for non native moduli (power of 2 or not) are in [0; q[, keep the noise in
+    // the same range
+    if ciphertext_modulus.is_native_modulus() {
+        slice_wrapping_sub(
+            noise.as_mut(),
+            output_plaintext_list.as_ref(),
+            expected_plaintext_list.as_ref(),
+        );
+    } else {
+        slice_wrapping_sub_custom_mod(
+            noise.as_mut(),
+            output_plaintext_list.as_ref(),
+            expected_plaintext_list.as_ref(),
+            ciphertext_modulus.get_custom_modulus().cast_into(),
+        );
+    }
+
+    noise
+}
+
+/// Encrypt a (scalar) plaintext list in a [`GLWE ciphertext`](`GlweCiphertext`) like
+/// [`encrypt_glwe_ciphertext`] and return the error polynomial that was sampled and added to the
+/// body.
+///
+/// Given the same generator state the output ciphertext is identical to the one produced by
+/// [`encrypt_glwe_ciphertext`]. The returned error uses the same representation as the input
+/// plaintexts, i.e. before any scaling to the native torus for non native power of 2 moduli.
+///
+/// This is only meant for noise analysis, to measure empirical noise distributions.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let encoded_msg = msg << 60;
+/// let plaintext_list = PlaintextList::new(encoded_msg, PlaintextCount(polynomial_size.0));
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
 ///
-/// // Get the raw vector
-/// let mut cleartext_list = output_plaintext_list.into_container();
-/// // Remove the encoding
-/// cleartext_list.iter_mut().for_each(|elt| *elt = *elt >> 60);
-/// // Get the list immutably
-/// let cleartext_list = cleartext_list;
+/// let sampled_noise = encrypt_glwe_ciphertext_and_return_noise(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
 ///
-/// // Check we recovered the original message for each plaintext we encrypted
-/// cleartext_list.iter().for_each(|&elt| assert_eq!(elt, msg));
+/// // The sampled error is exactly the error observed after decryption
+/// let noise = glwe_ciphertext_noise_polynomial(&glwe_secret_key, &glwe, &plaintext_list);
+///
+/// assert!(sampled_noise
+///     .iter()
+///     .zip(noise.iter())
+///     .all(|(sampled, &observed)| *sampled.0 as i64 == observed));
 /// ```
-pub fn encrypt_glwe_ciphertext_list<
+#[cfg(feature = "noise-analysis")]
+pub fn encrypt_glwe_ciphertext_and_return_noise<
     Scalar,
     NoiseDistribution,
     KeyCont,
@@ -515,11 +2871,12 @@ pub fn encrypt_glwe_ciphertext_list<
     Gen,
 >(
     glwe_secret_key: &GlweSecretKey<KeyCont>,
-    output_glwe_ciphertext_list: &mut GlweCiphertextList<OutputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
     input_plaintext_list: &PlaintextList<InputCont>,
     noise_distribution: NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
-) where
+) -> PlaintextListOwned<Scalar>
+where
     Scalar: Encryptable<Uniform, NoiseDistribution>,
     NoiseDistribution: Distribution,
     KeyCont: Container<Element = Scalar>,
@@ -527,153 +2884,64 @@ pub fn encrypt_glwe_ciphertext_list<
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
-    assert!(
-        output_glwe_ciphertext_list.polynomial_size().0
-            * output_glwe_ciphertext_list.glwe_ciphertext_count().0
-            == input_plaintext_list.plaintext_count().0,
-        "Mismatch between required number of plaintexts: {} ({:?} * {:?}) and input \
-        PlaintextCount: {:?}",
-        output_glwe_ciphertext_list.polynomial_size().0
-            * output_glwe_ciphertext_list.glwe_ciphertext_count().0,
-        output_glwe_ciphertext_list.polynomial_size(),
-        output_glwe_ciphertext_list.glwe_ciphertext_count(),
+    dimension_assert!(
+        output_glwe_ciphertext.polynomial_size().0 == input_plaintext_list.plaintext_count().0,
+        "Mismatch between PolynomialSize of output ciphertext PlaintextCount of input. \
+    Got {:?} in output, and {:?} in input.",
+        output_glwe_ciphertext.polynomial_size(),
         input_plaintext_list.plaintext_count()
     );
-    assert!(
-        output_glwe_ciphertext_list.glwe_size().to_glwe_dimension()
-            == glwe_secret_key.glwe_dimension(),
+    dimension_assert!(
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension() == glwe_secret_key.glwe_dimension(),
         "Mismatch between GlweDimension of output ciphertext and input secret key. \
         Got {:?} in output, and {:?} in secret key.",
-        output_glwe_ciphertext_list.glwe_size().to_glwe_dimension(),
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension(),
         glwe_secret_key.glwe_dimension()
     );
-    assert!(
-        output_glwe_ciphertext_list.polynomial_size() == glwe_secret_key.polynomial_size(),
+    dimension_assert!(
+        output_glwe_ciphertext.polynomial_size() == glwe_secret_key.polynomial_size(),
         "Mismatch between PolynomialSize of output ciphertext and input secret key. \
         Got {:?} in output, and {:?} in secret key.",
-        output_glwe_ciphertext_list.polynomial_size(),
+        output_glwe_ciphertext.polynomial_size(),
         glwe_secret_key.polynomial_size()
     );
 
-    let polynomial_size = output_glwe_ciphertext_list.polynomial_size();
-    for (mut ciphertext, encoded) in output_glwe_ciphertext_list
-        .iter_mut()
-        .zip(input_plaintext_list.chunks_exact(polynomial_size.0))
-    {
-        encrypt_glwe_ciphertext(
-            glwe_secret_key,
-            &mut ciphertext,
-            &encoded,
-            noise_distribution,
-            generator,
-        );
-    }
-}
+    let ciphertext_modulus = output_glwe_ciphertext.ciphertext_modulus();
 
-/// Decrypt a [`GLWE ciphertext`](`GlweCiphertext`) in a (scalar) plaintext list.
-///
-/// See [`encrypt_glwe_ciphertext`] for usage.
-///
-/// # Formal Definition
-///
-/// See this [`formal definition`](`GlweCiphertext#glwe-decryption`) for the definition
-/// of the GLWE decryption algorithm.
-pub fn decrypt_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
-    glwe_secret_key: &GlweSecretKey<KeyCont>,
-    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
-    output_plaintext_list: &mut PlaintextList<OutputCont>,
-) where
-    Scalar: UnsignedTorus,
-    KeyCont: Container<Element = Scalar>,
-    InputCont: Container<Element = Scalar>,
-    OutputCont: ContainerMut<Element = Scalar>,
-{
-    assert!(
-        output_plaintext_list.plaintext_count().0 == input_glwe_ciphertext.polynomial_size().0,
-        "Mismatched output PlaintextCount {:?} and input PolynomialSize {:?}",
-        output_plaintext_list.plaintext_count(),
-        input_glwe_ciphertext.polynomial_size()
-    );
-    assert!(
-        glwe_secret_key.glwe_dimension() == input_glwe_ciphertext.glwe_size().to_glwe_dimension(),
-        "Mismatched GlweDimension between glwe_secret_key {:?} and input_glwe_ciphertext {:?}",
-        glwe_secret_key.glwe_dimension(),
-        input_glwe_ciphertext.glwe_size().to_glwe_dimension()
-    );
-    assert!(
-        glwe_secret_key.polynomial_size() == input_glwe_ciphertext.polynomial_size(),
-        "Mismatched PolynomialSize between glwe_secret_key {:?} and input_glwe_ciphertext {:?}",
-        glwe_secret_key.polynomial_size(),
-        input_glwe_ciphertext.polynomial_size()
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    let mut noise = PlaintextList::new(
+        Scalar::ZERO,
+        PlaintextCount(output_glwe_ciphertext.polynomial_size().0),
     );
 
-    let ciphertext_modulus = input_glwe_ciphertext.ciphertext_modulus();
+    let (mut mask, mut body) = output_glwe_ciphertext.get_mut_mask_and_body();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+    // Same sampling order as fill_glwe_mask_and_body_for_encryption, so that the output matches
+    // encrypt_glwe_ciphertext for a given generator state
+    generator.fill_slice_with_random_uniform_mask_custom_mod(mask.as_mut(), ciphertext_modulus);
+    generator.fill_slice_with_random_noise_from_distribution_custom_mod(
+        noise.as_mut(),
+        noise_distribution,
+        ciphertext_modulus,
+    );
 
-    let (mask, body) = input_glwe_ciphertext.get_mask_and_body();
-    output_plaintext_list
-        .as_mut()
-        .copy_from_slice(body.as_ref());
-    polynomial_wrapping_sub_multisum_assign(
-        &mut output_plaintext_list.as_mut_polynomial(),
-        &mask.as_polynomial_list(),
-        &glwe_secret_key.as_polynomial_list(),
+    body.as_mut().copy_from_slice(noise.as_ref());
+
+    polynomial_wrapping_add_assign(
+        &mut body.as_mut_polynomial(),
+        &input_plaintext_list.as_polynomial(),
     );
 
     if !ciphertext_modulus.is_native_modulus() {
-        slice_wrapping_scalar_div_assign(
-            output_plaintext_list.as_mut(),
-            ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
-        );
+        let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+        slice_wrapping_scalar_mul_assign(mask.as_mut(), torus_scaling);
+        slice_wrapping_scalar_mul_assign(body.as_mut(), torus_scaling);
     }
-}
 
-/// Decrypt a [`GLWE ciphertext list`](`GlweCiphertextList`) in a (scalar) plaintext list.
-///
-/// See [`encrypt_glwe_ciphertext_list`] for usage.
-pub fn decrypt_glwe_ciphertext_list<Scalar, KeyCont, InputCont, OutputCont>(
-    glwe_secret_key: &GlweSecretKey<KeyCont>,
-    input_glwe_ciphertext_list: &GlweCiphertextList<InputCont>,
-    output_plaintext_list: &mut PlaintextList<OutputCont>,
-) where
-    Scalar: UnsignedTorus,
-    KeyCont: Container<Element = Scalar>,
-    InputCont: Container<Element = Scalar>,
-    OutputCont: ContainerMut<Element = Scalar>,
-{
-    assert!(
-        output_plaintext_list.plaintext_count().0
-            == input_glwe_ciphertext_list.polynomial_size().0
-                * input_glwe_ciphertext_list.glwe_ciphertext_count().0,
-        "Mismatched output PlaintextCount {:?} and input PolynomialSize ({:?}) * \
-        GlweCiphertextCount ({:?}) = {:?}",
-        output_plaintext_list.plaintext_count(),
-        input_glwe_ciphertext_list.polynomial_size(),
-        input_glwe_ciphertext_list.glwe_ciphertext_count(),
-        input_glwe_ciphertext_list.polynomial_size().0
-            * input_glwe_ciphertext_list.glwe_ciphertext_count().0
-    );
-    assert!(
-        glwe_secret_key.glwe_dimension()
-            == input_glwe_ciphertext_list.glwe_size().to_glwe_dimension(),
-        "Mismatched GlweDimension between glwe_secret_key {:?} and input_glwe_ciphertext_list {:?}",
-        glwe_secret_key.glwe_dimension(),
-        input_glwe_ciphertext_list.glwe_size().to_glwe_dimension()
-    );
-    assert!(
-        glwe_secret_key.polynomial_size() == input_glwe_ciphertext_list.polynomial_size(),
-        "Mismatched PolynomialSize between glwe_secret_key {:?} and input_glwe_ciphertext_list {:?}",
-        glwe_secret_key.polynomial_size(),
-        input_glwe_ciphertext_list.polynomial_size()
-    );
+    glwe_body_wrapping_add_mask_key_multisum_assign(&mut body, &mask, glwe_secret_key);
 
-    for (ciphertext, mut output_sublist) in input_glwe_ciphertext_list
-        .iter()
-        .zip(output_plaintext_list.chunks_exact_mut(input_glwe_ciphertext_list.polynomial_size().0))
-    {
-        decrypt_glwe_ciphertext(glwe_secret_key, &ciphertext, &mut output_sublist);
-    }
+    noise
 }
 
 /// A trivial encryption uses a zero mask and no noise.
@@ -687,6 +2955,9 @@ pub fn decrypt_glwe_ciphertext_list<Scalar, KeyCont, InputCont, OutputCont>(
 ///
 /// Trivially encrypt an input (scalar) plaintext list in a [`GLWE ciphertext`](`GlweCiphertext`).
 ///
+/// The output may have a `glwe_size` of 1 (i.e. a [`GlweDimension`] of 0), in which case there is
+/// no mask to zero and only the body is written.
+///
 /// # Example
 ///
 /// ```
@@ -745,7 +3016,7 @@ pub fn trivially_encrypt_glwe_ciphertext<Scalar, InputCont, OutputCont>(
     OutputCont: ContainerMut<Element = Scalar>,
     InputCont: Container<Element = Scalar>,
 {
-    assert!(
+    dimension_assert!(
         encoded.plaintext_count().0 == output.polynomial_size().0,
         "Mismatched input PlaintextCount {:?} and output PolynomialSize {:?}",
         encoded.plaintext_count(),
@@ -769,6 +3040,133 @@ pub fn trivially_encrypt_glwe_ciphertext<Scalar, InputCont, OutputCont>(
     }
 }
 
+/// Variant of [`trivially_encrypt_glwe_ciphertext`] taking the encoded values as a raw slice
+/// instead of a [`PlaintextList`].
+///
+/// See [`trivially_encrypt_glwe_ciphertext`] for the caveats of trivial encryption.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the encoded values, e.g. a lookup table
+/// let encoded: Vec<u64> = (0..polynomial_size.0 as u64).map(|x| (x % 16) << 59).collect();
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// trivially_encrypt_glwe_ciphertext_from_slice(&mut glwe, &encoded);
+///
+/// assert_eq!(glwe.get_body().as_ref(), encoded.as_slice());
+/// glwe.get_mask()
+///     .as_ref()
+///     .iter()
+///     .for_each(|&elt| assert_eq!(elt, 0));
+/// ```
+pub fn trivially_encrypt_glwe_ciphertext_from_slice<Scalar, OutputCont>(
+    output: &mut GlweCiphertext<OutputCont>,
+    encoded: &[Scalar],
+) where
+    Scalar: UnsignedTorus,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    dimension_assert!(
+        encoded.len() == output.polynomial_size().0,
+        "Mismatched input slice length {} and output PolynomialSize {:?}",
+        encoded.len(),
+        output.polynomial_size()
+    );
+
+    // Borrows the slice, no allocation takes place
+    trivially_encrypt_glwe_ciphertext(output, &PlaintextList::from_container(encoded));
+}
+
+/// Trivially encrypt an input (scalar) plaintext list in the [`GLWE ciphertexts`](`GlweCiphertext`)
+/// of the output [`GLWE ciphertext list`](`GlweCiphertextList`).
+///
+/// See [`trivially_encrypt_glwe_ciphertext`] for the caveats of trivial encryption.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_count = GlweCiphertextCount(2);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let encoded_msg = msg << 60;
+/// let plaintext_list = PlaintextList::new(
+///     encoded_msg,
+///     PlaintextCount(polynomial_size.0 * glwe_count.0),
+/// );
+///
+/// // Create a new GlweCiphertextList
+/// let mut glwe_list = GlweCiphertextList::new(
+///     0u64,
+///     glwe_size,
+///     polynomial_size,
+///     glwe_count,
+///     ciphertext_modulus,
+/// );
+///
+/// trivially_encrypt_glwe_ciphertext_list(&mut glwe_list, &plaintext_list);
+///
+/// // Each body contains the corresponding chunk of the input data in clear and the masks are
+/// // full of 0s
+/// for (glwe, plaintexts) in glwe_list
+///     .iter()
+///     .zip(plaintext_list.chunks_exact(polynomial_size.0))
+/// {
+///     assert_eq!(glwe.get_body().as_ref(), plaintexts.as_ref());
+///     glwe.get_mask()
+///         .as_ref()
+///         .iter()
+///         .for_each(|&elt| assert_eq!(elt, 0));
+/// }
+/// ```
+pub fn trivially_encrypt_glwe_ciphertext_list<Scalar, InputCont, OutputCont>(
+    output: &mut GlweCiphertextList<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+) where
+    Scalar: UnsignedTorus,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    dimension_assert!(
+        output.polynomial_size().0 * output.glwe_ciphertext_count().0
+            == encoded.plaintext_count().0,
+        "{}",
+        plaintext_count_mismatch_message(
+            output.polynomial_size(),
+            output.glwe_ciphertext_count(),
+            encoded.plaintext_count()
+        )
+    );
+
+    let polynomial_size = output.polynomial_size();
+    for (mut ciphertext, encoded) in output
+        .iter_mut()
+        .zip(encoded.chunks_exact(polynomial_size.0))
+    {
+        trivially_encrypt_glwe_ciphertext(&mut ciphertext, &encoded);
+    }
+}
+
 /// A trivial encryption uses a zero mask and no noise.
 ///
 /// It is absolutely not secure, as the body contains a direct copy of the plaintext.
@@ -781,6 +3179,9 @@ pub fn trivially_encrypt_glwe_ciphertext<Scalar, InputCont, OutputCont>(
 /// Allocate a new [`GLWE ciphertext`](`GlweCiphertext`) and trivially encrypt an input (scalar)
 /// plaintext list in it.
 ///
+/// A `glwe_size` of 1 (i.e. a [`GlweDimension`] of 0) is supported and yields a ciphertext without
+/// mask whose body is the encoded plaintext list. A `glwe_size` of 0 has no body and panics.
+///
 /// # Example
 ///
 /// ```
@@ -842,6 +3243,10 @@ where
     Scalar: UnsignedTorus,
     InputCont: Container<Element = Scalar>,
 {
+    assert!(
+        glwe_size.0 >= 1,
+        "A GlweCiphertext requires a GlweSize of at least 1 to hold its body, got {glwe_size:?}"
+    );
     assert!(ciphertext_modulus.is_compatible_with_native_modulus());
 
     let polynomial_size = PolynomialSize(encoded.plaintext_count().0);
@@ -885,21 +3290,21 @@ pub fn encrypt_seeded_glwe_ciphertext_with_existing_generator<
     InputCont: Container<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
-    assert!(
+    dimension_assert!(
         output_glwe_ciphertext.polynomial_size().0 == input_plaintext_list.plaintext_count().0,
         "Mismatch between PolynomialSize of output ciphertext PlaintextCount of input. \
     Got {:?} in output, and {:?} in input.",
         output_glwe_ciphertext.polynomial_size(),
         input_plaintext_list.plaintext_count()
     );
-    assert!(
+    dimension_assert!(
         output_glwe_ciphertext.glwe_size().to_glwe_dimension() == glwe_secret_key.glwe_dimension(),
         "Mismatch between GlweDimension of output ciphertext and input secret key. \
         Got {:?} in output, and {:?} in secret key.",
         output_glwe_ciphertext.glwe_size().to_glwe_dimension(),
         glwe_secret_key.glwe_dimension()
     );
-    assert!(
+    dimension_assert!(
         output_glwe_ciphertext.polynomial_size() == glwe_secret_key.polynomial_size(),
         "Mismatch between PolynomialSize of output ciphertext and input secret key. \
         Got {:?} in output, and {:?} in secret key.",
@@ -1069,14 +3474,14 @@ pub fn encrypt_seeded_glwe_ciphertext_list_with_existing_generator<
     InputCont: Container<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
-    assert!(
+    dimension_assert!(
         output.glwe_size().to_glwe_dimension() == glwe_secret_key.glwe_dimension(),
         "Mismatched GlweDimension between input GlweSecretKey {:?} and output \
         SeededGlweCiphertextList {:?}.",
         glwe_secret_key.glwe_dimension(),
         output.glwe_size().to_glwe_dimension(),
     );
-    assert!(
+    dimension_assert!(
         output.glwe_ciphertext_count().0 * output.polynomial_size().0
             == encoded.plaintext_count().0,
         "Mismatch between number of output ciphertexts and input plaintexts. \
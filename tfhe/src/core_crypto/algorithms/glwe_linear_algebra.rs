@@ -94,6 +94,22 @@ pub fn glwe_ciphertext_add_assign<Scalar, LhsCont, RhsCont>(
         rhs.ciphertext_modulus()
     );
 
+    assert_eq!(
+        lhs.glwe_size(),
+        rhs.glwe_size(),
+        "Mismatched GlweSize between lhs ({:?}) and rhs ({:?}) GlweCiphertext",
+        lhs.glwe_size(),
+        rhs.glwe_size()
+    );
+
+    assert_eq!(
+        lhs.polynomial_size(),
+        rhs.polynomial_size(),
+        "Mismatched PolynomialSize between lhs ({:?}) and rhs ({:?}) GlweCiphertext",
+        lhs.polynomial_size(),
+        rhs.polynomial_size()
+    );
+
     slice_wrapping_add_assign(lhs.as_mut(), rhs.as_ref());
 }
 
@@ -191,6 +207,22 @@ pub fn glwe_ciphertext_add<Scalar, OutputCont, LhsCont, RhsCont>(
         rhs.ciphertext_modulus()
     );
 
+    assert_eq!(
+        lhs.glwe_size(),
+        rhs.glwe_size(),
+        "Mismatched GlweSize between lhs ({:?}) and rhs ({:?}) GlweCiphertext",
+        lhs.glwe_size(),
+        rhs.glwe_size()
+    );
+
+    assert_eq!(
+        lhs.polynomial_size(),
+        rhs.polynomial_size(),
+        "Mismatched PolynomialSize between lhs ({:?}) and rhs ({:?}) GlweCiphertext",
+        lhs.polynomial_size(),
+        rhs.polynomial_size()
+    );
+
     assert_eq!(
         output.ciphertext_modulus(),
         rhs.ciphertext_modulus(),
@@ -199,12 +231,36 @@ pub fn glwe_ciphertext_add<Scalar, OutputCont, LhsCont, RhsCont>(
         rhs.ciphertext_modulus()
     );
 
+    assert_eq!(
+        output.glwe_size(),
+        rhs.glwe_size(),
+        "Mismatched GlweSize between output ({:?}) and rhs ({:?}) GlweCiphertext",
+        output.glwe_size(),
+        rhs.glwe_size()
+    );
+
+    assert_eq!(
+        output.polynomial_size(),
+        rhs.polynomial_size(),
+        "Mismatched PolynomialSize between output ({:?}) and rhs ({:?}) GlweCiphertext",
+        output.polynomial_size(),
+        rhs.polynomial_size()
+    );
+
     slice_wrapping_add(output.as_mut(), lhs.as_ref(), rhs.as_ref());
 }
 
 /// Add the right-hand side encoded [`PlaintextList`] to the left-hand side [`GLWE
 /// ciphertext`](`GlweCiphertext`) updating it in-place.
 ///
+/// The plaintexts are applied to the body polynomial only, the mask is left untouched.
+///
+/// # Panics
+///
+/// Panics if the
+/// [`PlaintextCount`](`crate::core_crypto::commons::parameters::PlaintextCount`) of `rhs` differs
+/// from the [`PolynomialSize`](`crate::core_crypto::commons::parameters::PolynomialSize`) of `lhs`.
+///
 /// # Example
 ///
 /// ```
@@ -281,6 +337,14 @@ pub fn glwe_ciphertext_plaintext_list_add_assign<Scalar, InCont, PlainCont>(
     InCont: ContainerMut<Element = Scalar>,
     PlainCont: Container<Element = Scalar>,
 {
+    assert_eq!(
+        lhs.polynomial_size().0,
+        rhs.plaintext_count().0,
+        "Mismatched PolynomialSize of lhs GlweCiphertext ({:?}) and PlaintextCount of rhs ({:?})",
+        lhs.polynomial_size(),
+        rhs.plaintext_count()
+    );
+
     let mut body = lhs.get_mut_body();
     let ciphertext_modulus = body.ciphertext_modulus();
     assert!(ciphertext_modulus.is_compatible_with_native_modulus());
@@ -297,6 +361,14 @@ pub fn glwe_ciphertext_plaintext_list_add_assign<Scalar, InCont, PlainCont>(
 /// Subtract the right-hand side encoded [`PlaintextList`] to the left-hand side [`GLWE
 /// ciphertext`](`GlweCiphertext`) updating it in-place.
 ///
+/// The plaintexts are applied to the body polynomial only, the mask is left untouched.
+///
+/// # Panics
+///
+/// Panics if the
+/// [`PlaintextCount`](`crate::core_crypto::commons::parameters::PlaintextCount`) of `rhs` differs
+/// from the [`PolynomialSize`](`crate::core_crypto::commons::parameters::PolynomialSize`) of `lhs`.
+///
 /// # Example
 ///
 /// ```
@@ -373,6 +445,14 @@ pub fn glwe_ciphertext_plaintext_list_sub_assign<Scalar, InCont, PlainCont>(
     InCont: ContainerMut<Element = Scalar>,
     PlainCont: Container<Element = Scalar>,
 {
+    assert_eq!(
+        lhs.polynomial_size().0,
+        rhs.plaintext_count().0,
+        "Mismatched PolynomialSize of lhs GlweCiphertext ({:?}) and PlaintextCount of rhs ({:?})",
+        lhs.polynomial_size(),
+        rhs.plaintext_count()
+    );
+
     let mut body = lhs.get_mut_body();
     let ciphertext_modulus = body.ciphertext_modulus();
     assert!(ciphertext_modulus.is_compatible_with_native_modulus());
@@ -919,6 +999,22 @@ pub fn glwe_ciphertext_sub_assign<Scalar, LhsCont, RhsCont>(
         rhs.ciphertext_modulus()
     );
 
+    assert_eq!(
+        lhs.glwe_size(),
+        rhs.glwe_size(),
+        "Mismatched GlweSize between lhs ({:?}) and rhs ({:?}) GlweCiphertext",
+        lhs.glwe_size(),
+        rhs.glwe_size()
+    );
+
+    assert_eq!(
+        lhs.polynomial_size(),
+        rhs.polynomial_size(),
+        "Mismatched PolynomialSize between lhs ({:?}) and rhs ({:?}) GlweCiphertext",
+        lhs.polynomial_size(),
+        rhs.polynomial_size()
+    );
+
     slice_wrapping_sub_assign(lhs.as_mut(), rhs.as_ref());
 }
 
@@ -1016,6 +1112,22 @@ pub fn glwe_ciphertext_sub<Scalar, OutputCont, LhsCont, RhsCont>(
         rhs.ciphertext_modulus()
     );
 
+    assert_eq!(
+        lhs.glwe_size(),
+        rhs.glwe_size(),
+        "Mismatched GlweSize between lhs ({:?}) and rhs ({:?}) GlweCiphertext",
+        lhs.glwe_size(),
+        rhs.glwe_size()
+    );
+
+    assert_eq!(
+        lhs.polynomial_size(),
+        rhs.polynomial_size(),
+        "Mismatched PolynomialSize between lhs ({:?}) and rhs ({:?}) GlweCiphertext",
+        lhs.polynomial_size(),
+        rhs.polynomial_size()
+    );
+
     assert_eq!(
         output.ciphertext_modulus(),
         rhs.ciphertext_modulus(),
@@ -1024,6 +1136,22 @@ pub fn glwe_ciphertext_sub<Scalar, OutputCont, LhsCont, RhsCont>(
         rhs.ciphertext_modulus()
     );
 
+    assert_eq!(
+        output.glwe_size(),
+        rhs.glwe_size(),
+        "Mismatched GlweSize between output ({:?}) and rhs ({:?}) GlweCiphertext",
+        output.glwe_size(),
+        rhs.glwe_size()
+    );
+
+    assert_eq!(
+        output.polynomial_size(),
+        rhs.polynomial_size(),
+        "Mismatched PolynomialSize between output ({:?}) and rhs ({:?}) GlweCiphertext",
+        output.polynomial_size(),
+        rhs.polynomial_size()
+    );
+
     output.as_mut().copy_from_slice(lhs.as_ref());
     glwe_ciphertext_sub_assign(output, rhs);
 }
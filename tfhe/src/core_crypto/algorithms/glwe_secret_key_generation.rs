@@ -2,7 +2,7 @@
 //! [`GLWE secret keys`](`GlweSecretKey`).
 
 use crate::core_crypto::commons::generators::SecretRandomGenerator;
-use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary};
+use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary, UniformTernary};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -68,3 +68,75 @@ pub fn generate_binary_glwe_secret_key<Scalar, InCont, Gen>(
 {
     generator.fill_slice_with_random_uniform_binary(glwe_secret_key.as_mut());
 }
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) and fill it with uniformly random ternary
+/// coefficients.
+///
+/// See [`generate_ternary_glwe_secret_key`] for the representation of the coefficients.
+pub fn allocate_and_generate_new_ternary_glwe_secret_key<Scalar, Gen>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: RandomGenerable<UniformTernary> + Numeric,
+    Gen: ByteRandomGenerator,
+{
+    let mut glwe_secret_key =
+        GlweSecretKeyOwned::new_empty_key(Scalar::ZERO, glwe_dimension, polynomial_size);
+
+    generate_ternary_glwe_secret_key(&mut glwe_secret_key, generator);
+
+    glwe_secret_key
+}
+
+/// Fill a [`GLWE secret key`](`GlweSecretKey`) with uniformly random ternary coefficients.
+///
+/// The coefficients are taken in `{-1, 0, 1}`, -1 being stored as `Scalar::MAX` so that the
+/// wrapping arithmetic of the encryption and decryption primitives uses the key as is, for the
+/// native modulus and power of two moduli. The primitives handling other moduli, e.g.
+/// [`encrypt_glwe_ciphertext_custom_mod`](`super::encrypt_glwe_ciphertext_custom_mod`), map -1
+/// to `q - 1` for a modulus `q`.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweSecretKey creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut glwe_secret_key =
+///     GlweSecretKey::new_empty_key(0u64, glwe_size.to_glwe_dimension(), polynomial_size);
+///
+/// generate_ternary_glwe_secret_key(&mut glwe_secret_key, &mut secret_generator);
+///
+/// // Check all coefficients are ternary
+/// assert!(glwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1 || elt == u64::MAX));
+/// // Check all coefficients are not zero as we just generated a new key
+/// // Note probability of this assert failing is (1/3)^polynomial_size for a polynomial size of
+/// // 1024.
+/// assert!(glwe_secret_key.as_ref().iter().all(|&elt| elt == 0) == false);
+/// ```
+pub fn generate_ternary_glwe_secret_key<Scalar, InCont, Gen>(
+    glwe_secret_key: &mut GlweSecretKey<InCont>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<UniformTernary>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_uniform_ternary(glwe_secret_key.as_mut());
+}
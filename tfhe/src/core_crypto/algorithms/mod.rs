@@ -4,6 +4,7 @@
 
 pub mod ggsw_conversion;
 pub mod ggsw_encryption;
+pub mod glwe_encoding;
 pub mod glwe_encryption;
 pub mod glwe_linear_algebra;
 pub mod glwe_sample_extraction;
@@ -50,6 +51,7 @@ pub(crate) mod test;
 // They can still be used via `use crate::core_crypto::algorithms::slice_algorithms::*;`
 pub use ggsw_conversion::*;
 pub use ggsw_encryption::*;
+pub use glwe_encoding::*;
 pub use glwe_encryption::*;
 pub use glwe_linear_algebra::*;
 pub use glwe_sample_extraction::*;
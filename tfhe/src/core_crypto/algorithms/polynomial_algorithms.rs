@@ -4,6 +4,7 @@ use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::commons::parameters::MonomialDegree;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
+use rayon::prelude::*;
 
 /// Add a polynomial to the output polynomial.
 ///
@@ -104,6 +105,67 @@ pub fn polynomial_wrapping_add_multisum_assign<Scalar, OutputCont, InputCont1, I
     }
 }
 
+/// Parallel variant of [`polynomial_wrapping_add_multisum_assign`], the element-wise products are
+/// computed and accumulated across threads before being added to the output polynomial.
+///
+/// # Note
+///
+/// Computations wrap around (similar to computing modulo $2^{n\_{bits}}$) when exceeding the
+/// unsigned integer capacity.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_list = PolynomialList::from_container(vec![100_u8, 20, 3, 4, 5, 6], PolynomialSize(3));
+/// let bin_poly_list = PolynomialList::from_container(vec![0, 1, 1, 1, 0, 0], PolynomialSize(3));
+/// let mut output = Polynomial::new(250, PolynomialSize(3));
+/// par_polynomial_wrapping_add_multisum_assign(&mut output, &poly_list, &bin_poly_list);
+/// assert_eq!(output.as_ref(), &[231, 96, 120]);
+/// ```
+pub fn par_polynomial_wrapping_add_multisum_assign<Scalar, OutputCont, InputCont1, InputCont2>(
+    output: &mut Polynomial<OutputCont>,
+    poly_list_1: &PolynomialList<InputCont1>,
+    poly_list_2: &PolynomialList<InputCont2>,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    let polynomial_size = output.polynomial_size();
+
+    assert_eq!(poly_list_1.polynomial_size(), polynomial_size);
+    assert_eq!(poly_list_2.polynomial_size(), polynomial_size);
+
+    let sum = poly_list_1
+        .as_ref()
+        .par_chunks_exact(polynomial_size.0)
+        .zip(poly_list_2.as_ref().par_chunks_exact(polynomial_size.0))
+        .fold(
+            || Polynomial::new(Scalar::ZERO, polynomial_size),
+            |mut acc, (poly_1, poly_2)| {
+                polynomial_wrapping_add_mul_assign(
+                    &mut acc,
+                    &Polynomial::from_container(poly_1),
+                    &Polynomial::from_container(poly_2),
+                );
+                acc
+            },
+        )
+        .reduce(
+            || Polynomial::new(Scalar::ZERO, polynomial_size),
+            |mut lhs, rhs| {
+                polynomial_wrapping_add_assign(&mut lhs, &rhs);
+                lhs
+            },
+        );
+
+    polynomial_wrapping_add_assign(output, &sum);
+}
+
 fn polynomial_wrapping_add_mul_assign_schoolbook<Scalar, OutputCont, InputCont1, InputCont2>(
     output: &mut Polynomial<OutputCont>,
     lhs: &Polynomial<InputCont1>,
@@ -530,6 +592,248 @@ pub fn polynomial_wrapping_sub_multisum_assign<Scalar, OutputCont, InputCont1, I
     }
 }
 
+/// Add the sum of the element-wise product between two lists of polynomials to the output
+/// polynomial, the computations being done modulo a custom modulus.
+///
+/// I.e., if the output polynomial is $C(X)$, for a collection of polynomials $(P\_i(X)))\_i$
+/// and another collection of polynomials $(B\_i(X))\_i$ we perform the operation:
+/// $$
+/// C(X) := C(X) + \sum\_i P\_i(X) \times B\_i(X) mod (X^{N} + 1) mod q
+/// $$
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_list = PolynomialList::from_container(vec![100_u8, 20, 3, 4, 5, 6], PolynomialSize(3));
+/// let bin_poly_list = PolynomialList::from_container(vec![0, 1, 1, 1, 0, 0], PolynomialSize(3));
+/// let mut output = Polynomial::new(250, PolynomialSize(3));
+/// polynomial_wrapping_add_multisum_assign_custom_mod(
+///     &mut output,
+///     &poly_list,
+///     &bin_poly_list,
+///     251,
+/// );
+/// assert_eq!(output.as_ref(), &[231, 101, 125]);
+/// ```
+pub fn polynomial_wrapping_add_multisum_assign_custom_mod<
+    Scalar,
+    OutputCont,
+    InputCont1,
+    InputCont2,
+>(
+    output: &mut Polynomial<OutputCont>,
+    poly_list_1: &PolynomialList<InputCont1>,
+    poly_list_2: &PolynomialList<InputCont2>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    for (poly_1, poly_2) in poly_list_1.iter().zip(poly_list_2.iter()) {
+        polynomial_wrapping_add_mul_assign_custom_mod(output, &poly_1, &poly_2, custom_modulus);
+    }
+}
+
+/// Add the result of the product between two polynomials, reduced modulo $(X^{N}+1)$ and modulo a
+/// custom modulus, to the output polynomial.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_1 = Polynomial::from_container(vec![1_u8, 2, 3]);
+/// let poly_2 = Polynomial::from_container(vec![0, 1, 1]);
+/// let mut res = Polynomial::from_container(vec![1, 0, 250]);
+/// polynomial_wrapping_add_mul_assign_custom_mod(&mut res, &poly_1, &poly_2, 251);
+/// assert_eq!(res.as_ref(), &[247, 249, 2]);
+/// ```
+pub fn polynomial_wrapping_add_mul_assign_custom_mod<Scalar, OutputCont, InputCont1, InputCont2>(
+    output: &mut Polynomial<OutputCont>,
+    lhs: &Polynomial<InputCont1>,
+    rhs: &Polynomial<InputCont2>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == lhs.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input lhs polynomial {:?}.",
+        output.polynomial_size(),
+        lhs.polynomial_size(),
+    );
+    assert!(
+        output.polynomial_size() == rhs.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input rhs polynomial {:?}.",
+        output.polynomial_size(),
+        rhs.polynomial_size(),
+    );
+
+    fn implementation<Scalar: UnsignedInteger>(
+        mut output: Polynomial<&mut [Scalar]>,
+        lhs: Polynomial<&[Scalar]>,
+        rhs: Polynomial<&[Scalar]>,
+        custom_modulus: Scalar,
+    ) {
+        let polynomial_size = output.polynomial_size();
+        let degree = output.degree();
+
+        for (lhs_degree, &lhs_coeff) in lhs.iter().enumerate() {
+            for (rhs_degree, &rhs_coeff) in rhs.iter().enumerate() {
+                let target_degree = lhs_degree + rhs_degree;
+                let product = lhs_coeff.wrapping_mul_custom_mod(rhs_coeff, custom_modulus);
+                if target_degree <= degree {
+                    let output_coefficient = &mut output.as_mut()[target_degree];
+
+                    *output_coefficient =
+                        (*output_coefficient).wrapping_add_custom_mod(product, custom_modulus);
+                } else {
+                    let target_degree = target_degree % polynomial_size.0;
+                    let output_coefficient = &mut output.as_mut()[target_degree];
+
+                    *output_coefficient =
+                        (*output_coefficient).wrapping_sub_custom_mod(product, custom_modulus);
+                }
+            }
+        }
+    }
+    implementation(
+        output.as_mut_view(),
+        lhs.as_view(),
+        rhs.as_view(),
+        custom_modulus,
+    );
+}
+
+/// Subtract the sum of the element-wise product between two lists of polynomials to the output
+/// polynomial, the computations being done modulo a custom modulus.
+///
+/// I.e., if the output polynomial is $C(X)$, for two lists of polynomials $(P\_i(X)))\_i$ and
+/// $(B\_i(X)))\_i$ we perform the operation:
+/// $$
+/// C(X) := C(X) - \sum\_i P\_i(X) \times B\_i(X) mod (X^{N} + 1) mod q
+/// $$
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_list = PolynomialList::from_container(vec![100_u8, 20, 3, 4, 5, 6], PolynomialSize(3));
+/// let bin_poly_list = PolynomialList::from_container(vec![0, 1, 1, 1, 0, 0], PolynomialSize(3));
+/// let mut output = Polynomial::new(250, PolynomialSize(3));
+/// polynomial_wrapping_sub_multisum_assign_custom_mod(
+///     &mut output,
+///     &poly_list,
+///     &bin_poly_list,
+///     251,
+/// );
+/// assert_eq!(output.as_ref(), &[18, 148, 124]);
+/// ```
+pub fn polynomial_wrapping_sub_multisum_assign_custom_mod<
+    Scalar,
+    OutputCont,
+    InputCont1,
+    InputCont2,
+>(
+    output: &mut Polynomial<OutputCont>,
+    poly_list_1: &PolynomialList<InputCont1>,
+    poly_list_2: &PolynomialList<InputCont2>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    for (poly_1, poly_2) in poly_list_1.iter().zip(poly_list_2.iter()) {
+        polynomial_wrapping_sub_mul_assign_custom_mod(output, &poly_1, &poly_2, custom_modulus);
+    }
+}
+
+/// Subtract the result of the product between two polynomials, reduced modulo $(X^{N}+1)$ and
+/// modulo a custom modulus, to the output polynomial.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_1 = Polynomial::from_container(vec![1_u8, 2, 3]);
+/// let poly_2 = Polynomial::from_container(vec![0, 1, 1]);
+/// let mut res = Polynomial::from_container(vec![250, 250, 1]);
+/// polynomial_wrapping_sub_mul_assign_custom_mod(&mut res, &poly_1, &poly_2, 251);
+/// assert_eq!(res.as_ref(), &[4, 1, 249]);
+/// ```
+pub fn polynomial_wrapping_sub_mul_assign_custom_mod<Scalar, OutputCont, InputCont1, InputCont2>(
+    output: &mut Polynomial<OutputCont>,
+    lhs: &Polynomial<InputCont1>,
+    rhs: &Polynomial<InputCont2>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == lhs.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input lhs polynomial {:?}.",
+        output.polynomial_size(),
+        lhs.polynomial_size(),
+    );
+    assert!(
+        output.polynomial_size() == rhs.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input rhs polynomial {:?}.",
+        output.polynomial_size(),
+        rhs.polynomial_size(),
+    );
+
+    fn implementation<Scalar: UnsignedInteger>(
+        mut output: Polynomial<&mut [Scalar]>,
+        lhs: Polynomial<&[Scalar]>,
+        rhs: Polynomial<&[Scalar]>,
+        custom_modulus: Scalar,
+    ) {
+        let polynomial_size = output.polynomial_size();
+        let degree = output.degree();
+
+        for (lhs_degree, &lhs_coeff) in lhs.iter().enumerate() {
+            for (rhs_degree, &rhs_coeff) in rhs.iter().enumerate() {
+                let target_degree = lhs_degree + rhs_degree;
+                let product = lhs_coeff.wrapping_mul_custom_mod(rhs_coeff, custom_modulus);
+                if target_degree <= degree {
+                    let output_coefficient = &mut output.as_mut()[target_degree];
+
+                    *output_coefficient =
+                        (*output_coefficient).wrapping_sub_custom_mod(product, custom_modulus);
+                } else {
+                    let target_degree = target_degree % polynomial_size.0;
+                    let output_coefficient = &mut output.as_mut()[target_degree];
+
+                    *output_coefficient =
+                        (*output_coefficient).wrapping_add_custom_mod(product, custom_modulus);
+                }
+            }
+        }
+    }
+    implementation(
+        output.as_mut_view(),
+        lhs.as_view(),
+        rhs.as_view(),
+        custom_modulus,
+    );
+}
+
 fn polynomial_wrapping_sub_mul_assign_schoolbook<Scalar, OutputCont, InputCont1, InputCont2>(
     output: &mut Polynomial<OutputCont>,
     lhs: &Polynomial<InputCont1>,
@@ -939,6 +1243,46 @@ mod test {
         }
     }
 
+    /// test if we have the same result when using the sequential or parallel multisum
+    fn test_par_add_multisum<T: UnsignedTorus>() {
+        for polynomial_log in 4..=11 {
+            for poly_count in [1, 2, 5] {
+                let polynomial_size = PolynomialSize(1 << polynomial_log);
+                let mut generator = new_random_generator();
+
+                // generate two random lists of Torus polynomials
+                let mut poly_list_1 =
+                    PolynomialList::new(T::ZERO, polynomial_size, PolynomialCount(poly_count));
+                generator.fill_slice_with_random_uniform::<T>(poly_list_1.as_mut());
+                let poly_list_1 = poly_list_1;
+
+                let mut poly_list_2 =
+                    PolynomialList::new(T::ZERO, polynomial_size, PolynomialCount(poly_count));
+                generator.fill_slice_with_random_uniform::<T>(poly_list_2.as_mut());
+                let poly_list_2 = poly_list_2;
+
+                // start from the same random output polynomial
+                let mut seq_multisum = Polynomial::new(T::ZERO, polynomial_size);
+                generator.fill_slice_with_random_uniform::<T>(seq_multisum.as_mut());
+                let mut par_multisum = seq_multisum.clone();
+
+                polynomial_wrapping_add_multisum_assign(
+                    &mut seq_multisum,
+                    &poly_list_1,
+                    &poly_list_2,
+                );
+                par_polynomial_wrapping_add_multisum_assign(
+                    &mut par_multisum,
+                    &poly_list_1,
+                    &poly_list_2,
+                );
+
+                // test
+                assert_eq!(&seq_multisum, &par_multisum);
+            }
+        }
+    }
+
     #[test]
     pub fn test_multiply_divide_unit_monomial_u32() {
         test_multiply_divide_unit_monomial::<u32>();
@@ -978,4 +1322,14 @@ mod test {
     pub fn test_sub_mul_u64() {
         test_sub_mul::<u64>();
     }
+
+    #[test]
+    pub fn test_par_add_multisum_u32() {
+        test_par_add_multisum::<u32>();
+    }
+
+    #[test]
+    pub fn test_par_add_multisum_u64() {
+        test_par_add_multisum::<u64>();
+    }
 }
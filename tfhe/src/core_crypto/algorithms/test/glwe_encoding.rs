@@ -0,0 +1,152 @@
+use super::*;
+use crate::core_crypto::commons::math::random::{RandomGenerator, Seed};
+
+#[test]
+fn glwe_encoder_round_trip_with_noise() {
+    for message_modulus_log in 1..=8 {
+        let message_modulus = 1u64 << message_modulus_log;
+
+        for encoder in [
+            GlweEncoder::new_with_padding(message_modulus),
+            GlweEncoder::new(message_modulus, 1u64 << (u64::BITS - message_modulus_log)),
+        ] {
+            let messages: Vec<u64> = (0..message_modulus).collect();
+            let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(messages.len()));
+            encoder.encode_into(&mut plaintext_list, &messages);
+
+            assert!(plaintext_list
+                .iter()
+                .zip(messages.iter())
+                .all(|(plaintext, &msg)| *plaintext.0 == msg * encoder.delta));
+
+            // Any error strictly smaller than delta / 2 is rounded away
+            let max_error = encoder.delta / 2 - 1;
+            for error in [max_error, max_error.wrapping_neg(), 0] {
+                let noisy_plaintext_list = PlaintextList::from_container(
+                    plaintext_list
+                        .iter()
+                        .map(|plaintext| plaintext.0.wrapping_add(error))
+                        .collect::<Vec<_>>(),
+                );
+
+                assert_eq!(encoder.decode(&noisy_plaintext_list), messages);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "is not smaller than message_modulus")]
+fn glwe_encoder_message_too_large() {
+    let encoder = GlweEncoder::new_with_padding(4);
+    let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(1));
+    encoder.encode_into(&mut plaintext_list, &[4]);
+}
+
+#[test]
+#[should_panic(expected = "does not fit in a u64")]
+fn glwe_encoder_delta_too_large() {
+    let _ = GlweEncoder::new(4, 1u64 << 63);
+}
+
+#[test]
+fn glwe_encoder_t_uniform_max_bound_round_trip() {
+    let glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(1024);
+    let ciphertext_modulus = CiphertextModulus::new_native();
+
+    let mut rsc = TestResources::new();
+
+    let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    for message_modulus_log in 1..=8 {
+        let message_modulus = 1u64 << message_modulus_log;
+
+        for encoder in [
+            GlweEncoder::new_with_padding(message_modulus),
+            GlweEncoder::new(message_modulus, 1u64 << (u64::BITS - message_modulus_log)),
+        ] {
+            let delta_log = encoder.delta.ilog2();
+            let decomposer_base_log = DecompositionBaseLog((u64::BITS - delta_log) as usize);
+
+            // Largest bound guaranteeing an exact decoding: bound_log2 + 2 <= 64 - base_log
+            let bound_log2 = u64::BITS - decomposer_base_log.0 as u32 - 2;
+            let noise_distribution = TUniform::<u64>::new(bound_log2);
+
+            let messages: Vec<u64> = (0..polynomial_size.0 as u64)
+                .map(|x| x % message_modulus)
+                .collect();
+            let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+            encoder.encode_into(&mut plaintext_list, &messages);
+
+            let mut glwe = GlweCiphertext::new(
+                0u64,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext(
+                &glwe_secret_key,
+                &mut glwe,
+                &plaintext_list,
+                noise_distribution,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut output_plaintext_list =
+                PlaintextList::new(0u64, plaintext_list.plaintext_count());
+            decrypt_glwe_ciphertext(&glwe_secret_key, &glwe, &mut output_plaintext_list);
+
+            // The error is the fresh TUniform noise, bounded by 2^bound_log2
+            assert!(output_plaintext_list.iter().zip(plaintext_list.iter()).all(
+                |(decrypted, plaintext)| {
+                    let error = decrypted.0.wrapping_sub(*plaintext.0) as i64;
+                    error.unsigned_abs() <= 1u64 << bound_log2
+                }
+            ));
+
+            let decomposer = SignedDecomposer::new(decomposer_base_log, DecompositionLevelCount(1));
+            assert!(output_plaintext_list.iter().zip(plaintext_list.iter()).all(
+                |(decrypted, plaintext)| {
+                    decomposer.closest_representable(*decrypted.0) == *plaintext.0
+                }
+            ));
+
+            assert_eq!(encoder.decode(&output_plaintext_list), messages);
+        }
+    }
+}
+
+#[test]
+fn glwe_encoder_decode_with_t_uniform_noise() {
+    let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+
+    for message_modulus_log in 1..=8 {
+        let encoder = GlweEncoder::new_with_padding(1u64 << message_modulus_log);
+
+        // Largest bound for which decoding is exact
+        let distribution = TUniform::<u64>::new(encoder.delta.ilog2() - 2);
+
+        let messages: Vec<u64> = (0..1024).map(|x| x % encoder.message_modulus).collect();
+        let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(messages.len()));
+        encoder.encode_into(&mut plaintext_list, &messages);
+
+        let mut noise = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+        noise.fill_with_t_uniform_noise(distribution, &mut generator);
+
+        let noisy_plaintext_list = PlaintextList::from_container(
+            plaintext_list
+                .iter()
+                .zip(noise.iter())
+                .map(|(plaintext, noise)| plaintext.0.wrapping_add(*noise.0))
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(encoder.decode(&noisy_plaintext_list), messages);
+    }
+}
@@ -1,4 +1,6 @@
 use super::*;
+use crate::core_crypto::commons::generators::DeterministicSeeder;
+use crate::core_crypto::commons::math::random::Seed;
 
 #[cfg(not(tarpaulin))]
 const NB_TESTS: usize = 10;
@@ -73,7 +75,9 @@ fn glwe_encrypt_assign_decrypt_custom_mod<Scalar: UnsignedTorus>(
 
 create_parametrized_test!(glwe_encrypt_assign_decrypt_custom_mod);
 
-fn glwe_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestParams<Scalar>) {
+fn glwe_encrypt_with_mask_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
     let glwe_dimension = params.glwe_dimension;
     let polynomial_size = params.polynomial_size;
     let glwe_noise_distribution = params.glwe_noise_distribution;
@@ -96,24 +100,38 @@ fn glwe_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestPar
                 &mut rsc.secret_random_generator,
             );
 
-            let mut glwe = GlweCiphertext::new(
+            // Encryption of zero providing the public mask
+            let mut reference_glwe = GlweCiphertext::new(
                 Scalar::ZERO,
                 glwe_dimension.to_glwe_size(),
                 polynomial_size,
                 ciphertext_modulus,
             );
 
-            let plaintext_list =
-                PlaintextList::new(msg * delta, PlaintextCount(glwe.polynomial_size().0));
+            encrypt_glwe_ciphertext_assign(
+                &glwe_sk,
+                &mut reference_glwe,
+                glwe_noise_distribution,
+                &mut rsc.encryption_random_generator,
+            );
 
-            encrypt_glwe_ciphertext(
+            let mut glwe = GlweCiphertext::new(
+                msg * delta,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext_with_mask(
                 &glwe_sk,
                 &mut glwe,
-                &plaintext_list,
+                &reference_glwe.get_mask(),
                 glwe_noise_distribution,
                 &mut rsc.encryption_random_generator,
             );
 
+            assert_eq!(glwe.get_mask().as_ref(), reference_glwe.get_mask().as_ref());
+
             assert!(check_encrypted_content_respects_mod(
                 &glwe,
                 ciphertext_modulus
@@ -141,16 +159,15 @@ fn glwe_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestPar
     }
 }
 
-create_parametrized_test!(glwe_encrypt_decrypt_custom_mod);
+create_parametrized_test!(glwe_encrypt_with_mask_decrypt_custom_mod);
 
-fn glwe_list_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestParams<Scalar>) {
+fn glwe_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestParams<Scalar>) {
     let glwe_dimension = params.glwe_dimension;
     let polynomial_size = params.polynomial_size;
     let glwe_noise_distribution = params.glwe_noise_distribution;
     let ciphertext_modulus = params.ciphertext_modulus;
     let message_modulus_log = params.message_modulus_log;
     let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
-    let ct_count = GlweCiphertextCount(10);
 
     let mut rsc = TestResources::new();
 
@@ -167,38 +184,33 @@ fn glwe_list_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTe
                 &mut rsc.secret_random_generator,
             );
 
-            let mut glwe_list = GlweCiphertextList::new(
+            let mut glwe = GlweCiphertext::new(
                 Scalar::ZERO,
                 glwe_dimension.to_glwe_size(),
                 polynomial_size,
-                ct_count,
                 ciphertext_modulus,
             );
 
-            let plaintext_list = PlaintextList::new(
-                msg * delta,
-                PlaintextCount(glwe_list.polynomial_size().0 * ct_count.0),
-            );
+            let plaintext_list =
+                PlaintextList::new(msg * delta, PlaintextCount(glwe.polynomial_size().0));
 
-            encrypt_glwe_ciphertext_list(
+            encrypt_glwe_ciphertext(
                 &glwe_sk,
-                &mut glwe_list,
+                &mut glwe,
                 &plaintext_list,
                 glwe_noise_distribution,
                 &mut rsc.encryption_random_generator,
             );
 
             assert!(check_encrypted_content_respects_mod(
-                &glwe_list,
+                &glwe,
                 ciphertext_modulus
             ));
 
-            let mut plaintext_list = PlaintextList::new(
-                Scalar::ZERO,
-                PlaintextCount(glwe_list.polynomial_size().0 * glwe_list.glwe_ciphertext_count().0),
-            );
+            let mut plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(glwe.polynomial_size().0));
 
-            decrypt_glwe_ciphertext_list(&glwe_sk, &glwe_list, &mut plaintext_list);
+            decrypt_glwe_ciphertext(&glwe_sk, &glwe, &mut plaintext_list);
 
             let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
 
@@ -217,13 +229,12 @@ fn glwe_list_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTe
     }
 }
 
-create_parametrized_test!(glwe_list_encrypt_decrypt_custom_mod);
+create_parametrized_test!(glwe_encrypt_decrypt_custom_mod);
 
-fn glwe_trivial_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
-    params: ClassicTestParams<Scalar>,
-) {
+fn glwe_split_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestParams<Scalar>) {
     let glwe_dimension = params.glwe_dimension;
     let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
     let ciphertext_modulus = params.ciphertext_modulus;
     let message_modulus_log = params.message_modulus_log;
     let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
@@ -243,6 +254,11 @@ fn glwe_trivial_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
                 &mut rsc.secret_random_generator,
             );
 
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+            let main_seed = rsc.seeder.seed();
+            let encryption_seed = rsc.seeder.seed();
+
             let mut glwe = GlweCiphertext::new(
                 Scalar::ZERO,
                 glwe_dimension.to_glwe_size(),
@@ -250,26 +266,63 @@ fn glwe_trivial_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
                 ciphertext_modulus,
             );
 
-            let plaintext_list =
-                PlaintextList::new(msg * delta, PlaintextCount(glwe.polynomial_size().0));
+            let mut deterministic_seeder =
+                DeterministicSeeder::<ActivatedRandomGenerator>::new(main_seed);
+            let mut encryption_generator =
+                EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+                    encryption_seed,
+                    &mut deterministic_seeder,
+                );
 
-            trivially_encrypt_glwe_ciphertext(&mut glwe, &plaintext_list);
+            encrypt_glwe_ciphertext(
+                &glwe_sk,
+                &mut glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut encryption_generator,
+            );
+
+            let mut split_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            let mut deterministic_seeder =
+                DeterministicSeeder::<ActivatedRandomGenerator>::new(main_seed);
+            let mut encryption_generator =
+                EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+                    encryption_seed,
+                    &mut deterministic_seeder,
+                );
+
+            fill_glwe_mask_for_encryption(&mut split_glwe, &mut encryption_generator);
+            finalize_glwe_body_for_encryption(
+                &glwe_sk,
+                &mut split_glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut encryption_generator,
+            );
+
+            assert_eq!(glwe, split_glwe);
 
             assert!(check_encrypted_content_respects_mod(
-                &glwe,
+                &split_glwe,
                 ciphertext_modulus
             ));
 
-            let mut plaintext_list =
-                PlaintextList::new(Scalar::ZERO, PlaintextCount(glwe.polynomial_size().0));
+            let mut output_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, plaintext_list.plaintext_count());
 
-            decrypt_glwe_ciphertext(&glwe_sk, &glwe, &mut plaintext_list);
+            decrypt_glwe_ciphertext(&glwe_sk, &split_glwe, &mut output_plaintext_list);
 
-            let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
+            let mut decoded = vec![Scalar::ZERO; output_plaintext_list.plaintext_count().0];
 
             decoded
                 .iter_mut()
-                .zip(plaintext_list.iter())
+                .zip(output_plaintext_list.iter())
                 .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
 
             assert!(decoded.iter().all(|&x| x == msg));
@@ -282,13 +335,14 @@ fn glwe_trivial_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
     }
 }
 
-create_parametrized_test!(glwe_trivial_encrypt_decrypt_custom_mod);
+create_parametrized_test!(glwe_split_encrypt_decrypt_custom_mod);
 
-fn glwe_allocate_trivial_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
+fn glwe_encrypt_add_into_decrypt_custom_mod<Scalar: UnsignedTorus>(
     params: ClassicTestParams<Scalar>,
 ) {
     let glwe_dimension = params.glwe_dimension;
     let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
     let ciphertext_modulus = params.ciphertext_modulus;
     let message_modulus_log = params.message_modulus_log;
     let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
@@ -302,35 +356,86 @@ fn glwe_allocate_trivial_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
     while msg != Scalar::ZERO {
         msg = msg.wrapping_sub(Scalar::ONE);
         for _ in 0..NB_TESTS {
-            let lwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
                 glwe_dimension,
                 polynomial_size,
                 &mut rsc.secret_random_generator,
             );
 
-            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+            // The accumulator encrypts msg, the fresh encryption encrypts msg + 1
+            let accumulator_plaintext_list =
+                PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+            let plaintext_list = PlaintextList::new(
+                (msg.wrapping_add(Scalar::ONE) % msg_modulus) * delta,
+                PlaintextCount(polynomial_size.0),
+            );
 
-            let ct = allocate_and_trivially_encrypt_new_glwe_ciphertext(
-                glwe_dimension.to_glwe_size(),
+            let mut accumulator = allocate_and_encrypt_new_glwe_ciphertext(
+                &glwe_sk,
+                &accumulator_plaintext_list,
+                glwe_noise_distribution,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+            let mut expected = accumulator.clone();
+
+            let main_seed = rsc.seeder.seed();
+            let encryption_seed = rsc.seeder.seed();
+
+            let mut deterministic_seeder =
+                DeterministicSeeder::<ActivatedRandomGenerator>::new(main_seed);
+            let mut encryption_generator =
+                EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+                    encryption_seed,
+                    &mut deterministic_seeder,
+                );
+
+            encrypt_glwe_ciphertext_add_into(
+                &glwe_sk,
+                &mut accumulator,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut encryption_generator,
+            );
+
+            let mut deterministic_seeder =
+                DeterministicSeeder::<ActivatedRandomGenerator>::new(main_seed);
+            let mut encryption_generator =
+                EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+                    encryption_seed,
+                    &mut deterministic_seeder,
+                );
+
+            let fresh_glwe = allocate_and_encrypt_new_glwe_ciphertext(
+                &glwe_sk,
                 &plaintext_list,
+                glwe_noise_distribution,
                 ciphertext_modulus,
+                &mut encryption_generator,
             );
+            glwe_ciphertext_add_assign(&mut expected, &fresh_glwe);
+
+            assert_eq!(accumulator, expected);
 
             assert!(check_encrypted_content_respects_mod(
-                &ct,
+                &accumulator,
                 ciphertext_modulus
             ));
 
             let mut output_plaintext_list =
-                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+                PlaintextList::new(Scalar::ZERO, plaintext_list.plaintext_count());
 
-            decrypt_glwe_ciphertext(&lwe_sk, &ct, &mut output_plaintext_list);
+            decrypt_glwe_ciphertext(&glwe_sk, &accumulator, &mut output_plaintext_list);
 
-            output_plaintext_list
+            let mut decoded = vec![Scalar::ZERO; output_plaintext_list.plaintext_count().0];
+
+            decoded
                 .iter_mut()
-                .for_each(|x| *x.0 = round_decode(*x.0, delta) % msg_modulus);
+                .zip(output_plaintext_list.iter())
+                .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
 
-            assert!(output_plaintext_list.iter().all(|x| *x.0 == msg));
+            let expected_msg = msg.wrapping_add(msg.wrapping_add(Scalar::ONE)) % msg_modulus;
+            assert!(decoded.iter().all(|&x| x == expected_msg));
         }
 
         // In coverage, we break after one while loop iteration, changing message values does not
@@ -340,9 +445,9 @@ fn glwe_allocate_trivial_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
     }
 }
 
-create_parametrized_test!(glwe_allocate_trivial_encrypt_decrypt_custom_mod);
+create_parametrized_test!(glwe_encrypt_add_into_decrypt_custom_mod);
 
-fn glwe_seeded_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
+fn glwe_encrypt_decrypt_ternary_key_custom_mod<Scalar: UnsignedTorus>(
     params: ClassicTestParams<Scalar>,
 ) {
     let glwe_dimension = params.glwe_dimension;
@@ -361,55 +466,126 @@ fn glwe_seeded_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
     while msg != Scalar::ZERO {
         msg = msg.wrapping_sub(Scalar::ONE);
         for _ in 0..NB_TESTS {
-            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            let glwe_sk = allocate_and_generate_new_ternary_glwe_secret_key(
                 glwe_dimension,
                 polynomial_size,
                 &mut rsc.secret_random_generator,
             );
 
-            let mut seeded_glwe = SeededGlweCiphertext::new(
+            assert!(glwe_sk
+                .as_ref()
+                .iter()
+                .all(|&x| x == Scalar::ZERO || x == Scalar::ONE || x == Scalar::MAX));
+
+            let mut glwe = GlweCiphertext::new(
                 Scalar::ZERO,
                 glwe_dimension.to_glwe_size(),
                 polynomial_size,
-                rsc.seeder.seed().into(),
                 ciphertext_modulus,
             );
 
             let plaintext_list =
-                PlaintextList::new(msg * delta, PlaintextCount(seeded_glwe.polynomial_size().0));
+                PlaintextList::new(msg * delta, PlaintextCount(glwe.polynomial_size().0));
 
-            encrypt_seeded_glwe_ciphertext(
+            // The custom_mod variants forward native compatible moduli to encrypt_glwe_ciphertext
+            // and decrypt_glwe_ciphertext
+            encrypt_glwe_ciphertext_custom_mod(
                 &glwe_sk,
-                &mut seeded_glwe,
+                &mut glwe,
                 &plaintext_list,
                 glwe_noise_distribution,
-                rsc.seeder.as_mut(),
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
             );
 
             assert!(check_encrypted_content_respects_mod(
-                &seeded_glwe,
+                &glwe,
                 ciphertext_modulus
             ));
 
-            let decompressed_glwe = seeded_glwe.decompress_into_glwe_ciphertext();
+            let mut plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(glwe.polynomial_size().0));
+
+            decrypt_glwe_ciphertext_custom_mod(
+                &glwe_sk,
+                &glwe,
+                &mut plaintext_list,
+                ciphertext_modulus,
+            );
+
+            let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
+
+            decoded
+                .iter_mut()
+                .zip(plaintext_list.iter())
+                .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
+
+            assert!(decoded.iter().all(|&x| x == msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+create_parametrized_test_with_non_native_parameters!(glwe_encrypt_decrypt_ternary_key_custom_mod);
+
+fn glwe_allocate_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+            let glwe = allocate_and_encrypt_new_glwe_ciphertext(
+                &glwe_sk,
+                &plaintext_list,
+                glwe_noise_distribution,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            assert_eq!(glwe.glwe_size(), glwe_dimension.to_glwe_size());
+            assert_eq!(glwe.polynomial_size(), polynomial_size);
+            assert_eq!(glwe.ciphertext_modulus(), ciphertext_modulus);
 
             assert!(check_encrypted_content_respects_mod(
-                &decompressed_glwe,
+                &glwe,
                 ciphertext_modulus
             ));
 
-            let mut plaintext_list = PlaintextList::new(
-                Scalar::ZERO,
-                PlaintextCount(decompressed_glwe.polynomial_size().0),
-            );
+            let mut output_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, plaintext_list.plaintext_count());
 
-            decrypt_glwe_ciphertext(&glwe_sk, &decompressed_glwe, &mut plaintext_list);
+            decrypt_glwe_ciphertext(&glwe_sk, &glwe, &mut output_plaintext_list);
 
-            let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
+            let mut decoded = vec![Scalar::ZERO; output_plaintext_list.plaintext_count().0];
 
             decoded
                 .iter_mut()
-                .zip(plaintext_list.iter())
+                .zip(output_plaintext_list.iter())
                 .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
 
             assert!(decoded.iter().all(|&x| x == msg));
@@ -422,9 +598,9 @@ fn glwe_seeded_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
     }
 }
 
-create_parametrized_test!(glwe_seeded_encrypt_decrypt_custom_mod);
+create_parametrized_test!(glwe_allocate_encrypt_decrypt_custom_mod);
 
-fn glwe_seeded_list_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
+fn glwe_encrypt_custom_mod_decrypt_custom_mod<Scalar: UnsignedTorus>(
     params: ClassicTestParams<Scalar>,
 ) {
     let glwe_dimension = params.glwe_dimension;
@@ -433,7 +609,6 @@ fn glwe_seeded_list_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
     let ciphertext_modulus = params.ciphertext_modulus;
     let message_modulus_log = params.message_modulus_log;
     let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
-    let ct_count = GlweCiphertextCount(10);
 
     let mut rsc = TestResources::new();
 
@@ -450,42 +625,40 @@ fn glwe_seeded_list_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
                 &mut rsc.secret_random_generator,
             );
 
-            let mut glwe_seeded_list = SeededGlweCiphertextList::new(
+            let mut glwe = GlweCiphertext::new(
                 Scalar::ZERO,
                 glwe_dimension.to_glwe_size(),
                 polynomial_size,
-                ct_count,
-                rsc.seeder.seed().into(),
                 ciphertext_modulus,
             );
 
-            let plaintext_list = PlaintextList::new(
-                msg * delta,
-                PlaintextCount(glwe_seeded_list.polynomial_size().0 * ct_count.0),
-            );
+            let plaintext_list =
+                PlaintextList::new(msg * delta, PlaintextCount(glwe.polynomial_size().0));
 
-            encrypt_seeded_glwe_ciphertext_list(
+            encrypt_glwe_ciphertext_custom_mod(
                 &glwe_sk,
-                &mut glwe_seeded_list,
+                &mut glwe,
                 &plaintext_list,
                 glwe_noise_distribution,
-                rsc.seeder.as_mut(),
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
             );
 
             assert!(check_encrypted_content_respects_mod(
-                &glwe_seeded_list,
+                &glwe,
                 ciphertext_modulus
             ));
 
-            let glwe_list = glwe_seeded_list.decompress_into_glwe_ciphertext_list();
+            let mut plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(glwe.polynomial_size().0));
 
-            let mut plaintext_list = PlaintextList::new(
-                Scalar::ZERO,
-                PlaintextCount(glwe_list.polynomial_size().0 * glwe_list.glwe_ciphertext_count().0),
+            decrypt_glwe_ciphertext_custom_mod(
+                &glwe_sk,
+                &glwe,
+                &mut plaintext_list,
+                ciphertext_modulus,
             );
 
-            decrypt_glwe_ciphertext_list(&glwe_sk, &glwe_list, &mut plaintext_list);
-
             let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
 
             decoded
@@ -503,4 +676,1668 @@ fn glwe_seeded_list_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
     }
 }
 
-create_parametrized_test!(glwe_seeded_list_encrypt_decrypt_custom_mod);
+create_parametrized_test_with_non_native_parameters!(glwe_encrypt_custom_mod_decrypt_custom_mod);
+
+#[test]
+#[should_panic(expected = "Mismatch between CiphertextModulus of input ciphertext")]
+fn glwe_decrypt_custom_mod_mismatched_modulus() {
+    let params = TEST_PARAMS_3_BITS_SOLINAS_U64;
+    let mut rsc = TestResources::new();
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        params.glwe_dimension,
+        params.polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let mut glwe = GlweCiphertext::new(
+        0u64,
+        params.glwe_dimension.to_glwe_size(),
+        params.polynomial_size,
+        params.ciphertext_modulus,
+    );
+
+    encrypt_glwe_ciphertext_custom_mod(
+        &glwe_sk,
+        &mut glwe,
+        &PlaintextList::new(0u64, PlaintextCount(params.polynomial_size.0)),
+        params.glwe_noise_distribution,
+        params.ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(params.polynomial_size.0));
+
+    // Decrypting with a modulus different from the one used for the encryption is rejected
+    decrypt_glwe_ciphertext_custom_mod(
+        &glwe_sk,
+        &glwe,
+        &mut plaintext_list,
+        CiphertextModulus::try_new_power_of_2(63).unwrap(),
+    );
+}
+
+fn glwe_encrypt_decrypt_t_uniform_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    // The bound is small compared to the encoding for all the tested moduli
+    let params = ClassicTestParams {
+        glwe_noise_distribution: DynamicDistribution::new_t_uniform(27),
+        ..params
+    };
+
+    glwe_encrypt_custom_mod_decrypt_custom_mod(params);
+}
+
+create_parametrized_test_with_non_native_parameters!(glwe_encrypt_decrypt_t_uniform_custom_mod);
+
+fn glwe_list_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestParams<Scalar>) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let ct_count = GlweCiphertextCount(10);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let mut glwe_list = GlweCiphertextList::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ct_count,
+                ciphertext_modulus,
+            );
+
+            let plaintext_list = PlaintextList::new(
+                msg * delta,
+                PlaintextCount(glwe_list.polynomial_size().0 * ct_count.0),
+            );
+
+            encrypt_glwe_ciphertext_list(
+                &glwe_sk,
+                &mut glwe_list,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut rsc.encryption_random_generator,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &glwe_list,
+                ciphertext_modulus
+            ));
+
+            let mut plaintext_list = PlaintextList::new(
+                Scalar::ZERO,
+                PlaintextCount(glwe_list.polynomial_size().0 * glwe_list.glwe_ciphertext_count().0),
+            );
+
+            decrypt_glwe_ciphertext_list(&glwe_sk, &glwe_list, &mut plaintext_list);
+
+            let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
+
+            decoded
+                .iter_mut()
+                .zip(plaintext_list.iter())
+                .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
+
+            assert!(decoded.iter().all(|&x| x == msg));
+
+            // Streaming decryption yields the same plaintexts, one ciphertext at a time
+            let mut next_glwe_index = 0;
+            decrypt_glwe_ciphertext_list_into_each(
+                &glwe_sk,
+                &glwe_list,
+                |glwe_index, glwe_plaintext_list| {
+                    assert_eq!(glwe_index, next_glwe_index);
+                    assert_eq!(
+                        glwe_plaintext_list.as_ref(),
+                        &plaintext_list.as_ref()
+                            [glwe_index * polynomial_size.0..(glwe_index + 1) * polynomial_size.0]
+                    );
+                    next_glwe_index += 1;
+                },
+            );
+            assert_eq!(next_glwe_index, ct_count.0);
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+create_parametrized_test!(glwe_list_encrypt_decrypt_custom_mod);
+
+fn glwe_list_encrypt_from_iter_custom_mod<Scalar: UnsignedTorus + CastFrom<usize>>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let ct_count = GlweCiphertextCount(10);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    for _ in 0..NB_TESTS {
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+
+        // Each plaintext of the list encodes a different message
+        let plaintext_list = PlaintextList::from_container(
+            (0..polynomial_size.0 * ct_count.0)
+                .map(|i| (Scalar::cast_from(i) % msg_modulus) * delta)
+                .collect::<Vec<_>>(),
+        );
+
+        let main_seed = rsc.seeder.seed();
+        let encryption_seed = rsc.seeder.seed();
+
+        let new_encryption_generator = || {
+            let mut deterministic_seeder =
+                DeterministicSeeder::<ActivatedRandomGenerator>::new(main_seed);
+            EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+                encryption_seed,
+                &mut deterministic_seeder,
+            )
+        };
+
+        let mut glwe_list = GlweCiphertextList::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ct_count,
+            ciphertext_modulus,
+        );
+
+        encrypt_glwe_ciphertext_list(
+            &glwe_sk,
+            &mut glwe_list,
+            &plaintext_list,
+            glwe_noise_distribution,
+            &mut new_encryption_generator(),
+        );
+
+        let mut glwe_list_from_iter = GlweCiphertextList::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ct_count,
+            ciphertext_modulus,
+        );
+
+        // Extra plaintexts are not consumed
+        let mut plaintexts = plaintext_list.iter().map(|x| *x.0).chain([Scalar::ONE]);
+
+        encrypt_glwe_ciphertext_list_from_iter(
+            &glwe_sk,
+            plaintexts.by_ref(),
+            &mut glwe_list_from_iter,
+            glwe_noise_distribution,
+            &mut new_encryption_generator(),
+        )
+        .unwrap();
+
+        assert_eq!(plaintexts.next(), Some(Scalar::ONE));
+        assert_eq!(glwe_list_from_iter, glwe_list);
+
+        let mut decrypted_plaintext_list =
+            PlaintextList::new(Scalar::ZERO, plaintext_list.plaintext_count());
+        decrypt_glwe_ciphertext_list(
+            &glwe_sk,
+            &glwe_list_from_iter,
+            &mut decrypted_plaintext_list,
+        );
+
+        assert!(decrypted_plaintext_list
+            .iter()
+            .zip(plaintext_list.iter())
+            .all(
+                |(decrypted, expected)| round_decode(*decrypted.0, delta) % msg_modulus
+                    == *expected.0 / delta
+            ));
+
+        // One plaintext short: only the first ciphertexts are encrypted
+        let mut short_glwe_list = GlweCiphertextList::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ct_count,
+            ciphertext_modulus,
+        );
+
+        let short_plaintext_count = plaintext_list.plaintext_count().0 - 1;
+        let result = encrypt_glwe_ciphertext_list_from_iter(
+            &glwe_sk,
+            plaintext_list
+                .iter()
+                .map(|x| *x.0)
+                .take(short_plaintext_count),
+            &mut short_glwe_list,
+            glwe_noise_distribution,
+            &mut new_encryption_generator(),
+        );
+
+        assert_eq!(
+            result,
+            Err(NotEnoughPlaintextsError {
+                expected: plaintext_list.plaintext_count(),
+                got: PlaintextCount(short_plaintext_count),
+            })
+        );
+
+        let (encrypted, untouched) = short_glwe_list.split_at(ct_count.0 - 1);
+        assert_eq!(
+            encrypted.as_ref(),
+            glwe_list.split_at(ct_count.0 - 1).0.as_ref()
+        );
+        assert!(untouched.as_ref().iter().all(|&x| x == Scalar::ZERO));
+    }
+}
+
+create_parametrized_test!(glwe_list_encrypt_from_iter_custom_mod);
+
+fn glwe_list_par_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus + Sync + Send>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let ct_count = GlweCiphertextCount(10);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let plaintext_list =
+                PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0 * ct_count.0));
+
+            let main_seed = rsc.seeder.seed();
+            let encryption_seed = rsc.seeder.seed();
+
+            // Encrypt with the same seeds on thread pools of different sizes, the forked generators
+            // must make the result independent of the scheduling
+            let par_encrypt_with_threads = |num_threads: usize| {
+                let mut glwe_list = GlweCiphertextList::new(
+                    Scalar::ZERO,
+                    glwe_dimension.to_glwe_size(),
+                    polynomial_size,
+                    ct_count,
+                    ciphertext_modulus,
+                );
+
+                let mut deterministic_seeder =
+                    DeterministicSeeder::<ActivatedRandomGenerator>::new(main_seed);
+                let mut encryption_generator =
+                    EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+                        encryption_seed,
+                        &mut deterministic_seeder,
+                    );
+
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(num_threads)
+                    .build()
+                    .unwrap();
+
+                pool.install(|| {
+                    par_encrypt_glwe_ciphertext_list(
+                        &glwe_sk,
+                        &mut glwe_list,
+                        &plaintext_list,
+                        glwe_noise_distribution,
+                        &mut encryption_generator,
+                    );
+                });
+
+                glwe_list
+            };
+
+            let glwe_list = par_encrypt_with_threads(1);
+            let other_glwe_list = par_encrypt_with_threads(4);
+
+            assert_eq!(glwe_list, other_glwe_list);
+
+            assert!(check_encrypted_content_respects_mod(
+                &glwe_list,
+                ciphertext_modulus
+            ));
+
+            let mut plaintext_list = PlaintextList::new(
+                Scalar::ZERO,
+                PlaintextCount(glwe_list.polynomial_size().0 * glwe_list.glwe_ciphertext_count().0),
+            );
+
+            decrypt_glwe_ciphertext_list(&glwe_sk, &glwe_list, &mut plaintext_list);
+
+            let mut par_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, plaintext_list.plaintext_count());
+
+            par_decrypt_glwe_ciphertext_list(&glwe_sk, &glwe_list, &mut par_plaintext_list);
+
+            assert_eq!(par_plaintext_list, plaintext_list);
+
+            let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
+
+            decoded
+                .iter_mut()
+                .zip(plaintext_list.iter())
+                .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
+
+            assert!(decoded.iter().all(|&x| x == msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+create_parametrized_test!(glwe_list_par_encrypt_decrypt_custom_mod);
+
+fn glwe_trivial_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let mut glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            let plaintext_list =
+                PlaintextList::new(msg * delta, PlaintextCount(glwe.polynomial_size().0));
+
+            trivially_encrypt_glwe_ciphertext(&mut glwe, &plaintext_list);
+
+            assert!(check_encrypted_content_respects_mod(
+                &glwe,
+                ciphertext_modulus
+            ));
+
+            let mut plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(glwe.polynomial_size().0));
+
+            decrypt_glwe_ciphertext(&glwe_sk, &glwe, &mut plaintext_list);
+
+            let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
+
+            decoded
+                .iter_mut()
+                .zip(plaintext_list.iter())
+                .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
+
+            assert!(decoded.iter().all(|&x| x == msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+create_parametrized_test!(glwe_trivial_encrypt_decrypt_custom_mod);
+
+fn glwe_trivial_encrypt_from_slice_decrypt_custom_mod<Scalar: UnsignedTorus + CastFrom<usize>>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    for _ in 0..NB_TESTS {
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+
+        // A different message in each coefficient, as in a lookup table
+        let msgs: Vec<Scalar> = (0..polynomial_size.0)
+            .map(|i| Scalar::cast_from(i) % msg_modulus)
+            .collect();
+        let encoded: Vec<Scalar> = msgs.iter().map(|&msg| msg * delta).collect();
+
+        let mut glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        trivially_encrypt_glwe_ciphertext_from_slice(&mut glwe, &encoded);
+
+        assert!(check_encrypted_content_respects_mod(
+            &glwe,
+            ciphertext_modulus
+        ));
+
+        // Same result as the PlaintextList based trivial encryption
+        let mut reference_glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+        trivially_encrypt_glwe_ciphertext(
+            &mut reference_glwe,
+            &PlaintextList::from_container(encoded.as_slice()),
+        );
+        assert_eq!(glwe, reference_glwe);
+
+        let mut plaintext_list =
+            PlaintextList::new(Scalar::ZERO, PlaintextCount(glwe.polynomial_size().0));
+
+        decrypt_glwe_ciphertext(&glwe_sk, &glwe, &mut plaintext_list);
+
+        let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
+
+        decoded
+            .iter_mut()
+            .zip(plaintext_list.iter())
+            .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
+
+        assert_eq!(decoded, msgs);
+    }
+}
+
+create_parametrized_test!(glwe_trivial_encrypt_from_slice_decrypt_custom_mod);
+
+#[test]
+#[should_panic(expected = "Mismatched input slice length")]
+fn glwe_trivial_encrypt_from_slice_mismatched_length() {
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+    let mut glwe = GlweCiphertext::new(0u64, GlweSize(2), PolynomialSize(1024), ciphertext_modulus);
+
+    trivially_encrypt_glwe_ciphertext_from_slice(&mut glwe, &[0u64; 512]);
+}
+
+// The dimension checks are skipped in release builds with the skip-dimension-checks feature
+#[cfg(any(not(feature = "skip-dimension-checks"), debug_assertions))]
+#[test]
+#[should_panic(expected = "expected PlaintextCount(2048) = PolynomialSize(1024) * \
+    GlweCiphertextCount(2), got PlaintextCount(2047)")]
+fn glwe_list_encrypt_mismatched_plaintext_count() {
+    let glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(1024);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    let mut rsc = TestResources::new();
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let mut glwe_list = GlweCiphertextList::new(
+        0u64,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        GlweCiphertextCount(2),
+        ciphertext_modulus,
+    );
+
+    // One plaintext short for two ciphertexts
+    let plaintext_list = PlaintextList::new(0u64, PlaintextCount(2 * polynomial_size.0 - 1));
+
+    encrypt_glwe_ciphertext_list(
+        &glwe_sk,
+        &mut glwe_list,
+        &plaintext_list,
+        Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0),
+        &mut rsc.encryption_random_generator,
+    );
+}
+
+// The dimension checks are skipped in release builds with the skip-dimension-checks feature
+#[cfg(any(not(feature = "skip-dimension-checks"), debug_assertions))]
+#[test]
+#[should_panic(expected = "expected PlaintextCount(2048) = PolynomialSize(1024) * \
+    GlweCiphertextCount(2), got PlaintextCount(1024)")]
+fn glwe_list_trivial_encrypt_mismatched_plaintext_count() {
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+    let mut glwe_list = GlweCiphertextList::new(
+        0u64,
+        GlweSize(2),
+        PolynomialSize(1024),
+        GlweCiphertextCount(2),
+        ciphertext_modulus,
+    );
+
+    let plaintext_list = PlaintextList::new(0u64, PlaintextCount(1024));
+
+    trivially_encrypt_glwe_ciphertext_list(&mut glwe_list, &plaintext_list);
+}
+
+fn glwe_encrypt_and_sample_extract_decrypt_custom_mod<Scalar: UnsignedTorus + CastFrom<usize>>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let equivalent_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+
+    // A different message in each coefficient, as in a lookup table
+    let msgs: Vec<Scalar> = (0..polynomial_size.0)
+        .map(|i| Scalar::cast_from(i) % msg_modulus)
+        .collect();
+    let plaintext_list =
+        PlaintextList::from_container(msgs.iter().map(|&msg| msg * delta).collect::<Vec<_>>());
+
+    for nth in [0, 1, polynomial_size.0 / 2, polynomial_size.0 - 1] {
+        let lwe = encrypt_glwe_and_sample_extract(
+            &glwe_sk,
+            &plaintext_list,
+            MonomialDegree(nth),
+            glwe_noise_distribution,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert_eq!(
+            lwe.lwe_size(),
+            equivalent_lwe_sk.lwe_dimension().to_lwe_size()
+        );
+        assert_eq!(lwe.ciphertext_modulus(), ciphertext_modulus);
+        assert!(check_encrypted_content_respects_mod(
+            &lwe,
+            ciphertext_modulus
+        ));
+
+        let decrypted = decrypt_lwe_ciphertext(&equivalent_lwe_sk, &lwe);
+        let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+        assert_eq!(decoded, msgs[nth]);
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_and_sample_extract_decrypt_custom_mod);
+
+#[test]
+#[should_panic(expected = "Cannot extract the coefficient")]
+fn glwe_encrypt_and_sample_extract_out_of_range() {
+    let mut rsc = TestResources::new();
+    let polynomial_size = PolynomialSize(512);
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        GlweDimension(1),
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+
+    let _ = encrypt_glwe_and_sample_extract(
+        &glwe_sk,
+        &plaintext_list,
+        MonomialDegree(polynomial_size.0),
+        Gaussian::from_dispersion_parameter(StandardDev(0.0), 0.0),
+        CiphertextModulus::new_native(),
+        &mut rsc.encryption_random_generator,
+    );
+}
+
+fn glwe_list_trivial_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let ct_count = GlweCiphertextCount(10);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let mut glwe_list = GlweCiphertextList::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ct_count,
+                ciphertext_modulus,
+            );
+
+            let plaintext_list = PlaintextList::new(
+                msg * delta,
+                PlaintextCount(glwe_list.polynomial_size().0 * ct_count.0),
+            );
+
+            trivially_encrypt_glwe_ciphertext_list(&mut glwe_list, &plaintext_list);
+
+            assert!(check_encrypted_content_respects_mod(
+                &glwe_list,
+                ciphertext_modulus
+            ));
+
+            assert!(glwe_list.iter().all(|glwe| glwe
+                .get_mask()
+                .as_ref()
+                .iter()
+                .all(|&x| x == Scalar::ZERO)));
+
+            let mut plaintext_list = PlaintextList::new(
+                Scalar::ZERO,
+                PlaintextCount(glwe_list.polynomial_size().0 * glwe_list.glwe_ciphertext_count().0),
+            );
+
+            decrypt_glwe_ciphertext_list(&glwe_sk, &glwe_list, &mut plaintext_list);
+
+            let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
+
+            decoded
+                .iter_mut()
+                .zip(plaintext_list.iter())
+                .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
+
+            assert!(decoded.iter().all(|&x| x == msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+create_parametrized_test!(glwe_list_trivial_encrypt_decrypt_custom_mod);
+
+fn glwe_allocate_trivial_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let lwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+            let ct = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+                glwe_dimension.to_glwe_size(),
+                &plaintext_list,
+                ciphertext_modulus,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &ct,
+                ciphertext_modulus
+            ));
+
+            let mut output_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+            decrypt_glwe_ciphertext(&lwe_sk, &ct, &mut output_plaintext_list);
+
+            output_plaintext_list
+                .iter_mut()
+                .for_each(|x| *x.0 = round_decode(*x.0, delta) % msg_modulus);
+
+            assert!(output_plaintext_list.iter().all(|x| *x.0 == msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+create_parametrized_test!(glwe_allocate_trivial_encrypt_decrypt_custom_mod);
+
+fn glwe_trivial_encrypt_without_mask_custom_mod<Scalar: UnsignedTorus + CastFrom<usize>>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let plaintext_list = PlaintextList::from_container(
+        (0..polynomial_size.0)
+            .map(|i| (Scalar::cast_from(i) % msg_modulus) * delta)
+            .collect::<Vec<_>>(),
+    );
+
+    // GlweSize(1) is a GlweDimension of 0, the ciphertext only has a body
+    let ct = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+        GlweSize(1),
+        &plaintext_list,
+        ciphertext_modulus,
+    );
+
+    assert_eq!(ct.glwe_size().to_glwe_dimension(), GlweDimension(0));
+    assert!(ct.get_mask().as_ref().is_empty());
+    assert!(check_encrypted_content_respects_mod(
+        &ct,
+        ciphertext_modulus
+    ));
+
+    let mut glwe = GlweCiphertext::new(
+        Scalar::ONE,
+        GlweSize(1),
+        polynomial_size,
+        ciphertext_modulus,
+    );
+    trivially_encrypt_glwe_ciphertext(&mut glwe, &plaintext_list);
+    assert_eq!(glwe, ct);
+
+    // The body is the same as the one of a trivial encryption with a mask
+    let reference_ct = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+        glwe_dimension.to_glwe_size(),
+        &plaintext_list,
+        ciphertext_modulus,
+    );
+    assert_eq!(ct.get_body().as_ref(), reference_ct.get_body().as_ref());
+}
+
+create_parametrized_test!(glwe_trivial_encrypt_without_mask_custom_mod);
+
+#[test]
+#[should_panic(expected = "requires a GlweSize of at least 1")]
+fn glwe_allocate_trivial_encrypt_zero_glwe_size() {
+    let plaintext_list = PlaintextList::new(0u64, PlaintextCount(1024));
+
+    let _ = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+        GlweSize(0),
+        &plaintext_list,
+        CiphertextModulus::new_native(),
+    );
+}
+
+fn glwe_seeded_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let mut seeded_glwe = SeededGlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                rsc.seeder.seed().into(),
+                ciphertext_modulus,
+            );
+
+            let plaintext_list =
+                PlaintextList::new(msg * delta, PlaintextCount(seeded_glwe.polynomial_size().0));
+
+            encrypt_seeded_glwe_ciphertext(
+                &glwe_sk,
+                &mut seeded_glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                rsc.seeder.as_mut(),
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &seeded_glwe,
+                ciphertext_modulus
+            ));
+
+            let decompressed_glwe = seeded_glwe.decompress_into_glwe_ciphertext();
+
+            assert!(check_encrypted_content_respects_mod(
+                &decompressed_glwe,
+                ciphertext_modulus
+            ));
+
+            let mut plaintext_list = PlaintextList::new(
+                Scalar::ZERO,
+                PlaintextCount(decompressed_glwe.polynomial_size().0),
+            );
+
+            decrypt_glwe_ciphertext(&glwe_sk, &decompressed_glwe, &mut plaintext_list);
+
+            let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
+
+            decoded
+                .iter_mut()
+                .zip(plaintext_list.iter())
+                .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
+
+            assert!(decoded.iter().all(|&x| x == msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+create_parametrized_test!(glwe_seeded_encrypt_decrypt_custom_mod);
+
+fn glwe_seeded_list_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let ct_count = GlweCiphertextCount(10);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let mut glwe_seeded_list = SeededGlweCiphertextList::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ct_count,
+                rsc.seeder.seed().into(),
+                ciphertext_modulus,
+            );
+
+            let plaintext_list = PlaintextList::new(
+                msg * delta,
+                PlaintextCount(glwe_seeded_list.polynomial_size().0 * ct_count.0),
+            );
+
+            encrypt_seeded_glwe_ciphertext_list(
+                &glwe_sk,
+                &mut glwe_seeded_list,
+                &plaintext_list,
+                glwe_noise_distribution,
+                rsc.seeder.as_mut(),
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &glwe_seeded_list,
+                ciphertext_modulus
+            ));
+
+            let glwe_list = glwe_seeded_list.decompress_into_glwe_ciphertext_list();
+
+            let mut plaintext_list = PlaintextList::new(
+                Scalar::ZERO,
+                PlaintextCount(glwe_list.polynomial_size().0 * glwe_list.glwe_ciphertext_count().0),
+            );
+
+            decrypt_glwe_ciphertext_list(&glwe_sk, &glwe_list, &mut plaintext_list);
+
+            let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
+
+            decoded
+                .iter_mut()
+                .zip(plaintext_list.iter())
+                .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
+
+            assert!(decoded.iter().all(|&x| x == msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+create_parametrized_test!(glwe_seeded_list_encrypt_decrypt_custom_mod);
+
+#[cfg(feature = "noise-analysis")]
+fn glwe_encrypt_noise_polynomial_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let half_delta = (delta / Scalar::TWO).into_signed();
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+            let trivial_glwe = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+                glwe_dimension.to_glwe_size(),
+                &plaintext_list,
+                ciphertext_modulus,
+            );
+
+            let trivial_noise =
+                glwe_ciphertext_noise_polynomial(&glwe_sk, &trivial_glwe, &plaintext_list);
+
+            assert_eq!(trivial_noise.len(), polynomial_size.0);
+            assert!(trivial_noise.iter().all(|&x| x == Scalar::Signed::ZERO));
+
+            let mut glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext(
+                &glwe_sk,
+                &mut glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let noise = glwe_ciphertext_noise_polynomial(&glwe_sk, &glwe, &plaintext_list);
+
+            assert_eq!(noise.len(), polynomial_size.0);
+            assert!(noise.iter().all(|&x| x > -half_delta && x < half_delta));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+#[cfg(feature = "noise-analysis")]
+create_parametrized_test!(glwe_encrypt_noise_polynomial_custom_mod);
+
+#[cfg(feature = "noise-analysis")]
+fn glwe_encrypt_and_return_noise_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let centering_shift = if ciphertext_modulus.is_native_modulus() {
+        0
+    } else {
+        Scalar::BITS - ciphertext_modulus.get_custom_modulus().ilog2() as usize
+    };
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+            let main_seed = rsc.seeder.seed();
+            let encryption_seed = rsc.seeder.seed();
+
+            let mut glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            let mut deterministic_seeder =
+                DeterministicSeeder::<ActivatedRandomGenerator>::new(main_seed);
+            let mut encryption_generator =
+                EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+                    encryption_seed,
+                    &mut deterministic_seeder,
+                );
+
+            encrypt_glwe_ciphertext(
+                &glwe_sk,
+                &mut glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut encryption_generator,
+            );
+
+            let mut glwe_with_noise = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            let mut deterministic_seeder =
+                DeterministicSeeder::<ActivatedRandomGenerator>::new(main_seed);
+            let mut encryption_generator =
+                EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+                    encryption_seed,
+                    &mut deterministic_seeder,
+                );
+
+            let sampled_noise = encrypt_glwe_ciphertext_and_return_noise(
+                &glwe_sk,
+                &mut glwe_with_noise,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut encryption_generator,
+            );
+
+            assert_eq!(glwe, glwe_with_noise);
+
+            let observed_noise =
+                glwe_ciphertext_noise_polynomial(&glwe_sk, &glwe_with_noise, &plaintext_list);
+
+            assert_eq!(sampled_noise.plaintext_count().0, polynomial_size.0);
+            assert!(sampled_noise
+                .iter()
+                .zip(observed_noise.iter())
+                .all(|(sampled, &observed)| {
+                    (*sampled.0 << centering_shift).into_signed() >> centering_shift == observed
+                }));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+#[cfg(feature = "noise-analysis")]
+create_parametrized_test!(glwe_encrypt_and_return_noise_custom_mod);
+
+fn glwe_decrypt_with_noise_estimate_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    // The noise is computed modulo the ciphertext modulus
+    let add_mod = |lhs: Scalar, rhs: Scalar| {
+        if ciphertext_modulus.is_native_modulus() {
+            lhs.wrapping_add(rhs)
+        } else {
+            lhs.wrapping_add_custom_mod(rhs, ciphertext_modulus.get_custom_modulus().cast_into())
+        }
+    };
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+            let mut glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext_custom_mod(
+                &glwe_sk,
+                &mut glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut decrypted_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, plaintext_list.plaintext_count());
+            let noise = decrypt_glwe_ciphertext_with_noise_estimate(
+                &glwe_sk,
+                &glwe,
+                &plaintext_list,
+                &mut decrypted_plaintext_list,
+            );
+
+            // The output is the same as for a regular decryption
+            let mut expected_decrypted_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, plaintext_list.plaintext_count());
+            decrypt_glwe_ciphertext_custom_mod(
+                &glwe_sk,
+                &glwe,
+                &mut expected_decrypted_plaintext_list,
+                ciphertext_modulus,
+            );
+
+            assert_eq!(decrypted_plaintext_list, expected_decrypted_plaintext_list);
+
+            assert!(decrypted_plaintext_list
+                .iter()
+                .zip(plaintext_list.iter().zip(noise.iter()))
+                .all(
+                    |(decrypted, (expected, noise))| *decrypted.0 == add_mod(*expected.0, *noise.0)
+                ));
+
+            let mut decoded = vec![Scalar::ZERO; decrypted_plaintext_list.plaintext_count().0];
+
+            decoded
+                .iter_mut()
+                .zip(decrypted_plaintext_list.iter())
+                .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
+
+            assert!(decoded.iter().all(|&x| x == msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+create_parametrized_test_with_non_native_parameters!(glwe_decrypt_with_noise_estimate_custom_mod);
+
+fn glwe_encrypt_with_noise_decrypt_custom_mod<Scalar: UnsignedTorus + CastFrom<usize>>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let centering_shift = if ciphertext_modulus.is_native_modulus() {
+        0
+    } else {
+        Scalar::BITS - ciphertext_modulus.get_custom_modulus().ilog2() as usize
+    };
+
+    // Small fixed error pattern with both signs, in the plaintext representation
+    let noise_list = PlaintextList::from_container(
+        (0..polynomial_size.0)
+            .map(|i| {
+                let noise = Scalar::cast_from(i % 8);
+                if i % 2 == 0 {
+                    noise
+                } else {
+                    noise.wrapping_neg()
+                }
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+            let main_seed = rsc.seeder.seed();
+            let encryption_seed = rsc.seeder.seed();
+
+            let mut reference_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            let mut deterministic_seeder =
+                DeterministicSeeder::<ActivatedRandomGenerator>::new(main_seed);
+            let mut encryption_generator =
+                EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+                    encryption_seed,
+                    &mut deterministic_seeder,
+                );
+
+            encrypt_glwe_ciphertext(
+                &glwe_sk,
+                &mut reference_glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut encryption_generator,
+            );
+
+            let mut glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            let mut deterministic_seeder =
+                DeterministicSeeder::<ActivatedRandomGenerator>::new(main_seed);
+            let mut encryption_generator =
+                EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+                    encryption_seed,
+                    &mut deterministic_seeder,
+                );
+
+            encrypt_glwe_ciphertext_with_noise(
+                &glwe_sk,
+                &mut glwe,
+                &plaintext_list,
+                &noise_list,
+                &mut encryption_generator,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &glwe,
+                ciphertext_modulus
+            ));
+
+            // Same seeds, the mask is sampled the same way as in encrypt_glwe_ciphertext
+            assert_eq!(glwe.get_mask().as_ref(), reference_glwe.get_mask().as_ref());
+
+            let mut output_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(glwe.polynomial_size().0));
+
+            decrypt_glwe_ciphertext(&glwe_sk, &glwe, &mut output_plaintext_list);
+
+            // The decryption error is exactly the provided noise
+            assert!(output_plaintext_list
+                .iter()
+                .zip(plaintext_list.iter())
+                .zip(noise_list.iter())
+                .all(|((decrypted, plaintext), noise)| {
+                    let error = decrypted.0.wrapping_sub(*plaintext.0);
+                    (error << centering_shift) == (*noise.0 << centering_shift)
+                }));
+
+            let mut decoded = vec![Scalar::ZERO; output_plaintext_list.plaintext_count().0];
+
+            decoded
+                .iter_mut()
+                .zip(output_plaintext_list.iter())
+                .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
+
+            assert!(decoded.iter().all(|&x| x == msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_with_noise_decrypt_custom_mod);
+
+/// Encrypt `message_modulus - 1`, the largest representable message, in every coefficient using
+/// fixed seeds, then shift the body so that the decryption error of every coefficient is exactly
+/// `tail_error` and `-tail_error`, checking the message is still decoded correctly.
+///
+/// Returns the errors sampled by the encryption so that callers can check them against the bound
+/// of `glwe_noise_distribution`.
+fn glwe_encrypt_decrypt_at_modulus_boundary_native_mod(
+    glwe_noise_distribution: DynamicDistribution<u64>,
+    tail_error: u64,
+) -> Vec<i64> {
+    let params = TEST_PARAMS_4_BITS_NATIVE_U64;
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let ciphertext_modulus = params.ciphertext_modulus;
+
+    let msg_modulus = 1u64 << params.message_modulus_log.0;
+    let delta = get_encoding_with_padding(ciphertext_modulus) / msg_modulus;
+    let msg = msg_modulus - 1;
+
+    assert!(tail_error < delta / 2);
+
+    let mut deterministic_seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(Seed(0));
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(deterministic_seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+        deterministic_seeder.seed(),
+        &mut deterministic_seeder,
+    );
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut secret_generator,
+    );
+
+    let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+    let mut glwe = GlweCiphertext::new(
+        0u64,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    encrypt_glwe_ciphertext(
+        &glwe_sk,
+        &mut glwe,
+        &plaintext_list,
+        glwe_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+    decrypt_glwe_ciphertext(&glwe_sk, &glwe, &mut output_plaintext_list);
+
+    let sampled_errors: Vec<i64> = output_plaintext_list
+        .iter()
+        .zip(plaintext_list.iter())
+        .map(|(decrypted, plaintext)| decrypted.0.wrapping_sub(*plaintext.0) as i64)
+        .collect();
+
+    assert!(output_plaintext_list
+        .iter()
+        .all(|decrypted| round_decode(*decrypted.0, delta) % msg_modulus == msg));
+
+    for target_error in [tail_error, tail_error.wrapping_neg()] {
+        // Replace the sampled error by the target one, the mask is left untouched
+        let mut glwe_at_tail = glwe.clone();
+        for (body, &sampled_error) in glwe_at_tail
+            .get_mut_body()
+            .as_mut()
+            .iter_mut()
+            .zip(sampled_errors.iter())
+        {
+            *body = body
+                .wrapping_sub(sampled_error as u64)
+                .wrapping_add(target_error);
+        }
+
+        decrypt_glwe_ciphertext(&glwe_sk, &glwe_at_tail, &mut output_plaintext_list);
+
+        assert!(output_plaintext_list
+            .iter()
+            .zip(plaintext_list.iter())
+            .all(|(decrypted, plaintext)| decrypted.0.wrapping_sub(*plaintext.0) == target_error));
+        assert!(output_plaintext_list
+            .iter()
+            .all(|decrypted| round_decode(*decrypted.0, delta) % msg_modulus == msg));
+    }
+
+    // An error of delta / 2 is rounded up to the next multiple of delta, which wraps the largest
+    // message around to 0: the tested errors are right at the edge of what decoding tolerates
+    let mut glwe_past_tail = glwe;
+    for (body, &sampled_error) in glwe_past_tail
+        .get_mut_body()
+        .as_mut()
+        .iter_mut()
+        .zip(sampled_errors.iter())
+    {
+        *body = body
+            .wrapping_sub(sampled_error as u64)
+            .wrapping_add(delta / 2);
+    }
+
+    decrypt_glwe_ciphertext(&glwe_sk, &glwe_past_tail, &mut output_plaintext_list);
+
+    assert!(output_plaintext_list
+        .iter()
+        .all(|decrypted| round_decode(*decrypted.0, delta) % msg_modulus == 0));
+
+    sampled_errors
+}
+
+#[test]
+fn glwe_encrypt_decrypt_t_uniform_at_modulus_boundary_native_mod() {
+    let params = TEST_PARAMS_4_BITS_NATIVE_U64;
+    let msg_modulus = 1u64 << params.message_modulus_log.0;
+    let delta = get_encoding_with_padding(params.ciphertext_modulus) / msg_modulus;
+
+    // A TUniform noise with bound_log2 b has an error in [-2^b, 2^b], largest b for which
+    // 2^b < delta / 2
+    let bound_log2 = delta.ilog2() - 2;
+
+    let sampled_errors = glwe_encrypt_decrypt_at_modulus_boundary_native_mod(
+        DynamicDistribution::new_t_uniform(bound_log2),
+        1u64 << bound_log2,
+    );
+
+    // The bound is a hard one, it holds for every sample, not with some probability
+    assert!(sampled_errors
+        .iter()
+        .all(|error| error.unsigned_abs() <= 1u64 << bound_log2));
+}
+
+#[test]
+fn glwe_encrypt_decrypt_gaussian_at_modulus_boundary_native_mod() {
+    let params = TEST_PARAMS_4_BITS_NATIVE_U64;
+    let msg_modulus = 1u64 << params.message_modulus_log.0;
+    let delta = get_encoding_with_padding(params.ciphertext_modulus) / msg_modulus;
+
+    // A Gaussian noise is not bounded, the tail error used is the largest one decoding tolerates.
+    // A fresh sample reaches it with probability erfc(delta / (2 * sqrt(2) * sigma)): with
+    // delta / 2 = 2^58 and sigma * 2^64 ~= 2^12.4 for these parameters that is more than 2^45
+    // standard deviations away, which does not happen in practice. The seeds are fixed anyway so
+    // the test is deterministic.
+    let sampled_errors = glwe_encrypt_decrypt_at_modulus_boundary_native_mod(
+        params.glwe_noise_distribution,
+        delta / 2 - 1,
+    );
+
+    assert!(sampled_errors
+        .iter()
+        .all(|error| error.unsigned_abs() < delta / 2));
+}
+
+#[test]
+fn glwe_decrypt_and_decode_native_mod() {
+    let params = TEST_PARAMS_4_BITS_NATIVE_U64;
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+
+    let mut rsc = TestResources::new();
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    for (message_modulus, carry_modulus) in [(2u64, 2u64), (4, 4), (8, 1)] {
+        let full_modulus = message_modulus * carry_modulus;
+        let delta = (1u64 << 63) / full_modulus;
+
+        let clear_list: Vec<u64> = (0..polynomial_size.0 as u64)
+            .map(|x| x % full_modulus)
+            .collect();
+        let plaintext_list =
+            PlaintextList::from_container(clear_list.iter().map(|x| x * delta).collect::<Vec<_>>());
+
+        let mut glwe = GlweCiphertext::new(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        encrypt_glwe_ciphertext(
+            &glwe_sk,
+            &mut glwe,
+            &plaintext_list,
+            glwe_noise_distribution,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let decoded =
+            decrypt_and_decode_glwe_ciphertext(&glwe_sk, &glwe, message_modulus, carry_modulus);
+
+        assert!(decoded
+            .iter()
+            .zip(clear_list.iter())
+            .all(|(&dec, &clear)| dec == clear % message_modulus));
+    }
+}
@@ -246,6 +246,20 @@ fn glwe_encrypt_plaintext_list_add_assign_decrypt_custom_mod<Scalar: UnsignedTor
 
 create_parametrized_test!(glwe_encrypt_plaintext_list_add_assign_decrypt_custom_mod);
 
+#[test]
+#[should_panic(expected = "Mismatched PolynomialSize of lhs GlweCiphertext")]
+fn glwe_plaintext_list_add_assign_mismatched_plaintext_count() {
+    let mut glwe = GlweCiphertext::new(
+        0u64,
+        GlweSize(2),
+        PolynomialSize(1024),
+        CiphertextModulus::new_native(),
+    );
+    let plaintext_list = PlaintextList::new(0u64, PlaintextCount(512));
+
+    glwe_ciphertext_plaintext_list_add_assign(&mut glwe, &plaintext_list);
+}
+
 fn glwe_encrypt_plaintext_list_sub_assign_decrypt_custom_mod<Scalar: UnsignedTorus>(
     params: ClassicTestParams<Scalar>,
 ) {
@@ -893,3 +907,14 @@ fn glwe_encrypt_sub_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTes
 }
 
 create_parametrized_test!(glwe_encrypt_sub_decrypt_custom_mod);
+
+#[test]
+#[should_panic(expected = "Mismatched GlweSize")]
+fn glwe_add_assign_mismatched_glwe_size() {
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+    let mut lhs = GlweCiphertext::new(0u64, GlweSize(2), PolynomialSize(1024), ciphertext_modulus);
+    // Same total length, different layout
+    let rhs = GlweCiphertext::new(0u64, GlweSize(4), PolynomialSize(512), ciphertext_modulus);
+
+    glwe_ciphertext_add_assign(&mut lhs, &rhs);
+}
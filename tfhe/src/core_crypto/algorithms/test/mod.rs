@@ -9,6 +9,7 @@ use paste::paste;
 use std::fmt::Debug;
 
 mod ggsw_encryption;
+mod glwe_encoding;
 mod glwe_encryption;
 mod glwe_linear_algebra;
 mod glwe_sample_extraction;
@@ -6,9 +6,9 @@ use crate::core_crypto::commons::math::random::{
 };
 use crate::core_crypto::commons::numeric::UnsignedInteger;
 use crate::core_crypto::commons::parameters::{
-    CiphertextModulus, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount, GlweDimension,
-    GlweSize, LweBskGroupingFactor, LweCiphertextCount, LweDimension, LweMaskCount, LweSize,
-    PolynomialSize,
+    CiphertextModulus, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweCiphertextCount, GlweDimension, GlweSize, LweBskGroupingFactor, LweCiphertextCount,
+    LweDimension, LweMaskCount, LweSize, PolynomialSize,
 };
 use concrete_csprng::generators::ForkError;
 use rayon::prelude::*;
@@ -36,6 +36,12 @@ impl<G: ByteRandomGenerator> MaskRandomGenerator<G> {
         }
     }
 
+    /// Rewind the generator in place, as if it had just been created with
+    /// [`MaskRandomGenerator::new`] from the provided [`Seed`].
+    pub fn reset_to_seed(&mut self, seed: Seed) {
+        self.gen = RandomGenerator::new(seed);
+    }
+
     pub fn remaining_bytes(&self) -> Option<usize> {
         self.gen.remaining_bytes()
     }
@@ -315,6 +321,19 @@ impl<G: ParallelByteRandomGenerator> MaskRandomGenerator<G> {
         self.par_try_fork(lwe_count.0, mask_bytes)
     }
 
+    // Forks the generator into a parallel iterator, when splitting a glwe ciphertext list into
+    // ciphertexts.
+    pub(crate) fn par_fork_glwe_list_to_glwe<T: UnsignedInteger>(
+        &mut self,
+        glwe_count: GlweCiphertextCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+    ) -> Result<impl IndexedParallelIterator<Item = Self>, ForkError> {
+        let mask_bytes = mask_elements_per_glwe(glwe_size.to_glwe_dimension(), polynomial_size)
+            .to_mask_byte_count(mask_bytes_per_coef::<T>());
+        self.par_try_fork(glwe_count.0, mask_bytes)
+    }
+
     // Forks the generator, when splitting a collection of pfpksk for cbs
     pub(crate) fn par_fork_cbs_pfpksk_to_pfpksk<T: UnsignedInteger>(
         &mut self,
@@ -10,8 +10,9 @@ use crate::core_crypto::commons::math::random::{
 };
 use crate::core_crypto::commons::numeric::UnsignedInteger;
 use crate::core_crypto::commons::parameters::{
-    CiphertextModulus, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount, GlweSize,
-    LweBskGroupingFactor, LweCiphertextCount, LweDimension, LweMaskCount, LweSize, PolynomialSize,
+    CiphertextModulus, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweCiphertextCount, GlweSize, LweBskGroupingFactor, LweCiphertextCount, LweDimension,
+    LweMaskCount, LweSize, PolynomialSize,
 };
 use concrete_csprng::generators::ForkError;
 use mask_random_generator::MaskRandomGenerator;
@@ -37,6 +38,70 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         }
     }
 
+    /// Reset an existing [`EncryptionRandomGenerator`] in place, using the provided [`Seed`] to
+    /// seed the public mask generator and using the provided [`Seeder`] to privately seed the
+    /// noise generator.
+    ///
+    /// The generator is then in the same state as one returned by
+    /// [`EncryptionRandomGenerator::new`] with the same arguments. Used with a deterministic
+    /// [`Seeder`], this allows replaying the exact same encryptions without building a new
+    /// generator, e.g. between benchmark iterations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::generators::DeterministicSeeder;
+    /// use tfhe::core_crypto::commons::math::random::Seed;
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// let lwe_dimension = LweDimension(742);
+    /// let lwe_noise_distribution =
+    ///     Gaussian::from_dispersion_parameter(StandardDev(0.000007069849454709433), 0.0);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    /// let plaintext = Plaintext(3u64 << 60);
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<ActivatedRandomGenerator>::new(Seed(1));
+    /// let lwe_secret_key =
+    ///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+    ///
+    /// let seed = Seed(0);
+    /// let mut encryption_generator = EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+    ///     seed,
+    ///     &mut DeterministicSeeder::<ActivatedRandomGenerator>::new(seed),
+    /// );
+    ///
+    /// let first = allocate_and_encrypt_new_lwe_ciphertext(
+    ///     &lwe_secret_key,
+    ///     plaintext,
+    ///     lwe_noise_distribution,
+    ///     ciphertext_modulus,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// // Rewind the generator, the same encryption yields the same ciphertext
+    /// encryption_generator.reset_to_seed(
+    ///     seed,
+    ///     &mut DeterministicSeeder::<ActivatedRandomGenerator>::new(seed),
+    /// );
+    ///
+    /// let second = allocate_and_encrypt_new_lwe_ciphertext(
+    ///     &lwe_secret_key,
+    ///     plaintext,
+    ///     lwe_noise_distribution,
+    ///     ciphertext_modulus,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// assert_eq!(first, second);
+    /// ```
+    // S is ?Sized to allow Box<dyn Seeder> to be passed.
+    pub fn reset_to_seed<S: Seeder + ?Sized>(&mut self, seed: Seed, seeder: &mut S) {
+        self.mask.reset_to_seed(seed);
+        self.noise.reseed(seeder);
+    }
+
     /// Return the number of remaining bytes for the mask generator, if the generator is bounded.
     pub fn remaining_bytes(&self) -> Option<usize> {
         self.mask.remaining_bytes()
@@ -485,6 +550,23 @@ impl<G: ParallelByteRandomGenerator> EncryptionRandomGenerator<G> {
         Ok(par_map_to_encryption_generator(mask_iter, noise_iter))
     }
 
+    // Forks the generator into a parallel iterator, when splitting a glwe ciphertext list into
+    // ciphertexts.
+    pub(crate) fn par_fork_glwe_list_to_glwe<T: UnsignedInteger>(
+        &mut self,
+        glwe_count: GlweCiphertextCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+    ) -> Result<impl IndexedParallelIterator<Item = Self>, ForkError> {
+        let mask_iter =
+            self.mask
+                .par_fork_glwe_list_to_glwe::<T>(glwe_count, glwe_size, polynomial_size)?;
+        let noise_iter = self
+            .noise
+            .par_fork_glwe_list_to_glwe(glwe_count, polynomial_size)?;
+        Ok(par_map_to_encryption_generator(mask_iter, noise_iter))
+    }
+
     // Forks the generator, when splitting a collection of pfpksk for cbs
     pub(crate) fn par_fork_cbs_pfpksk_to_pfpksk<T: UnsignedInteger>(
         &mut self,
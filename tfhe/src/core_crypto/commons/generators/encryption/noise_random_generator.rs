@@ -6,8 +6,9 @@ use crate::core_crypto::commons::math::random::{
 };
 use crate::core_crypto::commons::numeric::UnsignedInteger;
 use crate::core_crypto::commons::parameters::{
-    CiphertextModulus, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount, GlweSize,
-    LweBskGroupingFactor, LweCiphertextCount, LweDimension, LweMaskCount, LweSize, PolynomialSize,
+    CiphertextModulus, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweCiphertextCount, GlweSize, LweBskGroupingFactor, LweCiphertextCount, LweDimension,
+    LweMaskCount, LweSize, PolynomialSize,
 };
 use concrete_csprng::generators::ForkError;
 use rayon::prelude::*;
@@ -39,6 +40,13 @@ impl<G: ByteRandomGenerator> NoiseRandomGenerator<G> {
         }
     }
 
+    /// Reseed the generator in place, using the provided [`Seeder`] to privately seed it, as
+    /// [`NoiseRandomGenerator::new`] does.
+    // S is ?Sized to allow Box<dyn Seeder> to be passed.
+    pub fn reseed<S: Seeder + ?Sized>(&mut self, seeder: &mut S) {
+        self.gen = RandomGenerator::new(seeder.seed());
+    }
+
     pub fn remaining_bytes(&self) -> Option<usize> {
         self.gen.remaining_bytes()
     }
@@ -384,6 +392,18 @@ impl<G: ParallelByteRandomGenerator> NoiseRandomGenerator<G> {
         self.par_try_fork(lwe_count.0, noise_bytes)
     }
 
+    // Forks the generator into a parallel iterator, when splitting a glwe ciphertext list into
+    // ciphertexts.
+    pub(crate) fn par_fork_glwe_list_to_glwe(
+        &mut self,
+        glwe_count: GlweCiphertextCount,
+        polynomial_size: PolynomialSize,
+    ) -> Result<impl IndexedParallelIterator<Item = Self>, ForkError> {
+        let noise_bytes =
+            noise_elements_per_glwe(polynomial_size).to_noise_byte_count(noise_bytes_per_coef());
+        self.par_try_fork(glwe_count.0, noise_bytes)
+    }
+
     // Forks the generator, when splitting a collection of pfpksk for cbs
     pub(crate) fn par_fork_cbs_pfpksk_to_pfpksk(
         &mut self,
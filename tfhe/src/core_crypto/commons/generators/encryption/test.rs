@@ -1,15 +1,17 @@
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::dispersion::StandardDev;
-use crate::core_crypto::commons::math::random::Gaussian;
+use crate::core_crypto::commons::generators::{DeterministicSeeder, EncryptionRandomGenerator};
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Gaussian, Seed};
 use crate::core_crypto::commons::numeric::CastInto;
 use crate::core_crypto::commons::parameters::{
     CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension,
-    PolynomialSize,
+    PlaintextCount, PolynomialSize,
 };
 use crate::core_crypto::commons::test_tools::{
     new_encryption_random_generator, new_secret_random_generator, normality_test_f64,
 };
 use crate::core_crypto::commons::traits::UnsignedTorus;
+use crate::core_crypto::entities::{GlweCiphertext, PlaintextList};
 
 #[test]
 fn test_gaussian_sampling_margin_factor_does_not_panic() {
@@ -49,6 +51,52 @@ fn test_gaussian_sampling_margin_factor_does_not_panic() {
     );
 }
 
+#[test]
+fn test_reset_to_seed_replays_encryption() {
+    let glwe_size = GlweSize(2);
+    let polynomial_size = PolynomialSize(512);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+    let noise_distribution = Gaussian::from_standard_dev(StandardDev(2.0f64.powi(-30)), 0.0);
+
+    let mut sec_generator = new_secret_random_generator();
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_size.to_glwe_dimension(),
+        polynomial_size,
+        &mut sec_generator,
+    );
+    let plaintext_list = PlaintextList::new(1u64 << 60, PlaintextCount(polynomial_size.0));
+
+    let seed = Seed(0);
+    let mut enc_generator = EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+        seed,
+        &mut DeterministicSeeder::<ActivatedRandomGenerator>::new(seed),
+    );
+
+    let encrypt = |enc_generator: &mut EncryptionRandomGenerator<ActivatedRandomGenerator>| {
+        let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+        encrypt_glwe_ciphertext(
+            &glwe_sk,
+            &mut glwe,
+            &plaintext_list,
+            noise_distribution,
+            enc_generator,
+        );
+        glwe
+    };
+
+    let first = encrypt(&mut enc_generator);
+    // Without a reset the generator moves on to new randomness
+    let second = encrypt(&mut enc_generator);
+    assert_ne!(first, second);
+
+    enc_generator.reset_to_seed(
+        seed,
+        &mut DeterministicSeeder::<ActivatedRandomGenerator>::new(seed),
+    );
+    let replayed = encrypt(&mut enc_generator);
+    assert_eq!(first, replayed);
+}
+
 fn noise_gen_native<Scalar: UnsignedTorus>() {
     let mut gen = new_encryption_random_generator();
 
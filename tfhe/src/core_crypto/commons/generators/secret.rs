@@ -2,7 +2,7 @@
 //! generation.
 
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, UniformBinary,
+    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, UniformBinary, UniformTernary,
 };
 
 /// A random number generator which can be used to generate secret keys.
@@ -25,4 +25,11 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
     {
         self.0.fill_slice_with_random_uniform_binary(slice);
     }
+
+    pub(crate) fn fill_slice_with_random_uniform_ternary<Scalar>(&mut self, slice: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<UniformTernary>,
+    {
+        self.0.fill_slice_with_random_uniform_ternary(slice);
+    }
 }
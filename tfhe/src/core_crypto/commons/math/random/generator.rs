@@ -77,6 +77,10 @@ impl From<Seed> for CompressionSeed {
 /// }
 /// // use the parent to generate as many bytes as needed.
 /// ```
+// Number of random values generated at once through RandomGenerable::fill_slice when adding random
+// values to a slice, this lets distributions use their batched slice filling
+const SLICE_ADD_RANDOM_CHUNK_SIZE: usize = 64;
+
 pub struct RandomGenerator<G: ByteRandomGenerator>(G);
 
 impl<G: ByteRandomGenerator> RandomGenerator<G> {
@@ -326,9 +330,13 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
         D: Distribution,
         Scalar: UnsignedInteger + RandomGenerable<D>,
     {
-        for x in output.iter_mut() {
-            let random = Scalar::generate_one(self, distribution);
-            *x = (*x).wrapping_add(random);
+        let mut random = [Scalar::ZERO; SLICE_ADD_RANDOM_CHUNK_SIZE];
+        for output_chunk in output.chunks_mut(SLICE_ADD_RANDOM_CHUNK_SIZE) {
+            let random = &mut random[..output_chunk.len()];
+            Scalar::fill_slice(self, distribution, random);
+            for (x, &random) in output_chunk.iter_mut().zip(random.iter()) {
+                *x = (*x).wrapping_add(random);
+            }
         }
     }
     /// Add a random gaussian value to each element in a slice.
@@ -384,10 +392,13 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
         }
 
         let custom_modulus_as_scalar: Scalar = custom_modulus.get_custom_modulus().cast_into();
-        for x in output.iter_mut() {
-            let random =
-                Scalar::generate_one_custom_modulus(self, distribution, custom_modulus_as_scalar);
-            *x = (*x).wrapping_add_custom_mod(random, custom_modulus_as_scalar);
+        let mut random = [Scalar::ZERO; SLICE_ADD_RANDOM_CHUNK_SIZE];
+        for output_chunk in output.chunks_mut(SLICE_ADD_RANDOM_CHUNK_SIZE) {
+            let random = &mut random[..output_chunk.len()];
+            Scalar::fill_slice_custom_mod(self, distribution, random, custom_modulus_as_scalar);
+            for (x, &random) in output_chunk.iter_mut().zip(random.iter()) {
+                *x = (*x).wrapping_add_custom_mod(random, custom_modulus_as_scalar);
+            }
         }
     }
 
@@ -571,6 +582,27 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
         Scalar::generate_one(self, UniformTernary)
     }
 
+    /// Fill a slice with random uniform ternary values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![0u32; 1000];
+    /// generator.fill_slice_with_random_uniform_ternary(&mut vec);
+    /// assert!(vec.iter().all(|&x| x == 0 || x == 1 || x == u32::MAX));
+    /// assert!(vec.iter().any(|&x| x == u32::MAX));
+    /// ```
+    pub fn fill_slice_with_random_uniform_ternary<Scalar>(&mut self, output: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<UniformTernary>,
+    {
+        Scalar::fill_slice(self, UniformTernary, output);
+    }
+
     /// Generate two floating point values drawn from a gaussian distribution with input mean and
     /// standard deviation.
     ///
@@ -94,6 +94,7 @@ mod seal {
     impl Sealed for super::UniformTernary {}
     impl<T: FloatingPoint> Sealed for super::Gaussian<T> {}
     impl<T: UnsignedInteger> Sealed for super::TUniform<T> {}
+    impl<T: UnsignedInteger> Sealed for super::TUniformAsDispersion<T> {}
     impl<T: UnsignedInteger> Sealed for super::DynamicDistribution<T> {}
 }
 impl Distribution for Uniform {}
@@ -101,7 +102,12 @@ impl Distribution for UniformBinary {}
 impl Distribution for UniformTernary {}
 impl<T: FloatingPoint> Distribution for Gaussian<T> {}
 impl<T: UnsignedInteger> Distribution for TUniform<T> {}
+impl<T: UnsignedInteger> Distribution for TUniformAsDispersion<T> {}
 
+/// A noise distribution selected at runtime, wrapping either a [`Gaussian`] or a [`TUniform`].
+///
+/// Sampling dispatches to the wrapped distribution, this allows parameter structs to carry a noise
+/// distribution without being generic over it.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum DynamicDistribution<T: UnsignedInteger> {
     Gaussian(Gaussian<f64>),
@@ -140,6 +146,16 @@ impl<T: UnsignedInteger> DynamicDistribution<T> {
         }
     }
 
+    #[track_caller]
+    pub const fn t_uniform_bound_log2(&self) -> u32 {
+        match self {
+            Self::Gaussian(_) => {
+                panic!("Tried to get TUniform bound from a non TUniform distribution")
+            }
+            Self::TUniform(t_uniform) => t_uniform.bound_log2(),
+        }
+    }
+
     #[track_caller]
     pub fn gaussian_variance(&self) -> Variance {
         match self {
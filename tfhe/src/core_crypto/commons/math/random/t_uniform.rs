@@ -2,9 +2,41 @@ use super::*;
 use crate::core_crypto::commons::numeric::Numeric;
 use serde::{Deserialize, Serialize};
 
-/// The distribution $TUniform(1, -2^b, 2^b)$ is defined as follows, any value in the interval
-/// $\left[-2^b, 2^b\right]$ is selected with probability $\frac{1}{2^{b+1}}$, with the two end
-/// points $-2^b$ and $2^b$ being selected with probability $\frac{1}{2^{b+2}}$.
+/// The distribution $TUniform(1, -2^b, 2^b)$.
+///
+/// It is defined as follows, any value in the interval $\left[-2^b, 2^b\right]$ is selected with
+/// probability $\frac{1}{2^{b+1}}$, with the two end points $-2^b$ and $2^b$ being selected with
+/// probability $\frac{1}{2^{b+2}}$.
+///
+/// # Byte order
+///
+/// A sample consumes [`TUniform::bytes_per_sample`] bytes from the generator, the first byte
+/// being the least significant one of the $b + 2$ random bits: the sampled values only depend on
+/// the generated bytes and are identical on little and big endian platforms, which is required to
+/// reproduce keys and encryptions from a seed.
+///
+/// # Signed output
+///
+/// A `TUniform<T>` can also be sampled directly as the signed counterpart `T::Signed` of `T`,
+/// yielding the same values as the unsigned samples interpreted in two's complement:
+///
+/// ```rust
+/// use concrete_csprng::generators::SoftwareRandomGenerator;
+/// use concrete_csprng::seeders::Seed;
+/// use tfhe::core_crypto::commons::math::random::{RandomGenerator, TUniform};
+///
+/// let distribution = TUniform::<u64>::new(10);
+///
+/// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+/// let signed: i64 = generator.random_from_distribution(distribution);
+///
+/// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+/// let unsigned: u64 = generator.random_from_distribution(distribution);
+///
+/// assert_eq!(signed, unsigned as i64);
+/// assert!(distribution.min_value_inclusive() <= signed);
+/// assert!(signed <= distribution.max_value_inclusive());
+/// ```
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct TUniform<T: UnsignedInteger> {
     bound_log2: u32,
@@ -22,6 +54,15 @@ impl<T: UnsignedInteger> TUniform<T> {
     /// The reason for this is that with a higher `bound_log2` it is impossible to distinguish
     /// between $-2^{bound\_log2}$ and $2^{bound\_log2}$ because of the 2's complement
     /// representation of integers.
+    ///
+    /// Being a const fn, an invalid `bound_log2` in a constant is reported at compile time:
+    ///
+    /// ```compile_fail
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// const NOISE_DISTRIBUTION: TUniform<u64> = TUniform::new(63);
+    /// # let _ = NOISE_DISTRIBUTION;
+    /// ```
     pub const fn new(bound_log2: u32) -> Self {
         match Self::try_new(bound_log2) {
             Ok(result) => result,
@@ -41,7 +82,7 @@ impl<T: UnsignedInteger> TUniform<T> {
     /// between $-2^{bound\_log2}$ and $2^{bound\_log2}$ because of the 2's complement
     /// representation of integers.
     pub const fn try_new(bound_log2: u32) -> Result<Self, &'static str> {
-        if (bound_log2 + 2) as usize > T::BITS {
+        if !Self::is_valid_bound_log2(bound_log2) {
             return Err("Cannot create TUnfirorm: \
             bound_log2 + 2 is greater than the current type's bit width");
         }
@@ -52,6 +93,21 @@ impl<T: UnsignedInteger> TUniform<T> {
         })
     }
 
+    /// Return whether `bound_log2` is a valid bound for the Scalar type, i.e. whether
+    /// [`TUniform::try_new`] succeeds for it.
+    ///
+    /// This can be used in static assertions in parameter definitions:
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// const LWE_NOISE_BOUND_LOG2: u32 = 46;
+    /// const _: () = assert!(TUniform::<u64>::is_valid_bound_log2(LWE_NOISE_BOUND_LOG2));
+    /// ```
+    pub const fn is_valid_bound_log2(bound_log2: u32) -> bool {
+        bound_log2 as u64 + 2 <= T::BITS as u64
+    }
+
     pub const fn bound_log2(&self) -> u32 {
         self.bound_log2
     }
@@ -60,6 +116,19 @@ impl<T: UnsignedInteger> TUniform<T> {
         (1 << (self.bound_log2 + 1)) + 1
     }
 
+    /// Return the number of random bytes consumed from the generator to sample one value, i.e.
+    /// $\left\lceil \frac{b + 2}{8} \right\rceil$.
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// assert_eq!(TUniform::<u64>::new(6).bytes_per_sample(), 1);
+    /// assert_eq!(TUniform::<u64>::new(46).bytes_per_sample(), 6);
+    /// ```
+    pub const fn bytes_per_sample(&self) -> usize {
+        (self.bound_log2 + 2).div_ceil(u8::BITS) as usize
+    }
+
     pub fn min_value_inclusive(&self) -> T::Signed {
         -(T::Signed::ONE << self.bound_log2 as usize)
     }
@@ -67,8 +136,162 @@ impl<T: UnsignedInteger> TUniform<T> {
     pub fn max_value_inclusive(&self) -> T::Signed {
         T::Signed::ONE << self.bound_log2 as usize
     }
+
+    /// Return the interval $\left[-2^b, 2^b\right]$ the values are sampled from, as a
+    /// `(min_value_inclusive, max_value_inclusive)` tuple.
+    pub fn interval(&self) -> (T::Signed, T::Signed) {
+        (self.min_value_inclusive(), self.max_value_inclusive())
+    }
+
+    /// Return the probability $\frac{1}{2^{b+2}}$ of sampling one of the two end points $-2^b$ and
+    /// $2^b$.
+    pub fn probability_endpoint(&self) -> f64 {
+        2.0f64.powi(-(self.bound_log2 as i32 + 2))
+    }
+
+    /// Return the probability $\frac{1}{2^{b+1}}$ of sampling a value strictly inside the interval
+    /// $\left]-2^b, 2^b\right[$.
+    pub fn probability_interior(&self) -> f64 {
+        2.0f64.powi(-(self.bound_log2 as i32 + 1))
+    }
+
+    /// Const variant of [`TUniform::min_value_inclusive`], the value is returned as an `i128` as
+    /// the Scalar type operations cannot be used in const fns.
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// const NOISE_DISTRIBUTION: TUniform<u64> = TUniform::new(46);
+    /// const _: () = assert!(NOISE_DISTRIBUTION.min_value_inclusive_i128() == -(1 << 46));
+    /// ```
+    pub const fn min_value_inclusive_i128(&self) -> i128 {
+        -(1i128 << self.bound_log2)
+    }
+
+    /// Const variant of [`TUniform::max_value_inclusive`], the value is returned as an `i128` as
+    /// the Scalar type operations cannot be used in const fns.
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// const NOISE_DISTRIBUTION: TUniform<u64> = TUniform::new(46);
+    /// const _: () = assert!(NOISE_DISTRIBUTION.max_value_inclusive_i128() == 1 << 46);
+    /// ```
+    pub const fn max_value_inclusive_i128(&self) -> i128 {
+        1i128 << self.bound_log2
+    }
+
+    /// Generate `n` values from the distribution using the given generator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::{RandomGenerator, TUniform};
+    ///
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let distribution = TUniform::<u64>::new(2);
+    /// let values = distribution.generate_n(&mut generator, 16);
+    /// assert_eq!(values.len(), 16);
+    /// assert!(values.iter().all(|&x| {
+    ///     let x = x as i64;
+    ///     x >= distribution.min_value_inclusive() && x <= distribution.max_value_inclusive()
+    /// }));
+    /// ```
+    pub fn generate_n<G: ByteRandomGenerator>(
+        &self,
+        generator: &mut RandomGenerator<G>,
+        n: usize,
+    ) -> Vec<T>
+    where
+        T: RandomGenerable<Self>,
+    {
+        (0..n).map(|_| T::generate_one(generator, *self)).collect()
+    }
+
+    /// Return the variance of the distribution, $\frac{2^{2b+1} + 1}{6}$.
+    ///
+    /// The variance is expressed for the integer values sampled by the distribution, i.e. it is
+    /// not normalized by the modulus like the variance of a [`Gaussian`] on the torus.
+    pub fn variance(&self) -> Variance {
+        variance_for_tuniform_bound(self.bound_log2)
+    }
+}
+
+/// Return the variance $\frac{2^{2b+1} + 1}{6}$ of a [`TUniform`] distribution with the given
+/// `bound_log2` $b$, see [`TUniform::variance`].
+///
+/// This is the inverse of [`tuniform_bound_for_variance`].
+pub fn variance_for_tuniform_bound(bound_log2: u32) -> Variance {
+    Variance((2.0f64.powi(2 * bound_log2 as i32 + 1) + 1.0) / 6.0)
 }
 
+/// Return the smallest `bound_log2` whose [`TUniform`] variance is at least `variance`.
+///
+/// Like [`TUniform::variance`], `variance` is expressed for the integer values sampled by the
+/// distribution. The variance of a [`Gaussian`] defined on the torus must first be scaled to the
+/// modulus, e.g. with [`DispersionParameter::get_modular_variance`].
+///
+/// The returned bound is not checked against a Scalar type, use
+/// [`TUniform::is_valid_bound_log2`] before building the distribution.
+///
+/// # Panics
+///
+/// Panics if `variance` is not finite.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::dispersion::{DispersionParameter, StandardDev, Variance};
+/// use tfhe::core_crypto::commons::math::random::{
+///     tuniform_bound_for_variance, variance_for_tuniform_bound,
+/// };
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // Port a Gaussian noise defined on the torus to a TUniform noise over the native u64 modulus
+/// let std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let modular_variance = Variance(std_dev.get_modular_variance(u64::BITS));
+///
+/// let bound_log2 = tuniform_bound_for_variance(modular_variance);
+/// assert!(TUniform::<u64>::is_valid_bound_log2(bound_log2));
+///
+/// let distribution = TUniform::<u64>::new(bound_log2);
+/// assert!(distribution.variance().0 >= modular_variance.0);
+///
+/// // One less would not preserve the variance
+/// assert!(variance_for_tuniform_bound(bound_log2 - 1).0 < modular_variance.0);
+/// ```
+pub fn tuniform_bound_for_variance(variance: Variance) -> u32 {
+    assert!(
+        variance.0.is_finite(),
+        "variance must be finite, got {}",
+        variance.0
+    );
+
+    // The variance grows as 4^b, the loop ends after at most ~512 iterations for f64::MAX
+    let mut bound_log2 = 0;
+    loop {
+        if variance_for_tuniform_bound(bound_log2).0 >= variance.0 {
+            return bound_log2;
+        }
+        bound_log2 += 1;
+    }
+}
+
+/// Map `bound_log2 + 2` uniformly random bits to a value of the $TUniform(1, -2^b, 2^b)$
+/// distribution.
+fn t_uniform_from_random_bits<T: UnsignedInteger>(random_bits: T, bound_log2: u32) -> T {
+    let bit_b_p_1 = random_bits & T::ONE;
+    (random_bits >> 1)
+        .wrapping_add(bit_b_p_1)
+        .wrapping_sub(T::ONE << bound_log2 as usize)
+}
+
+// Number of random bytes generated at once when filling slices, the bytes are consumed in the same
+// order as with generate_one so that both paths yield the same values
+const FILL_SLICE_CHUNK_BYTES: usize = 512;
+
 macro_rules! implement_t_uniform_uint {
     ($T:ty) => {
         impl RandomGenerable<TUniform<$T>> for $T {
@@ -88,14 +311,69 @@ macro_rules! implement_t_uniform_uint {
                 buf.iter_mut()
                     .take(required_bytes)
                     .for_each(|a| *a = generator.generate_next());
-                // We use from_le_bytes as most platforms are low endian, this avoids endianness
-                // issues
+                // from_le_bytes makes the first generated byte the least significant one whatever
+                // the host endianness, see the TUniform docs
                 let native_int_random = <$T>::from_le_bytes(buf);
-                let mut candidate_for_random = native_int_random & mod_mask;
-                let bit_b_p_1 = candidate_for_random & 1;
-                candidate_for_random >>= 1;
-                candidate_for_random = candidate_for_random.wrapping_add(bit_b_p_1);
-                candidate_for_random.wrapping_sub(1 << bound_log2)
+                t_uniform_from_random_bits(native_int_random & mod_mask, bound_log2)
+            }
+
+            fn generate_one_custom_modulus<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                distribution: TUniform<$T>,
+                custom_modulus: Self::CustomModulus,
+            ) -> Self {
+                let native_value = Self::generate_one(generator, distribution);
+                // Negative values are mapped to the upper half of [0; custom_modulus[
+                if native_value >> (<$T>::BITS - 1) == 1 {
+                    native_value.wrapping_add(custom_modulus)
+                } else {
+                    native_value
+                }
+            }
+
+            fn fill_slice<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                TUniform { bound_log2, .. }: TUniform<$T>,
+                slice: &mut [Self],
+            ) {
+                let required_bits = bound_log2 + 2;
+                let required_bytes = required_bits.div_ceil(u8::BITS) as usize;
+                let mod_mask = <$T>::MAX >> (<$T>::BITS - required_bits);
+
+                let mut bytes = [0u8; FILL_SLICE_CHUNK_BYTES];
+                let values_per_chunk = FILL_SLICE_CHUNK_BYTES / required_bytes;
+
+                for chunk in slice.chunks_mut(values_per_chunk) {
+                    let bytes = &mut bytes[..chunk.len() * required_bytes];
+                    for a in bytes.iter_mut() {
+                        *a = generator.generate_next();
+                    }
+
+                    for (s, value_bytes) in chunk.iter_mut().zip(bytes.chunks_exact(required_bytes))
+                    {
+                        let mut buf = [0; std::mem::size_of::<$T>()];
+                        buf[..required_bytes].copy_from_slice(value_bytes);
+                        // from_le_bytes makes the first generated byte the least significant one
+                        // whatever the host endianness, see the TUniform docs
+                        let native_int_random = <$T>::from_le_bytes(buf);
+                        *s = t_uniform_from_random_bits(native_int_random & mod_mask, bound_log2);
+                    }
+                }
+            }
+
+            fn fill_slice_custom_mod<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                distribution: TUniform<$T>,
+                slice: &mut [Self],
+                custom_modulus: Self::CustomModulus,
+            ) {
+                Self::fill_slice(generator, distribution, slice);
+                // Negative values are mapped to the upper half of [0; custom_modulus[
+                for s in slice.iter_mut() {
+                    if *s >> (<$T>::BITS - 1) == 1 {
+                        *s = s.wrapping_add(custom_modulus);
+                    }
+                }
             }
         }
     };
@@ -106,3 +384,149 @@ implement_t_uniform_uint!(u16);
 implement_t_uniform_uint!(u32);
 implement_t_uniform_uint!(u64);
 implement_t_uniform_uint!(u128);
+
+// The signed values are the two's complement reinterpretation of the unsigned samples: the same
+// random bytes are consumed and both end points keep their $\frac{1}{2^{b+2}}$ probability.
+// TODO: as for Uniform, custom moduli are not supported for the generation of signed integers
+macro_rules! implement_t_uniform_int {
+    ($T:ty, $U:ty) => {
+        impl RandomGenerable<TUniform<$U>> for $T {
+            type CustomModulus = $T;
+            fn generate_one<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                distribution: TUniform<$U>,
+            ) -> Self {
+                <$U>::generate_one(generator, distribution) as $T
+            }
+        }
+    };
+}
+
+implement_t_uniform_int!(i8, u8);
+implement_t_uniform_int!(i16, u16);
+implement_t_uniform_int!(i32, u32);
+implement_t_uniform_int!(i64, u64);
+implement_t_uniform_int!(i128, u128);
+
+/// An adapter exposing a [`TUniform`] distribution through the [`DispersionParameter`] trait.
+///
+/// The dispersion reported is the one of the wrapped [`TUniform`], normalized on the torus like a
+/// [`Gaussian`] standard deviation: for a `TUniform<T>` the variance returned by
+/// [`TUniform::variance`] is divided by $2^{2 \cdot T::BITS}$. Noise formulas written for a
+/// [`DispersionParameter`] can then be evaluated for a [`TUniform`] noise.
+///
+/// The adapter is also a [`Distribution`]: encrypting with it, e.g. with
+/// [`encrypt_glwe_ciphertext`](`crate::core_crypto::algorithms::encrypt_glwe_ciphertext`),
+/// samples the wrapped [`TUniform`] and yields the same values as encrypting with the
+/// [`TUniform`] itself.
+///
+/// # Semantic mismatch
+///
+/// [`DispersionParameter`] assumes a Gaussian-like distribution fully described by its standard
+/// deviation, which is not the case of a [`TUniform`]: its values are bounded by $2^b$ and it has
+/// no tail. The reported standard deviation must therefore not be used to recreate the
+/// distribution, e.g. with [`Gaussian::from_dispersion_parameter`] or
+/// [`DynamicDistribution::new_gaussian`], as this silently switches the sampling to a Gaussian.
+/// Convert the adapter with [`DynamicDistribution::from`] to keep the [`TUniform`] sampling, and
+/// do not derive failure probabilities from the standard deviation as is done for a Gaussian.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::dispersion::DispersionParameter;
+/// use tfhe::core_crypto::commons::math::random::TUniformAsDispersion;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let t_uniform = TUniform::<u64>::new(10);
+/// let dispersion = TUniformAsDispersion::new(t_uniform);
+///
+/// // The modular variance over the native u64 modulus is the TUniform variance
+/// let modular_variance = dispersion.get_modular_variance(u64::BITS);
+/// assert!((modular_variance - t_uniform.variance().0).abs() <= t_uniform.variance().0 * 1e-9);
+///
+/// // The conversion keeps the TUniform sampling
+/// assert_eq!(
+///     DynamicDistribution::from(dispersion),
+///     DynamicDistribution::TUniform(t_uniform)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TUniformAsDispersion<T: UnsignedInteger>(pub TUniform<T>);
+
+impl<T: UnsignedInteger> TUniformAsDispersion<T> {
+    pub const fn new(t_uniform: TUniform<T>) -> Self {
+        Self(t_uniform)
+    }
+
+    /// Return the wrapped [`TUniform`] distribution.
+    pub const fn t_uniform(&self) -> TUniform<T> {
+        self.0
+    }
+}
+
+impl<T: UnsignedInteger> DispersionParameter for TUniformAsDispersion<T> {
+    fn get_standard_dev(&self) -> f64 {
+        self.get_variance().sqrt()
+    }
+    fn get_variance(&self) -> f64 {
+        // The TUniform variance is expressed for values modulo 2^T::BITS
+        self.0.variance().0 / 2_f64.powi(2 * T::BITS as i32)
+    }
+    fn get_log_standard_dev(&self) -> f64 {
+        self.get_standard_dev().log2()
+    }
+    fn get_modular_standard_dev(&self, log2_modulus: u32) -> f64 {
+        2_f64.powf(log2_modulus as f64 + self.get_log_standard_dev())
+    }
+    fn get_modular_variance(&self, log2_modulus: u32) -> f64 {
+        2_f64.powf(2. * (log2_modulus as f64 + self.get_log_standard_dev()))
+    }
+    fn get_modular_log_standard_dev(&self, log2_modulus: u32) -> f64 {
+        log2_modulus as f64 + self.get_log_standard_dev()
+    }
+}
+
+impl<T: UnsignedInteger> From<TUniformAsDispersion<T>> for DynamicDistribution<T> {
+    fn from(value: TUniformAsDispersion<T>) -> Self {
+        Self::TUniform(value.0)
+    }
+}
+
+// Sampling is forwarded to the wrapped TUniform so that the adapter yields the same values
+impl<T: UnsignedInteger + RandomGenerable<TUniform<T>, CustomModulus = T>>
+    RandomGenerable<TUniformAsDispersion<T>> for T
+{
+    type CustomModulus = Self;
+
+    fn generate_one<G: ByteRandomGenerator>(
+        generator: &mut RandomGenerator<G>,
+        distribution: TUniformAsDispersion<T>,
+    ) -> Self {
+        Self::generate_one(generator, distribution.0)
+    }
+
+    fn generate_one_custom_modulus<G: ByteRandomGenerator>(
+        generator: &mut RandomGenerator<G>,
+        distribution: TUniformAsDispersion<T>,
+        custom_modulus: Self::CustomModulus,
+    ) -> Self {
+        Self::generate_one_custom_modulus(generator, distribution.0, custom_modulus)
+    }
+
+    fn fill_slice<G: ByteRandomGenerator>(
+        generator: &mut RandomGenerator<G>,
+        distribution: TUniformAsDispersion<T>,
+        slice: &mut [Self],
+    ) {
+        Self::fill_slice(generator, distribution.0, slice);
+    }
+
+    fn fill_slice_custom_mod<G: ByteRandomGenerator>(
+        generator: &mut RandomGenerator<G>,
+        distribution: TUniformAsDispersion<T>,
+        slice: &mut [Self],
+        custom_modulus: Self::CustomModulus,
+    ) {
+        Self::fill_slice_custom_mod(generator, distribution.0, slice, custom_modulus);
+    }
+}
@@ -1,9 +1,15 @@
 use crate::core_crypto::algorithms::misc::check_clear_content_respects_mod;
 use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
-use crate::core_crypto::commons::math::random::{Distribution, RandomGenerable, TUniform, Uniform};
+use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardDev, Variance};
+use crate::core_crypto::commons::math::random::{
+    tuniform_bound_for_variance, variance_for_tuniform_bound, ActivatedRandomGenerator,
+    Distribution, DynamicDistribution, Gaussian, RandomGenerable, RandomGenerator, TUniform,
+    TUniformAsDispersion, Uniform,
+};
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
-use crate::core_crypto::commons::numeric::{CastFrom, CastInto, UnsignedInteger};
+use crate::core_crypto::commons::numeric::{CastFrom, CastInto, Numeric, UnsignedInteger};
 use crate::core_crypto::commons::test_tools::*;
+use concrete_csprng::seeders::Seed;
 
 fn test_normal_random_three_sigma<T: UnsignedTorus>() {
     //! test if the normal random generation with std_dev is below 3*std_dev (99.7%)
@@ -625,12 +631,18 @@ impl<Scalar: UnsignedInteger + CastFrom<usize> + CastInto<usize>> DistributionTe
         value: Self::CreationInfos,
         ciphertext_modulus: CiphertextModulus<Scalar>,
     ) -> Self {
-        assert!(ciphertext_modulus.is_native_modulus());
-        Self::new(value)
+        let distribution = Self::new(value);
+        if !ciphertext_modulus.is_native_modulus() {
+            assert!(
+                distribution.distinct_value_count() as u128
+                    <= ciphertext_modulus.get_custom_modulus(),
+                "TUniform bound is too big for {ciphertext_modulus:?}"
+            );
+        }
+        distribution
     }
 
     fn distinct_values(&self, _ciphertext_modulus: CiphertextModulus<Scalar>) -> usize {
-        // TUniform does not support non native moduli for now
         self.distinct_value_count()
     }
 
@@ -640,8 +652,9 @@ impl<Scalar: UnsignedInteger + CastFrom<usize> + CastInto<usize>> DistributionTe
         ciphertext_modulus: CiphertextModulus<Scalar>,
     ) -> f64 {
         let max_value_inclusive = self.max_value_inclusive();
-        let integer_value_signed: Scalar::Signed = integer_value.cast_into();
         let value_index: usize = self.map_value_to_usize(integer_value, ciphertext_modulus);
+        let value_index_signed: Scalar::Signed = Scalar::cast_from(value_index).cast_into();
+        let integer_value_signed = value_index_signed + self.min_value_inclusive();
         // CDF for the TUniform distribution
         if integer_value_signed == max_value_inclusive {
             1.0
@@ -654,7 +667,7 @@ impl<Scalar: UnsignedInteger + CastFrom<usize> + CastInto<usize>> DistributionTe
     fn map_usize_to_value(
         &self,
         input: usize,
-        _ciphertext_modulus: CiphertextModulus<Scalar>,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
     ) -> Scalar {
         // Input is in [0; 2^(b + 1)]
         let input_as_scalar = Scalar::cast_from(input);
@@ -663,14 +676,31 @@ impl<Scalar: UnsignedInteger + CastFrom<usize> + CastInto<usize>> DistributionTe
         let min_value_inclusive = self.min_value_inclusive();
         // This is in [-2^b; 2^b]
         let value_as_signed = input_as_signed_scalar + min_value_inclusive;
-        Scalar::cast_from(value_as_signed)
+        let value = Scalar::cast_from(value_as_signed);
+        // For non native moduli negative values are represented in the upper half of [0; q[
+        if !ciphertext_modulus.is_native_modulus() && value_as_signed < Scalar::Signed::ZERO {
+            value.wrapping_add(ciphertext_modulus.get_custom_modulus().cast_into())
+        } else {
+            value
+        }
     }
 
     fn map_value_to_usize(
         &self,
         input: Scalar,
-        _ciphertext_modulus: CiphertextModulus<Scalar>,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
     ) -> usize {
+        // Bring values from the upper half of [0; q[ back to negative values
+        let input = if ciphertext_modulus.is_native_modulus() {
+            input
+        } else {
+            let custom_modulus: Scalar = ciphertext_modulus.get_custom_modulus().cast_into();
+            if input >= custom_modulus / Scalar::TWO {
+                input.wrapping_sub(custom_modulus)
+            } else {
+                input
+            }
+        };
         // Input is in [-2^b; 2^b]
         let input_as_signed_scalar: Scalar::Signed = input.cast_into();
         let min_value_inclusive = self.min_value_inclusive();
@@ -690,3 +720,586 @@ fn test_t_uniform_random_u64() {
     let ciphertext_modulus = CiphertextModulus::new_native();
     test_random_from_distribution_custom_mod::<u64, TUniform<_>>(bound_log2, ciphertext_modulus);
 }
+
+fn test_t_uniform_variance<Scalar: UnsignedTorus>() {
+    const NB_SAMPLES: usize = 1_000_000;
+
+    for bound_log2 in [0u32, 2, 11] {
+        let distribution = TUniform::<Scalar>::new(bound_log2);
+
+        let mut rng = new_random_generator();
+        let samples: Vec<f64> = (0..NB_SAMPLES)
+            .map(|_| {
+                let sample: Scalar = rng.random_from_distribution(distribution);
+                let sample: Scalar::Signed = sample.into_signed();
+                sample.cast_into()
+            })
+            .collect();
+
+        let expected_variance = distribution.variance().0;
+        let empirical_variance = variance(&samples).0;
+
+        // Relative error of the empirical variance is about sqrt(2 / NB_SAMPLES), use a large
+        // margin to avoid spurious failures
+        let relative_error = (empirical_variance - expected_variance).abs() / expected_variance;
+        assert!(
+            relative_error < 0.01,
+            "bound_log2={bound_log2}, expected={expected_variance}, empirical={empirical_variance}"
+        );
+    }
+}
+
+#[test]
+fn test_t_uniform_variance_u32() {
+    test_t_uniform_variance::<u32>();
+}
+
+#[test]
+fn test_t_uniform_variance_u64() {
+    test_t_uniform_variance::<u64>();
+}
+
+#[test]
+fn test_t_uniform_bound_for_variance() {
+    for bound_log2 in 0..=62u32 {
+        let variance = variance_for_tuniform_bound(bound_log2);
+        assert_eq!(variance, TUniform::<u64>::new(bound_log2).variance());
+
+        // The exact variance of a bound maps back to that bound
+        assert_eq!(tuniform_bound_for_variance(variance), bound_log2);
+
+        // Anything slightly above requires the next bound
+        let above = Variance(variance.0 * (1.0 + 1e-9));
+        assert_eq!(tuniform_bound_for_variance(above), bound_log2 + 1);
+    }
+
+    assert_eq!(tuniform_bound_for_variance(Variance(0.0)), 0);
+    assert_eq!(tuniform_bound_for_variance(Variance(-1.0)), 0);
+}
+
+#[test]
+#[should_panic(expected = "variance must be finite")]
+fn test_t_uniform_bound_for_infinite_variance() {
+    let _ = tuniform_bound_for_variance(Variance(f64::INFINITY));
+}
+
+fn test_t_uniform_const_bounds<Scalar: UnsignedTorus>() {
+    for bound_log2 in 0..Scalar::BITS as u32 + 2 {
+        let is_valid = TUniform::<Scalar>::is_valid_bound_log2(bound_log2);
+        assert_eq!(is_valid, TUniform::<Scalar>::try_new(bound_log2).is_ok());
+
+        if !is_valid {
+            continue;
+        }
+
+        // The bounds are powers of 2 and are exactly represented as f64
+        let distribution = TUniform::<Scalar>::new(bound_log2);
+        let min_value: f64 = distribution.min_value_inclusive().cast_into();
+        let max_value: f64 = distribution.max_value_inclusive().cast_into();
+        assert_eq!(distribution.min_value_inclusive_i128() as f64, min_value);
+        assert_eq!(distribution.max_value_inclusive_i128() as f64, max_value);
+    }
+}
+
+#[test]
+fn test_t_uniform_const_bounds_u32() {
+    test_t_uniform_const_bounds::<u32>();
+}
+
+#[test]
+fn test_t_uniform_const_bounds_u64() {
+    test_t_uniform_const_bounds::<u64>();
+}
+
+fn test_t_uniform_interval_and_probabilities<Scalar: UnsignedTorus>() {
+    for bound_log2 in 0..Scalar::BITS as u32 - 1 {
+        let distribution = TUniform::<Scalar>::new(bound_log2);
+
+        assert_eq!(
+            distribution.interval(),
+            (
+                distribution.min_value_inclusive(),
+                distribution.max_value_inclusive()
+            )
+        );
+
+        assert_eq!(
+            distribution.probability_interior(),
+            2.0 * distribution.probability_endpoint()
+        );
+
+        // The probability mass sums to 1, up to the f64 precision of the interior value count
+        let interior_value_count = (distribution.distinct_value_count() - 2) as f64;
+        let total_probability = 2.0 * distribution.probability_endpoint()
+            + interior_value_count * distribution.probability_interior();
+        assert!((total_probability - 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_t_uniform_interval_and_probabilities_u32() {
+    test_t_uniform_interval_and_probabilities::<u32>();
+}
+
+#[test]
+fn test_t_uniform_interval_and_probabilities_u64() {
+    test_t_uniform_interval_and_probabilities::<u64>();
+}
+
+fn test_t_uniform_fill_slice_matches_generate_one<Scalar: UnsignedTorus>() {
+    // Longer than a batch of random bytes for every bound
+    const SLICE_LEN: usize = 1000;
+    let custom_modulus = Scalar::ONE << (Scalar::BITS - 2);
+
+    for bound_log2 in 0..Scalar::BITS as u32 - 3 {
+        let distribution = TUniform::<Scalar>::new(bound_log2);
+
+        let mut generator_one = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+        let mut generator_slice = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+
+        let expected: Vec<Scalar> = (0..SLICE_LEN)
+            .map(|_| Scalar::generate_one(&mut generator_one, distribution))
+            .collect();
+        let mut output = vec![Scalar::ZERO; SLICE_LEN];
+        generator_slice.fill_slice_with_random_from_distribution(&mut output, distribution);
+        assert_eq!(output, expected);
+
+        let expected: Vec<Scalar> = (0..SLICE_LEN)
+            .map(|_| {
+                Scalar::generate_one_custom_modulus(
+                    &mut generator_one,
+                    distribution,
+                    custom_modulus,
+                )
+            })
+            .collect();
+        Scalar::fill_slice_custom_mod(
+            &mut generator_slice,
+            distribution,
+            &mut output,
+            custom_modulus,
+        );
+        assert_eq!(output, expected);
+
+        // Both paths consumed the same number of random bytes
+        assert_eq!(
+            generator_one.generate_next(),
+            generator_slice.generate_next()
+        );
+    }
+}
+
+#[test]
+fn test_t_uniform_fill_slice_matches_generate_one_u32() {
+    test_t_uniform_fill_slice_matches_generate_one::<u32>();
+}
+
+#[test]
+fn test_t_uniform_fill_slice_matches_generate_one_u64() {
+    test_t_uniform_fill_slice_matches_generate_one::<u64>();
+}
+
+fn test_t_uniform_as_dispersion_matches_t_uniform<Scalar>()
+where
+    Scalar: UnsignedTorus + RandomGenerable<TUniform<Scalar>, CustomModulus = Scalar>,
+{
+    const NB_SAMPLES: usize = 1000;
+
+    for bound_log2 in 0..Scalar::BITS as u32 - 1 {
+        let distribution = TUniform::<Scalar>::new(bound_log2);
+        let dispersion = TUniformAsDispersion::new(distribution);
+
+        // The dispersion is the TUniform variance normalized on the torus
+        let modular_variance = dispersion.get_modular_variance(Scalar::BITS as u32);
+        let expected_variance = distribution.variance().0;
+        assert!((modular_variance - expected_variance).abs() <= expected_variance * 1e-9);
+        assert_eq!(
+            dispersion.get_standard_dev(),
+            dispersion.get_variance().sqrt()
+        );
+
+        let mut generator_t_uniform = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+        let mut generator_dispersion = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+
+        for _ in 0..NB_SAMPLES {
+            let expected: Scalar = generator_t_uniform.random_from_distribution(distribution);
+            let sample: Scalar = generator_dispersion.random_from_distribution(dispersion);
+            assert_eq!(sample, expected);
+        }
+
+        let mut expected = vec![Scalar::ZERO; NB_SAMPLES];
+        let mut samples = vec![Scalar::ZERO; NB_SAMPLES];
+        generator_t_uniform.fill_slice_with_random_from_distribution(&mut expected, distribution);
+        generator_dispersion.fill_slice_with_random_from_distribution(&mut samples, dispersion);
+        assert_eq!(samples, expected);
+    }
+}
+
+#[test]
+fn test_t_uniform_as_dispersion_matches_t_uniform_u32() {
+    test_t_uniform_as_dispersion_matches_t_uniform::<u32>();
+}
+
+#[test]
+fn test_t_uniform_as_dispersion_matches_t_uniform_u64() {
+    test_t_uniform_as_dispersion_matches_t_uniform::<u64>();
+}
+
+fn test_t_uniform_signed_matches_unsigned<Scalar>()
+where
+    Scalar: UnsignedTorus,
+    Scalar::Signed: RandomGenerable<TUniform<Scalar>>,
+{
+    const NB_SAMPLES: usize = 1000;
+
+    for bound_log2 in 0..Scalar::BITS as u32 - 1 {
+        let distribution = TUniform::<Scalar>::new(bound_log2);
+
+        let mut generator_unsigned = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+        let mut generator_signed = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+
+        for _ in 0..NB_SAMPLES {
+            let unsigned: Scalar = generator_unsigned.random_from_distribution(distribution);
+            let signed: Scalar::Signed = generator_signed.random_from_distribution(distribution);
+
+            assert_eq!(signed, unsigned.into_signed());
+            assert!(distribution.min_value_inclusive() <= signed);
+            assert!(signed <= distribution.max_value_inclusive());
+        }
+
+        // Both paths consumed the same number of random bytes
+        assert_eq!(
+            generator_unsigned.generate_next(),
+            generator_signed.generate_next()
+        );
+    }
+}
+
+#[test]
+fn test_t_uniform_signed_matches_unsigned_u32() {
+    test_t_uniform_signed_matches_unsigned::<u32>();
+}
+
+#[test]
+fn test_t_uniform_signed_matches_unsigned_u64() {
+    test_t_uniform_signed_matches_unsigned::<u64>();
+}
+
+#[test]
+fn test_t_uniform_signed_endpoints_probability() {
+    const NB_SAMPLES: usize = 1_000_000;
+
+    // Values in [-2; 2], the end points having half the probability of the interior values
+    let distribution = TUniform::<u64>::new(1);
+    let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+
+    let mut counts = [0usize; 5];
+    for _ in 0..NB_SAMPLES {
+        let value: i64 = generator.random_from_distribution(distribution);
+        counts[(value - distribution.min_value_inclusive()) as usize] += 1;
+    }
+
+    for (index, &count) in counts.iter().enumerate() {
+        let expected_probability = if index == 0 || index == counts.len() - 1 {
+            distribution.probability_endpoint()
+        } else {
+            distribution.probability_interior()
+        };
+        let observed_probability = count as f64 / NB_SAMPLES as f64;
+
+        // Far above the standard deviation of the estimation, ~5e-4
+        assert!(
+            (observed_probability - expected_probability).abs() < 5e-3,
+            "value {}: observed probability {observed_probability}, \
+            expected {expected_probability}",
+            index as i64 + distribution.min_value_inclusive()
+        );
+    }
+}
+
+fn test_t_uniform_bytes_per_sample<Scalar: UnsignedTorus>() {
+    for bound_log2 in 0..Scalar::BITS as u32 - 1 {
+        let distribution = TUniform::<Scalar>::new(bound_log2);
+
+        let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+        let mut reference_generator = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+
+        let _: Scalar = generator.random_from_distribution(distribution);
+        for _ in 0..distribution.bytes_per_sample() {
+            reference_generator.generate_next();
+        }
+
+        // Both generators are at the same position in the random byte stream
+        let next_bytes: Vec<u8> = (0..16).map(|_| generator.generate_next()).collect();
+        let reference_next_bytes: Vec<u8> = (0..16)
+            .map(|_| reference_generator.generate_next())
+            .collect();
+        assert_eq!(next_bytes, reference_next_bytes);
+    }
+}
+
+#[test]
+fn test_t_uniform_bytes_per_sample_u32() {
+    test_t_uniform_bytes_per_sample::<u32>();
+}
+
+#[test]
+fn test_t_uniform_bytes_per_sample_u64() {
+    test_t_uniform_bytes_per_sample::<u64>();
+}
+
+// Values generated for bound_log2 = 2 from Seed(0), both u32 and u64 consume a single byte per
+// sample so the sequences are identical
+const T_UNIFORM_BOUND_LOG2_2_SEED_0_VALUES: [i8; 16] =
+    [1, 2, -2, 4, 1, 2, 2, 0, 2, 1, 1, 1, -2, 2, 3, 0];
+
+fn test_t_uniform_generate_n_test_vector<Scalar: UnsignedTorus>() {
+    let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+    let distribution = TUniform::<Scalar>::new(2);
+
+    let values =
+        distribution.generate_n(&mut generator, T_UNIFORM_BOUND_LOG2_2_SEED_0_VALUES.len());
+
+    let expected: Vec<Scalar> = T_UNIFORM_BOUND_LOG2_2_SEED_0_VALUES
+        .iter()
+        .map(|&x| Scalar::cast_from(x as u128))
+        .collect();
+
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn test_t_uniform_generate_n_test_vector_u32() {
+    test_t_uniform_generate_n_test_vector::<u32>();
+}
+
+#[test]
+fn test_t_uniform_generate_n_test_vector_u64() {
+    test_t_uniform_generate_n_test_vector::<u64>();
+}
+
+#[test]
+fn test_t_uniform_random_custom_mod_u64() {
+    // Means the random will be in [-2048; 2048]
+    let bound_log2 = 11u32;
+    let ciphertext_modulus = CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+    test_random_from_distribution_custom_mod::<u64, TUniform<_>>(bound_log2, ciphertext_modulus);
+}
+
+fn test_t_uniform_custom_mod_maps_native_samples<Scalar: UnsignedTorus>(custom_modulus: u128) {
+    const NB_SAMPLES: usize = 1000;
+
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new(custom_modulus).unwrap();
+    let modulus: Scalar = Scalar::cast_from(custom_modulus);
+    let distribution = TUniform::<Scalar>::new(11);
+
+    let mut native_rng = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+    let mut custom_mod_rng = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+
+    let native_samples: Vec<Scalar> = (0..NB_SAMPLES)
+        .map(|_| native_rng.random_from_distribution(distribution))
+        .collect();
+    let custom_mod_samples: Vec<Scalar> = (0..NB_SAMPLES)
+        .map(|_| {
+            custom_mod_rng.random_from_distribution_custom_mod(distribution, ciphertext_modulus)
+        })
+        .collect();
+
+    for (&native, &custom) in native_samples.iter().zip(custom_mod_samples.iter()) {
+        assert!(custom < modulus);
+        if native >> (Scalar::BITS - 1) == Scalar::ONE {
+            // -x is represented as 2^BITS - x natively and as custom_modulus - x under the custom
+            // modulus
+            assert_eq!(modulus - custom, native.wrapping_neg());
+        } else {
+            assert_eq!(custom, native);
+        }
+    }
+
+    // The slice filling path applies the same mapping
+    let mut filled = vec![Scalar::ZERO; NB_SAMPLES];
+    let mut fill_rng = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+    fill_rng.fill_slice_with_random_from_distribution_custom_mod(
+        &mut filled,
+        distribution,
+        ciphertext_modulus,
+    );
+    assert_eq!(filled, custom_mod_samples);
+}
+
+#[test]
+fn test_t_uniform_custom_mod_maps_native_samples_u32() {
+    test_t_uniform_custom_mod_maps_native_samples::<u32>(12289);
+    test_t_uniform_custom_mod_maps_native_samples::<u32>((1 << 31) - 1);
+}
+
+#[test]
+fn test_t_uniform_custom_mod_maps_native_samples_u64() {
+    test_t_uniform_custom_mod_maps_native_samples::<u64>(12289);
+    test_t_uniform_custom_mod_maps_native_samples::<u64>((1 << 64) - (1 << 32) + 1);
+}
+
+fn test_dynamic_distribution_dispatch<Scalar: UnsignedTorus>() {
+    const NB_SAMPLES: usize = 1000;
+
+    let gaussian = Gaussian::from_standard_dev(StandardDev(2.0f64.powi(-20)), 0.0);
+    let t_uniform = TUniform::<Scalar>::new(11);
+
+    let seed = random_seed();
+    let mut dynamic_rng = RandomGenerator::<ActivatedRandomGenerator>::new(seed);
+    let mut expected_rng = RandomGenerator::<ActivatedRandomGenerator>::new(seed);
+
+    for _ in 0..NB_SAMPLES {
+        let dynamic_sample: Scalar =
+            dynamic_rng.random_from_distribution(DynamicDistribution::Gaussian(gaussian));
+        let expected_sample: Scalar = expected_rng.random_from_distribution(gaussian);
+        assert_eq!(dynamic_sample, expected_sample);
+
+        let dynamic_sample: Scalar =
+            dynamic_rng.random_from_distribution(DynamicDistribution::TUniform(t_uniform));
+        let expected_sample: Scalar = expected_rng.random_from_distribution(t_uniform);
+        assert_eq!(dynamic_sample, expected_sample);
+    }
+
+    let dynamic_t_uniform = DynamicDistribution::TUniform(t_uniform);
+    assert_eq!(dynamic_t_uniform.t_uniform_bound_log2(), 11);
+}
+
+#[test]
+fn test_dynamic_distribution_dispatch_u32() {
+    test_dynamic_distribution_dispatch::<u32>();
+}
+
+#[test]
+fn test_dynamic_distribution_dispatch_u64() {
+    test_dynamic_distribution_dispatch::<u64>();
+}
+
+fn test_t_uniform_bound_log2_0_distribution<Scalar>()
+where
+    Scalar: UnsignedInteger + RandomGenerable<TUniform<Scalar>>,
+{
+    const NB_SAMPLES: usize = 100_000;
+
+    let distribution = TUniform::<Scalar>::new(0);
+    assert_eq!(distribution.distinct_value_count(), 3);
+
+    let mut rng = new_random_generator();
+
+    let mut samples: Vec<Scalar> = (0..NB_SAMPLES)
+        .map(|_| rng.random_from_distribution(distribution))
+        .collect();
+    let mut filled = vec![Scalar::ZERO; NB_SAMPLES];
+    rng.fill_slice_with_random_from_distribution(&mut filled, distribution);
+    samples.extend(filled);
+
+    // -1 is represented as Scalar::MAX
+    let minus_one_count = samples.iter().filter(|&&x| x == Scalar::MAX).count();
+    let zero_count = samples.iter().filter(|&&x| x == Scalar::ZERO).count();
+    let one_count = samples.iter().filter(|&&x| x == Scalar::ONE).count();
+    assert_eq!(minus_one_count + zero_count + one_count, samples.len());
+
+    // The standard deviation of the empirical frequencies is below 2e-3, use a large margin to
+    // avoid spurious failures
+    for (count, expected_probability) in [
+        (minus_one_count, distribution.probability_endpoint()),
+        (zero_count, distribution.probability_interior()),
+        (one_count, distribution.probability_endpoint()),
+    ] {
+        let frequency = count as f64 / samples.len() as f64;
+        assert!(
+            (frequency - expected_probability).abs() < 0.01,
+            "expected={expected_probability}, empirical={frequency}"
+        );
+    }
+}
+
+#[test]
+fn test_t_uniform_bound_log2_0_distribution_u8() {
+    test_t_uniform_bound_log2_0_distribution::<u8>();
+}
+
+#[test]
+fn test_t_uniform_bound_log2_0_distribution_u16() {
+    test_t_uniform_bound_log2_0_distribution::<u16>();
+}
+
+#[test]
+fn test_t_uniform_bound_log2_0_distribution_u32() {
+    test_t_uniform_bound_log2_0_distribution::<u32>();
+}
+
+#[test]
+fn test_t_uniform_bound_log2_0_distribution_u64() {
+    test_t_uniform_bound_log2_0_distribution::<u64>();
+}
+
+#[test]
+fn test_t_uniform_bound_log2_0_distribution_u128() {
+    test_t_uniform_bound_log2_0_distribution::<u128>();
+}
+
+// Check the TUniform samples against a reconstruction of the random bits with arithmetic shifts,
+// which does not depend on the host byte order
+fn test_t_uniform_byte_order<Scalar>()
+where
+    Scalar: UnsignedInteger + RandomGenerable<TUniform<Scalar>>,
+{
+    const NB_SAMPLES: usize = 64;
+
+    for bound_log2 in 0..Scalar::BITS as u32 - 1 {
+        let distribution = TUniform::<Scalar>::new(bound_log2);
+        let required_bits = bound_log2 + 2;
+        let required_bytes = distribution.bytes_per_sample();
+
+        let mut bytes_generator = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+        let expected: Vec<Scalar> = (0..NB_SAMPLES)
+            .map(|_| {
+                let random_bits = (0..required_bytes).fold(0u128, |acc, byte_idx| {
+                    acc | (u128::from(bytes_generator.generate_next()) << (8 * byte_idx))
+                }) & (u128::MAX >> (u128::BITS - required_bits));
+                let random_bits = Scalar::cast_from(random_bits);
+
+                (random_bits >> 1)
+                    .wrapping_add(random_bits & Scalar::ONE)
+                    .wrapping_sub(Scalar::ONE << bound_log2 as usize)
+            })
+            .collect();
+
+        let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+        let samples: Vec<Scalar> = (0..NB_SAMPLES)
+            .map(|_| generator.random_from_distribution(distribution))
+            .collect();
+        assert_eq!(samples, expected);
+
+        let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+        let mut samples = vec![Scalar::ZERO; NB_SAMPLES];
+        generator.fill_slice_with_random_from_distribution(&mut samples, distribution);
+        assert_eq!(samples, expected);
+    }
+}
+
+#[test]
+fn test_t_uniform_byte_order_u8() {
+    test_t_uniform_byte_order::<u8>();
+}
+
+#[test]
+fn test_t_uniform_byte_order_u16() {
+    test_t_uniform_byte_order::<u16>();
+}
+
+#[test]
+fn test_t_uniform_byte_order_u32() {
+    test_t_uniform_byte_order::<u32>();
+}
+
+#[test]
+fn test_t_uniform_byte_order_u64() {
+    test_t_uniform_byte_order::<u64>();
+}
+
+#[test]
+fn test_t_uniform_byte_order_u128() {
+    test_t_uniform_byte_order::<u128>();
+}
@@ -87,7 +87,24 @@ macro_rules! dbgx {
     };
 }
 
+/// An [`assert!`] checking the dimensions of the inputs and outputs of an algorithm.
+///
+/// It is always checked unless the `skip-dimension-checks` feature is enabled, in which case it
+/// becomes a [`debug_assert!`] and is compiled out of release builds.
+#[allow(unused_macros)]
+macro_rules! dimension_assert {
+    ($($arg:tt)*) => {
+        if cfg!(feature = "skip-dimension-checks") {
+            debug_assert!($($arg)*);
+        } else {
+            assert!($($arg)*);
+        }
+    };
+}
+
 #[allow(unused_imports)]
 pub(crate) use dbgx;
 #[allow(unused_imports)]
+pub(crate) use dimension_assert;
+#[allow(unused_imports)]
 pub(crate) use izip;
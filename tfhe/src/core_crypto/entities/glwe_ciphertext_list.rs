@@ -243,3 +243,388 @@ impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> ContiguousEntit
     where
         Self: 'this;
 }
+
+/// Return the shift and the number of bits required to losslessly store a coefficient of a
+/// ciphertext under the given [`CiphertextModulus`].
+///
+/// Non native power of 2 moduli store their coefficients in the MSBs of the `Scalar`, the LSBs are
+/// always 0 and are shifted away. Other moduli store their coefficients in the LSBs.
+fn packed_coefficient_layout<Scalar: UnsignedInteger>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> (usize, usize) {
+    if ciphertext_modulus.is_native_modulus() {
+        (0, Scalar::BITS)
+    } else if ciphertext_modulus.is_power_of_two() {
+        let bit_width = ciphertext_modulus.get_custom_modulus().ilog2() as usize;
+        (Scalar::BITS - bit_width, bit_width)
+    } else {
+        // Non power of 2 moduli, values are in [0, modulus[ so we need the ceil of the log2
+        let bit_width = ciphertext_modulus.get_custom_modulus().ilog2() as usize + 1;
+        (0, bit_width)
+    }
+}
+
+fn pack_coefficients<Scalar: UnsignedInteger>(
+    coefficients: &[Scalar],
+    shift: usize,
+    bit_width: usize,
+    output: &mut Vec<u8>,
+) {
+    let mut acc = 0u128;
+    let mut acc_bits = 0usize;
+
+    for &coeff in coefficients {
+        let mut value: u128 = (coeff >> shift).cast_into();
+        let mut remaining_bits = bit_width;
+        // Push at most 64 bits at a time so that the accumulator (which holds less than 8 pending
+        // bits) can never overflow
+        while remaining_bits > 0 {
+            let chunk_bits = remaining_bits.min(64);
+            let chunk_mask = (1u128 << chunk_bits) - 1;
+            acc |= (value & chunk_mask) << acc_bits;
+            acc_bits += chunk_bits;
+            value >>= chunk_bits;
+            remaining_bits -= chunk_bits;
+
+            while acc_bits >= u8::BITS as usize {
+                output.push(acc as u8);
+                acc >>= u8::BITS;
+                acc_bits -= u8::BITS as usize;
+            }
+        }
+    }
+
+    if acc_bits > 0 {
+        output.push(acc as u8);
+    }
+}
+
+fn unpack_coefficients<Scalar: UnsignedInteger>(
+    packed: &[u8],
+    shift: usize,
+    bit_width: usize,
+    output: &mut [Scalar],
+) {
+    let mut packed_iter = packed.iter();
+    let mut acc = 0u128;
+    let mut acc_bits = 0usize;
+
+    for coeff in output.iter_mut() {
+        let mut value = 0u128;
+        let mut value_bits = 0usize;
+        while value_bits < bit_width {
+            let chunk_bits = (bit_width - value_bits).min(64);
+            while acc_bits < chunk_bits {
+                let byte = *packed_iter
+                    .next()
+                    .expect("Packed data is too short for the requested coefficient count");
+                acc |= u128::from(byte) << acc_bits;
+                acc_bits += u8::BITS as usize;
+            }
+            let chunk_mask = (1u128 << chunk_bits) - 1;
+            value |= (acc & chunk_mask) << value_bits;
+            acc >>= chunk_bits;
+            acc_bits -= chunk_bits;
+            value_bits += chunk_bits;
+        }
+        *coeff = Scalar::cast_from(value) << shift;
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PackedGlweCiphertextList<Scalar: UnsignedInteger> {
+    packed_data: Vec<u8>,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    glwe_ciphertext_count: GlweCiphertextCount,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+fn packed_glwe_ciphertext_byte_count(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    bit_width: usize,
+) -> usize {
+    (glwe_ciphertext_size(glwe_size, polynomial_size) * bit_width).div_ceil(u8::BITS as usize)
+}
+
+impl<Scalar: UnsignedInteger + serde::Serialize, C: Container<Element = Scalar>>
+    GlweCiphertextList<C>
+{
+    /// Serialize the [`GlweCiphertextList`] in `writer`, storing each coefficient on the number of
+    /// bits required by the [`CiphertextModulus`] of the list instead of the full `Scalar` width.
+    ///
+    /// Each [`GlweCiphertext`] is packed independently, starting on a byte boundary. The packing is
+    /// lossless, e.g. a list with a $2^{48}$ modulus stored in `u64` is 25% smaller. Native moduli
+    /// do not benefit from the packing.
+    ///
+    /// Use [`GlweCiphertextListOwned::deserialize_compressed`] to recover the list.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let glwe_size = GlweSize(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let ciphertext_count = GlweCiphertextCount(2);
+    /// let ciphertext_modulus = CiphertextModulus::try_new_power_of_2(48).unwrap();
+    ///
+    /// let glwe_list = GlweCiphertextList::new(
+    ///     1u64 << 63,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     ciphertext_count,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// let mut buffer = vec![];
+    /// glwe_list.serialize_compressed(&mut buffer).unwrap();
+    ///
+    /// let uncompressed = bincode::serialize(&glwe_list).unwrap();
+    /// assert!(buffer.len() < uncompressed.len());
+    ///
+    /// let deserialized = GlweCiphertextListOwned::<u64>::deserialize_compressed(&*buffer).unwrap();
+    /// assert_eq!(deserialized, glwe_list);
+    /// ```
+    pub fn serialize_compressed<W: std::io::Write>(&self, writer: W) -> bincode::Result<()> {
+        let ciphertext_modulus = self.ciphertext_modulus();
+        let (shift, bit_width) = packed_coefficient_layout(ciphertext_modulus);
+
+        let glwe_ciphertext_count = self.glwe_ciphertext_count();
+        let mut packed_data = Vec::with_capacity(
+            packed_glwe_ciphertext_byte_count(self.glwe_size(), self.polynomial_size(), bit_width)
+                * glwe_ciphertext_count.0,
+        );
+
+        for glwe in self.iter() {
+            pack_coefficients(glwe.as_ref(), shift, bit_width, &mut packed_data);
+        }
+
+        let packed = PackedGlweCiphertextList {
+            packed_data,
+            glwe_size: self.glwe_size(),
+            polynomial_size: self.polynomial_size(),
+            glwe_ciphertext_count,
+            ciphertext_modulus,
+        };
+
+        bincode::serialize_into(writer, &packed)
+    }
+}
+
+impl<Scalar: UnsignedInteger + serde::de::DeserializeOwned> GlweCiphertextListOwned<Scalar> {
+    /// Deserialize a [`GlweCiphertextList`] serialized with
+    /// [`GlweCiphertextList::serialize_compressed`] from `reader`.
+    ///
+    /// See [`GlweCiphertextList::serialize_compressed`] for usage.
+    pub fn deserialize_compressed<R: std::io::Read>(reader: R) -> bincode::Result<Self> {
+        let PackedGlweCiphertextList {
+            packed_data,
+            glwe_size,
+            polynomial_size,
+            glwe_ciphertext_count,
+            ciphertext_modulus,
+        } = bincode::deserialize_from::<_, PackedGlweCiphertextList<Scalar>>(reader)?;
+
+        if glwe_size.0 == 0 || polynomial_size.0 == 0 {
+            return Err(Box::new(bincode::ErrorKind::Custom(format!(
+                "Invalid GlweCiphertextList dimensions, {glwe_size:?} and {polynomial_size:?} must \
+                be non zero"
+            ))));
+        }
+
+        let (shift, bit_width) = packed_coefficient_layout(ciphertext_modulus);
+
+        let Some((packed_glwe_byte_count, packed_data_len)) = glwe_size
+            .0
+            .checked_mul(polynomial_size.0)
+            .and_then(|glwe_ciphertext_size| glwe_ciphertext_size.checked_mul(bit_width))
+            .map(|packed_glwe_bit_count| packed_glwe_bit_count.div_ceil(u8::BITS as usize))
+            .and_then(|packed_glwe_byte_count| {
+                packed_glwe_byte_count
+                    .checked_mul(glwe_ciphertext_count.0)
+                    .map(|packed_data_len| (packed_glwe_byte_count, packed_data_len))
+            })
+        else {
+            return Err(Box::new(bincode::ErrorKind::Custom(format!(
+                "Invalid GlweCiphertextList dimensions, the packed data length for \
+                {glwe_ciphertext_count:?} with {glwe_size:?} and {polynomial_size:?} overflows \
+                usize"
+            ))));
+        };
+
+        if packed_data.len() != packed_data_len {
+            return Err(Box::new(bincode::ErrorKind::Custom(format!(
+                "Invalid packed data length, expected {packed_data_len} bytes for \
+                {glwe_ciphertext_count:?} with {glwe_size:?} and {polynomial_size:?}, got {} bytes",
+                packed_data.len()
+            ))));
+        }
+
+        let mut list = Self::new(
+            Scalar::ZERO,
+            glwe_size,
+            polynomial_size,
+            glwe_ciphertext_count,
+            ciphertext_modulus,
+        );
+
+        for (mut glwe, packed_glwe) in list
+            .iter_mut()
+            .zip(packed_data.chunks_exact(packed_glwe_byte_count))
+        {
+            unpack_coefficients(packed_glwe, shift, bit_width, glwe.as_mut());
+        }
+
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core_crypto::prelude::test::TestResources;
+
+    fn compressed_serialization_round_trip<Scalar>(ciphertext_modulus: CiphertextModulus<Scalar>)
+    where
+        Scalar: UnsignedTorus + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let mut rsc = TestResources::new();
+
+        let glwe_size = GlweSize(3);
+        let polynomial_size = PolynomialSize(256);
+        let ciphertext_count = GlweCiphertextCount(3);
+
+        let mut glwe_list = GlweCiphertextList::new(
+            Scalar::ZERO,
+            glwe_size,
+            polynomial_size,
+            ciphertext_count,
+            ciphertext_modulus,
+        );
+
+        rsc.encryption_random_generator
+            .fill_slice_with_random_uniform_mask_custom_mod(glwe_list.as_mut(), ciphertext_modulus);
+        if ciphertext_modulus.is_non_native_power_of_two() {
+            let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+            glwe_list
+                .as_mut()
+                .iter_mut()
+                .for_each(|x| *x = (*x).wrapping_mul(torus_scaling));
+        }
+
+        let mut buffer = vec![];
+        glwe_list.serialize_compressed(&mut buffer).unwrap();
+
+        let (_, bit_width) = packed_coefficient_layout(ciphertext_modulus);
+        let expected_payload = ciphertext_count.0
+            * packed_glwe_ciphertext_byte_count(glwe_size, polynomial_size, bit_width);
+        assert!(buffer.len() >= expected_payload);
+        if bit_width < Scalar::BITS {
+            assert!(buffer.len() < bincode::serialize(&glwe_list).unwrap().len());
+        }
+
+        let deserialized =
+            GlweCiphertextListOwned::<Scalar>::deserialize_compressed(&*buffer).unwrap();
+
+        assert_eq!(deserialized, glwe_list);
+    }
+
+    #[test]
+    fn glwe_list_compressed_serialization() {
+        compressed_serialization_round_trip::<u32>(CiphertextModulus::new_native());
+        compressed_serialization_round_trip::<u32>(
+            CiphertextModulus::try_new_power_of_2(17).unwrap(),
+        );
+        compressed_serialization_round_trip::<u64>(CiphertextModulus::new_native());
+        compressed_serialization_round_trip::<u64>(
+            CiphertextModulus::try_new_power_of_2(48).unwrap(),
+        );
+        compressed_serialization_round_trip::<u64>(
+            CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap(),
+        );
+        compressed_serialization_round_trip::<u128>(CiphertextModulus::new_native());
+        compressed_serialization_round_trip::<u128>(
+            CiphertextModulus::try_new_power_of_2(100).unwrap(),
+        );
+    }
+
+    #[test]
+    fn glwe_list_compressed_deserialization_rejects_truncated_data() {
+        let glwe_list = GlweCiphertextList::new(
+            0u64,
+            GlweSize(2),
+            PolynomialSize(16),
+            GlweCiphertextCount(2),
+            CiphertextModulus::try_new_power_of_2(48).unwrap(),
+        );
+
+        let mut packed: PackedGlweCiphertextList<u64> = {
+            let mut buffer = vec![];
+            glwe_list.serialize_compressed(&mut buffer).unwrap();
+            bincode::deserialize(&buffer).unwrap()
+        };
+        packed.packed_data.pop();
+        let buffer = bincode::serialize(&packed).unwrap();
+
+        assert!(GlweCiphertextListOwned::<u64>::deserialize_compressed(&*buffer).is_err());
+    }
+
+    fn deserialize_crafted_packed_list(
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        glwe_ciphertext_count: GlweCiphertextCount,
+    ) -> bincode::Result<GlweCiphertextListOwned<u64>> {
+        let packed = PackedGlweCiphertextList {
+            packed_data: vec![],
+            glwe_size,
+            polynomial_size,
+            glwe_ciphertext_count,
+            ciphertext_modulus: CiphertextModulus::<u64>::try_new_power_of_2(48).unwrap(),
+        };
+        let buffer = bincode::serialize(&packed).unwrap();
+
+        GlweCiphertextListOwned::<u64>::deserialize_compressed(&*buffer)
+    }
+
+    fn assert_custom_error(result: bincode::Result<GlweCiphertextListOwned<u64>>) {
+        match result.map_err(|err| *err) {
+            Err(bincode::ErrorKind::Custom(_)) => {}
+            other => panic!("Expected a custom error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn glwe_list_compressed_deserialization_rejects_zero_glwe_size() {
+        assert_custom_error(deserialize_crafted_packed_list(
+            GlweSize(0),
+            PolynomialSize(16),
+            GlweCiphertextCount(2),
+        ));
+    }
+
+    #[test]
+    fn glwe_list_compressed_deserialization_rejects_zero_polynomial_size() {
+        assert_custom_error(deserialize_crafted_packed_list(
+            GlweSize(2),
+            PolynomialSize(0),
+            GlweCiphertextCount(2),
+        ));
+    }
+
+    #[test]
+    fn glwe_list_compressed_deserialization_rejects_overflowing_length() {
+        // The size of a GlweCiphertext overflows
+        assert_custom_error(deserialize_crafted_packed_list(
+            GlweSize(usize::MAX),
+            PolynomialSize(2),
+            GlweCiphertextCount(1),
+        ));
+
+        // The size of the packed data overflows
+        assert_custom_error(deserialize_crafted_packed_list(
+            GlweSize(2),
+            PolynomialSize(16),
+            GlweCiphertextCount(usize::MAX),
+        ));
+    }
+}
@@ -1,5 +1,6 @@
 //! Module containing the definition of the PlaintextList.
 
+use crate::core_crypto::commons::math::random::{RandomGenerable, RandomGenerator, TUniform};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -90,6 +91,66 @@ impl<Scalar, C: ContainerMut<Element = Scalar>> PlaintextList<C> {
     pub fn as_mut_view(&mut self) -> PlaintextListMutView<'_, Scalar> {
         PlaintextListMutView::from_container(self.as_mut())
     }
+
+    /// Overwrite every plaintext of the [`PlaintextList`] with a value sampled from the given
+    /// [`TUniform`] distribution, negative values being stored in two's complement.
+    ///
+    /// This is meant to build noise profiles for tests, e.g. to check the rounding performed after
+    /// decryption on errors up to the bound of the distribution. Using a [`RandomGenerator`]
+    /// created from a fixed seed makes the values reproducible.
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let distribution = TUniform::<u64>::new(10);
+    ///
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut noise = PlaintextList::new(0u64, PlaintextCount(1024));
+    /// noise.fill_with_t_uniform_noise(distribution, &mut generator);
+    ///
+    /// assert!(noise.iter().all(|x| {
+    ///     let x = *x.0 as i64;
+    ///     x >= distribution.min_value_inclusive() && x <= distribution.max_value_inclusive()
+    /// }));
+    ///
+    /// // The same seed yields the same noise
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut other_noise = PlaintextList::new(0u64, PlaintextCount(1024));
+    /// other_noise.fill_with_t_uniform_noise(distribution, &mut generator);
+    ///
+    /// assert_eq!(noise, other_noise);
+    /// ```
+    pub fn fill_with_t_uniform_noise<Gen>(
+        &mut self,
+        distribution: TUniform<Scalar>,
+        generator: &mut RandomGenerator<Gen>,
+    ) where
+        Scalar: UnsignedInteger + RandomGenerable<TUniform<Scalar>>,
+        Gen: ByteRandomGenerator,
+    {
+        generator.fill_slice_with_random_from_distribution(self.as_mut(), distribution);
+    }
+
+    /// Variant of [`PlaintextList::fill_with_t_uniform_noise`] for any ciphertext modulus,
+    /// negative values being stored as their representative modulo `ciphertext_modulus`.
+    pub fn fill_with_t_uniform_noise_custom_mod<Gen>(
+        &mut self,
+        distribution: TUniform<Scalar>,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+        generator: &mut RandomGenerator<Gen>,
+    ) where
+        Scalar: UnsignedInteger + RandomGenerable<TUniform<Scalar>, CustomModulus = Scalar>,
+        Gen: ByteRandomGenerator,
+    {
+        generator.fill_slice_with_random_from_distribution_custom_mod(
+            self.as_mut(),
+            distribution,
+            ciphertext_modulus,
+        );
+    }
 }
 
 /// A [`PlaintextList`] owning the memory for its own storage.
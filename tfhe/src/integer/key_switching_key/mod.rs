@@ -1,6 +1,7 @@
 use super::{ClientKey, ServerKey};
 
-use crate::integer::IntegerCiphertext;
+use crate::integer::{IntegerCiphertext, RadixCiphertext};
+use crate::shortint::key_switching_key::{KeySwitchError, KeySwitchingKeyV0};
 use crate::shortint::parameters::ShortintKeySwitchingParameters;
 
 use rayon::prelude::*;
@@ -31,7 +32,14 @@ impl KeySwitchingKey {
             ),
         };
 
-        assert!(ret.key.cast_rshift == 0, "Attempt to build a KeySwitchingKey between integer key pairs with different message modulus and carry");
+        let key_view = ret.key.as_view();
+        let src_server_key = key_view.src_server_key();
+        let dest_server_key = key_view.dest_server_key();
+        assert_eq!(
+            src_server_key.message_modulus.0 * src_server_key.carry_modulus.0,
+            dest_server_key.message_modulus.0 * dest_server_key.carry_modulus.0,
+            "Attempt to build a KeySwitchingKey between integer key pairs with different message modulus and carry"
+        );
 
         ret
     }
@@ -55,6 +63,65 @@ impl KeySwitchingKey {
             .for_each(|(b1, b2)| self.key.cast_into(b1, b2));
     }
 
+    /// Cast a [`RadixCiphertext`] from the source parameter set to the dest parameter set, block
+    /// by block, returning a new [`RadixCiphertext`].
+    ///
+    /// The blocks are cast in parallel and the lookup table possibly required by the cast is
+    /// generated once and shared by all the blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::key_switching_key::KeySwitchingKey;
+    /// use tfhe::integer::{gen_keys_radix, RadixClientKey};
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    /// let (ck2, sk2) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// // Generate the casting key:
+    /// let ksk_params = ShortintKeySwitchingParameters::new(
+    ///     ck2.parameters().ks_base_log(),
+    ///     ck2.parameters().ks_level(),
+    /// );
+    /// let ksk = KeySwitchingKey::new((&ck1, &sk1), (&ck2, &sk2), ksk_params);
+    ///
+    /// let ct1 = ck1.encrypt(228u64);
+    /// let ct2 = ksk.cast_radix(&ct1);
+    ///
+    /// let clear: u64 = ck2.decrypt(&ct2);
+    /// assert_eq!(clear, 228);
+    /// ```
+    pub fn cast_radix(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        RadixCiphertext::from(self.key.par_cast(&ct.blocks))
+    }
+
+    /// Cast a [`RadixCiphertext`] from the source parameter set to the dest parameter set, block
+    /// by block, using provided &mut.
+    ///
+    /// See [`KeySwitchingKey::cast_radix`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input and output ciphertexts do not have the same number of blocks.
+    pub fn cast_radix_into(&self, ct: &RadixCiphertext, ct_dest: &mut RadixCiphertext) {
+        assert_eq!(
+            ct.blocks.len(),
+            ct_dest.blocks.len(),
+            "Mismatch between the number of input blocks ({}) \
+            and the number of output blocks ({})",
+            ct.blocks.len(),
+            ct_dest.blocks.len(),
+        );
+
+        ct_dest.blocks = self.key.par_cast(&ct.blocks);
+    }
+
     pub fn cast<Int: IntegerCiphertext>(&self, ct: &Int) -> Int {
         Int::from_blocks(
             ct.blocks()
@@ -72,3 +139,13 @@ impl KeySwitchingKey {
         )
     }
 }
+
+/// An integer [`KeySwitchingKey`] serialized before the serialization of casting keys was versioned
+/// has the layout of the shortint key it wraps, see [`KeySwitchingKeyV0`].
+impl TryFrom<KeySwitchingKeyV0> for KeySwitchingKey {
+    type Error = KeySwitchError;
+
+    fn try_from(value: KeySwitchingKeyV0) -> Result<Self, Self::Error> {
+        crate::shortint::KeySwitchingKey::try_from(value).map(Self::from_raw_parts)
+    }
+}
@@ -129,3 +129,65 @@ fn gen_multi_keys_test_integer_to_integer_ci_run_filter() {
     let clear: u8 = client_key_2.decrypt_radix(&ct2);
     assert_eq!(clear, 228);
 }
+
+#[test]
+fn gen_multi_keys_test_cast_radix_ci_run_filter() {
+    let num_block = 4;
+
+    let (client_key_1, server_key_1) =
+        KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+    let client_key_1 = RadixClientKey::from((client_key_1, num_block));
+
+    let (client_key_2, server_key_2) =
+        crate::integer::gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_block);
+
+    // Get casting key
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        client_key_2.parameters().ks_base_log(),
+        client_key_2.parameters().ks_level(),
+    );
+    let ksk = KeySwitchingKey::new(
+        (&client_key_1, &server_key_1),
+        (&client_key_2, &server_key_2),
+        ksk_params,
+    );
+
+    let ct1 = client_key_1.encrypt(228u8);
+    let ct2 = ksk.cast_radix(&ct1);
+    assert_eq!(ct2.blocks.len(), num_block);
+    let clear: u64 = client_key_2.decrypt(&ct2);
+    assert_eq!(clear, 228);
+
+    let mut ct3 = client_key_2.encrypt(0u8);
+    ksk.cast_radix_into(&ct1, &mut ct3);
+    let clear: u64 = client_key_2.decrypt(&ct3);
+    assert_eq!(clear, 228);
+}
+
+#[test]
+#[should_panic(expected = "Mismatch between the number of input blocks")]
+fn gen_multi_keys_test_cast_radix_into_block_count_mismatch_ci_run_filter() {
+    let num_block = 4;
+
+    let (client_key_1, server_key_1) =
+        KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+    let client_key_1 = RadixClientKey::from((client_key_1, num_block));
+
+    let (client_key_2, server_key_2) =
+        crate::integer::gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_block + 1);
+
+    // Get casting key
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        client_key_2.parameters().ks_base_log(),
+        client_key_2.parameters().ks_level(),
+    );
+    let ksk = KeySwitchingKey::new(
+        (&client_key_1, &server_key_1),
+        (&client_key_2, &server_key_2),
+        ksk_params,
+    );
+
+    let ct1 = client_key_1.encrypt(228u8);
+    let mut ct2 = client_key_2.encrypt(0u8);
+    ksk.cast_radix_into(&ct1, &mut ct2);
+}
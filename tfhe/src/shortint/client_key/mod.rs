@@ -377,13 +377,9 @@ impl ClientKey {
         let delta = (1_u64 << 63)
             / (self.parameters.message_modulus().0 * self.parameters.carry_modulus().0) as u64;
 
-        //The bit before the message
-        let rounding_bit = delta >> 1;
-
-        //compute the rounding bit
-        let rounding = (decrypted_u64 & rounding_bit) << 1;
-
-        (decrypted_u64.wrapping_add(rounding)) / delta
+        // Round to the closest multiple of delta, for a power of 2 delta this adds the bit before
+        // the message, which would not be a single bit for other full message moduli
+        (decrypted_u64.wrapping_add(delta / 2)) / delta
     }
 
     /// Decrypt a ciphertext encrypting a message using the client key.
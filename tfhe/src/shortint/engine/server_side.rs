@@ -176,6 +176,24 @@ impl ShortintEngine {
         )
     }
 
+    pub(crate) fn new_seeded_key_switching_key(
+        &mut self,
+        cks1: &ClientKey,
+        cks2: &ClientKey,
+        params: ShortintKeySwitchingParameters,
+    ) -> SeededLweKeyswitchKeyOwned<u64> {
+        // Creation of the seeded key switching key
+        allocate_and_generate_new_seeded_lwe_keyswitch_key(
+            &cks1.large_lwe_secret_key(),
+            &cks2.large_lwe_secret_key(),
+            params.ks_base_log,
+            params.ks_level,
+            cks2.parameters.lwe_noise_distribution(),
+            cks2.parameters.ciphertext_modulus(),
+            &mut self.seeder,
+        )
+    }
+
     pub(crate) fn new_compressed_server_key(&mut self, cks: &ClientKey) -> CompressedServerKey {
         // Plaintext Max Value
         let max_value = cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0 - 1;
@@ -0,0 +1,145 @@
+//! This module defines KeySwitchingKeyBuilder
+//!
+//! - [KeySwitchingKeyBuilder] assembles a [KeySwitchingKey] from keyswitching material generated
+//!   outside of [KeySwitchingKey::new], e.g. during a distributed key generation.
+
+use super::{CastServerKeys, KeySwitchError, KeySwitchingKey};
+use crate::core_crypto::prelude::LweKeyswitchKeyOwned;
+use crate::shortint::ServerKey;
+use std::sync::Arc;
+
+/// Error returned by [`KeySwitchingKeyBuilder::build`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeySwitchingKeyBuilderError {
+    MissingKeySwitchingKey,
+    MissingSrcServerKey,
+    MissingDestServerKey,
+    InvalidParts(KeySwitchError),
+}
+
+impl std::error::Error for KeySwitchingKeyBuilderError {}
+
+impl std::fmt::Display for KeySwitchingKeyBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingKeySwitchingKey => {
+                write!(f, "No LweKeyswitchKey was provided to the builder")
+            }
+            Self::MissingSrcServerKey => {
+                write!(f, "No source ServerKey was provided to the builder")
+            }
+            Self::MissingDestServerKey => {
+                write!(f, "No destination ServerKey was provided to the builder")
+            }
+            Self::InvalidParts(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<KeySwitchError> for KeySwitchingKeyBuilderError {
+    fn from(err: KeySwitchError) -> Self {
+        Self::InvalidParts(err)
+    }
+}
+
+/// Builder for a [`KeySwitchingKey`] using an already generated [`LweKeyswitchKeyOwned`].
+///
+/// Unlike [`KeySwitchingKey::from_raw_parts`], `cast_rshift` does not have to be provided, it is
+/// computed from the message and carry moduli of the source and destination [`ServerKey`]s the
+/// same way [`KeySwitchingKey::new`] does. The parts are checked with
+/// [`KeySwitchingKey::validate_raw_parts`].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::{
+///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+/// };
+/// use tfhe::shortint::{gen_keys, KeySwitchingKey, KeySwitchingKeyBuilder};
+///
+/// // Generate the client keys and server keys:
+/// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+/// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// // Keyswitching material generated by other means
+/// let (raw_ksk, _, _, _) = KeySwitchingKey::new(
+///     (&ck1, &sk1),
+///     (&ck2, &sk2),
+///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+/// )
+/// .into_raw_parts();
+///
+/// let ksk = KeySwitchingKeyBuilder::new()
+///     .key_switching_key(raw_ksk)
+///     .src_server_key(sk1)
+///     .dest_server_key(sk2)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(ksk.cast_rshift(), 2);
+///
+/// let cipher = ck1.encrypt(1);
+/// let cipher_2 = ksk.cast(&cipher);
+///
+/// assert_eq!(ck2.decrypt(&cipher_2), 1);
+/// ```
+#[derive(Clone, Debug, Default)]
+// The fields are named after the keys they hold, like the raw parts of the key
+#[allow(clippy::struct_field_names)]
+pub struct KeySwitchingKeyBuilder {
+    key_switching_key: Option<LweKeyswitchKeyOwned<u64>>,
+    src_server_key: Option<ServerKey>,
+    dest_server_key: Option<ServerKey>,
+}
+
+impl KeySwitchingKeyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the [`LweKeyswitchKeyOwned`] switching from the source to the destination secret key.
+    pub fn key_switching_key(mut self, key_switching_key: LweKeyswitchKeyOwned<u64>) -> Self {
+        self.key_switching_key = Some(key_switching_key);
+        self
+    }
+
+    /// Set the [`ServerKey`] of the source parameters.
+    pub fn src_server_key(mut self, src_server_key: ServerKey) -> Self {
+        self.src_server_key = Some(src_server_key);
+        self
+    }
+
+    /// Set the [`ServerKey`] of the destination parameters.
+    pub fn dest_server_key(mut self, dest_server_key: ServerKey) -> Self {
+        self.dest_server_key = Some(dest_server_key);
+        self
+    }
+
+    /// Build the [`KeySwitchingKey`], or return an error if a part is missing or if the parts are
+    /// not consistent with each other.
+    pub fn build(self) -> Result<KeySwitchingKey, KeySwitchingKeyBuilderError> {
+        let Self {
+            key_switching_key,
+            src_server_key,
+            dest_server_key,
+        } = self;
+
+        let key_switching_key =
+            key_switching_key.ok_or(KeySwitchingKeyBuilderError::MissingKeySwitchingKey)?;
+        let src_server_key =
+            src_server_key.ok_or(KeySwitchingKeyBuilderError::MissingSrcServerKey)?;
+        let dest_server_key =
+            dest_server_key.ok_or(KeySwitchingKeyBuilderError::MissingDestServerKey)?;
+
+        KeySwitchingKey::validate_raw_parts(&key_switching_key, &dest_server_key, &src_server_key)?;
+
+        Ok(KeySwitchingKey::try_from_server_keys(
+            key_switching_key,
+            CastServerKeys::Distinct {
+                src: Arc::new(src_server_key),
+                dest: Arc::new(dest_server_key),
+            },
+        )?)
+    }
+}
@@ -0,0 +1,379 @@
+//! This module defines KeySwitchingKeyChain
+//!
+//! - [KeySwitchingKeyChain] casts a ciphertext through several [KeySwitchingKey]s in sequence,
+//!   when no direct casting key exists between the first source and the last destination.
+
+use super::{CastCost, KeySwitchError, KeySwitchingKey};
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::parameters::LweDimension;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::shortint::Ciphertext;
+
+/// Error returned when the [`KeySwitchingKey`]s given to [`KeySwitchingKeyChain::new`] cannot be
+/// applied one after the other.
+///
+/// `step` is the index of the [`KeySwitchingKey`] whose output does not match the input of the
+/// next one, or for [`KeySwitchingKeyChainError::MissingDestServerKey`] returned by
+/// [`KeySwitchingKeyChain::with_auto_refresh`], the index of the key whose output would need a
+/// refresh.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeySwitchingKeyChainError {
+    EmptyChain,
+    MissingDestServerKey {
+        step: usize,
+    },
+    LweDimensionMismatch {
+        step: usize,
+        output: LweDimension,
+        expected: LweDimension,
+    },
+    MessageModulusMismatch {
+        step: usize,
+        output: MessageModulus,
+        expected: MessageModulus,
+    },
+    CarryModulusMismatch {
+        step: usize,
+        output: CarryModulus,
+        expected: CarryModulus,
+    },
+    CiphertextModulusMismatch {
+        step: usize,
+        output: CiphertextModulus<u64>,
+        expected: CiphertextModulus<u64>,
+    },
+}
+
+impl std::error::Error for KeySwitchingKeyChainError {}
+
+impl std::fmt::Display for KeySwitchingKeyChainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyChain => {
+                write!(
+                    f,
+                    "A KeySwitchingKeyChain requires at least one KeySwitchingKey"
+                )
+            }
+            Self::MissingDestServerKey { step } => {
+                write!(
+                    f,
+                    "The KeySwitchingKey at step {step} does not store its destination \
+                    ServerKey, which is required to refresh the ciphertexts it outputs"
+                )
+            }
+            Self::LweDimensionMismatch {
+                step,
+                output,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "The output ciphertext LweDimension (={}) of the KeySwitchingKey at step \
+                    {step} does not match the input LweDimension (={}) of the next one",
+                    output.0, expected.0,
+                )
+            }
+            Self::MessageModulusMismatch {
+                step,
+                output,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "The output MessageModulus (={}) of the KeySwitchingKey at step {step} \
+                    does not match the input MessageModulus (={}) of the next one",
+                    output.0, expected.0,
+                )
+            }
+            Self::CarryModulusMismatch {
+                step,
+                output,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "The output CarryModulus (={}) of the KeySwitchingKey at step {step} \
+                    does not match the input CarryModulus (={}) of the next one",
+                    output.0, expected.0,
+                )
+            }
+            Self::CiphertextModulusMismatch {
+                step,
+                output,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "The output CiphertextModulus ({output:?}) of the KeySwitchingKey at step \
+                    {step} does not match the input CiphertextModulus ({expected:?}) \
+                    of the next one",
+                )
+            }
+        }
+    }
+}
+
+/// An ordered list of [`KeySwitchingKey`]s, casting ciphertexts from the source parameters of the
+/// first key to the destination parameters of the last one.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::{
+///     ShortintKeySwitchingParameters, PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+///     PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+/// };
+/// use tfhe::shortint::{gen_keys, KeySwitchingKey, KeySwitchingKeyChain};
+///
+/// // Generate the client keys and server keys:
+/// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+/// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+/// let (ck3, sk3) = gen_keys(PARAM_MESSAGE_3_CARRY_3_KS_PBS);
+///
+/// // Generate the casting keys for each hop:
+/// let ksk_1_to_2 = KeySwitchingKey::new(
+///     (&ck1, &sk1),
+///     (&ck2, &sk2),
+///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+/// );
+/// let ksk_2_to_3 = KeySwitchingKey::new(
+///     (&ck2, &sk2),
+///     (&ck3, &sk3),
+///     ShortintKeySwitchingParameters::try_for(
+///         PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+///         PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+///     )
+///     .unwrap(),
+/// );
+///
+/// let chain = KeySwitchingKeyChain::new(vec![ksk_1_to_2, ksk_2_to_3]).unwrap();
+///
+/// let cipher = ck1.encrypt(1);
+/// let cipher_3 = chain.cast(&cipher);
+///
+/// assert_eq!(ck3.decrypt(&cipher_3), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeySwitchingKeyChain {
+    keys: Vec<KeySwitchingKey>,
+    // Indices of the keys whose output is refreshed, in increasing order
+    refresh_steps: Vec<usize>,
+}
+
+impl KeySwitchingKeyChain {
+    /// Build a [`KeySwitchingKeyChain`] applying `keys` in order.
+    ///
+    /// Returns an error if `keys` is empty or if the destination of a [`KeySwitchingKey`] does not
+    /// match the source of the next one, i.e. if the ciphertexts it outputs could not be cast by
+    /// the next key.
+    pub fn new(keys: Vec<KeySwitchingKey>) -> Result<Self, KeySwitchingKeyChainError> {
+        if keys.is_empty() {
+            return Err(KeySwitchingKeyChainError::EmptyChain);
+        }
+
+        for (step, pair) in keys.windows(2).enumerate() {
+            check_consecutive_keys(step, &pair[0], &pair[1])?;
+        }
+
+        Ok(Self {
+            keys,
+            refresh_steps: Vec::new(),
+        })
+    }
+
+    /// Make the chain insert a refresh bootstrap after a cast whenever the ciphertext would
+    /// otherwise carry the noise of more than `budget` keyswitches since its last bootstrap.
+    ///
+    /// [`NoiseLevel`](`crate::shortint::ciphertext::NoiseLevel`) has no unit for the noise of a
+    /// keyswitch, see [`KeySwitchingKey::cast_output_degree_and_noise_level`], so the running
+    /// estimate counts the keyswitches instead, assuming the input ciphertext is fresh or comes out
+    /// of a bootstrap:
+    ///
+    /// - a keyswitch only cast ([`CastCost::KeyswitchOnly`]) adds one keyswitch;
+    /// - a cast bootstrapping with the source [`ServerKey`](`crate::shortint::ServerKey`)
+    ///   ([`CastCost::SrcPbs`]) leaves the one keyswitch following the bootstrap;
+    /// - a cast bootstrapping with the destination
+    ///   [`ServerKey`](`crate::shortint::ServerKey`) ([`CastCost::DestPbs`]) resets the count.
+    ///
+    /// A refreshed cast behaves as [`KeySwitchingKey::cast_into_with_bootstrap`] and resets the
+    /// count. As the count only depends on the keys, the casts to refresh are decided here, see
+    /// [`KeySwitchingKeyChain::refresh_steps`], replacing those of a previous call. With a `budget`
+    /// of 0 every output which does not come out of a bootstrap with the destination parameters is
+    /// refreshed.
+    ///
+    /// This requires the destination parameters of the refreshed casts to support bootstrapping:
+    /// returns an error if a [`KeySwitchingKey`] whose output needs a refresh does not store its
+    /// destination [`ServerKey`](`crate::shortint::ServerKey`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::NoiseLevel;
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey, KeySwitchingKeyChain};
+    ///
+    /// // Generate the client key and server key:
+    /// let (ck, sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // A keyswitch only cast, applied three times
+    /// let ksk_params = ShortintKeySwitchingParameters::new(
+    ///     ck.parameters.ks_base_log(),
+    ///     ck.parameters.ks_level(),
+    /// );
+    /// let ksk = KeySwitchingKey::new((&ck, &sk), (&ck, &sk), ksk_params);
+    ///
+    /// let chain = KeySwitchingKeyChain::new(vec![ksk.clone(), ksk.clone(), ksk])
+    ///     .unwrap()
+    ///     .with_auto_refresh(2)
+    ///     .unwrap();
+    ///
+    /// // The third keyswitch would exceed the budget
+    /// assert_eq!(chain.refresh_steps(), &[2]);
+    ///
+    /// let cipher = sk.unchecked_add(&ck.encrypt(1), &ck.encrypt(2));
+    /// let cipher_2 = chain.cast(&cipher);
+    ///
+    /// assert_eq!(cipher_2.noise_level(), NoiseLevel::NOMINAL);
+    /// assert_eq!(ck.decrypt(&cipher_2), 3);
+    /// ```
+    pub fn with_auto_refresh(self, budget: usize) -> Result<Self, KeySwitchingKeyChainError> {
+        let mut refresh_steps = Vec::new();
+        let mut keyswitch_count = 0;
+
+        for (step, ksk) in self.keys.iter().enumerate() {
+            keyswitch_count = match ksk.cast_cost() {
+                CastCost::KeyswitchOnly => keyswitch_count + 1,
+                CastCost::SrcPbs => 1,
+                CastCost::DestPbs => 0,
+            };
+
+            if keyswitch_count > budget {
+                if ksk.as_view().server_keys.dest().is_none() {
+                    return Err(KeySwitchingKeyChainError::MissingDestServerKey { step });
+                }
+
+                refresh_steps.push(step);
+                keyswitch_count = 0;
+            }
+        }
+
+        Ok(Self {
+            keys: self.keys,
+            refresh_steps,
+        })
+    }
+
+    /// Return the [`KeySwitchingKey`]s of the chain, in the order they are applied.
+    pub fn keys(&self) -> &[KeySwitchingKey] {
+        &self.keys
+    }
+
+    /// Return the indices of the [`KeySwitchingKey`]s whose output is refreshed, in increasing
+    /// order.
+    ///
+    /// This is empty unless the chain was built with [`KeySwitchingKeyChain::with_auto_refresh`].
+    pub fn refresh_steps(&self) -> &[usize] {
+        &self.refresh_steps
+    }
+
+    /// Deconstruct a [`KeySwitchingKeyChain`] into its [`KeySwitchingKey`]s.
+    pub fn into_keys(self) -> Vec<KeySwitchingKey> {
+        self.keys
+    }
+
+    /// Cast a ciphertext through all the [`KeySwitchingKey`]s of the chain, returning a new
+    /// ciphertext under the destination parameters of the last key.
+    ///
+    /// Each hop behaves as [`KeySwitchingKey::cast`], in particular bits which do not fit in the
+    /// full message modulus of an intermediate destination are lost. The hops listed by
+    /// [`KeySwitchingKeyChain::refresh_steps`] behave as
+    /// [`KeySwitchingKey::cast_into_with_bootstrap`].
+    pub fn cast(&self, ct: &Ciphertext) -> Ciphertext {
+        // The chain is never empty, see Self::new
+        let (first, rest) = self.keys.split_first().unwrap();
+
+        rest.iter()
+            .enumerate()
+            .fold(self.cast_step(0, first, ct), |ct, (index, ksk)| {
+                self.cast_step(index + 1, ksk, &ct)
+            })
+    }
+
+    /// Cast a ciphertext through all the [`KeySwitchingKey`]s of the chain, or return an error if
+    /// the input ciphertext is not compatible with the source of the first key.
+    ///
+    /// See [`KeySwitchingKey::try_cast`].
+    pub fn try_cast(&self, ct: &Ciphertext) -> Result<Ciphertext, KeySwitchError> {
+        self.keys[0].can_cast(ct)?;
+        Ok(self.cast(ct))
+    }
+
+    fn cast_step(&self, step: usize, ksk: &KeySwitchingKey, ct: &Ciphertext) -> Ciphertext {
+        if self.refresh_steps.binary_search(&step).is_err() {
+            return ksk.cast(ct);
+        }
+
+        let ksk = ksk.as_view();
+        let mut ct_dest = ksk.new_dest_ciphertext();
+        ksk.cast_into_with_bootstrap(ct, &mut ct_dest);
+        ct_dest
+    }
+}
+
+/// Check that the ciphertexts output by `current` can be cast by `next`.
+fn check_consecutive_keys(
+    step: usize,
+    current: &KeySwitchingKey,
+    next: &KeySwitchingKey,
+) -> Result<(), KeySwitchingKeyChainError> {
+    let current = current.as_view();
+    let next = next.as_view();
+
+    let output_lwe_dimension = current.key_switching_key.output_key_lwe_dimension();
+    let expected_lwe_dimension = next.src_ciphertext_lwe_dimension();
+    if output_lwe_dimension != expected_lwe_dimension {
+        return Err(KeySwitchingKeyChainError::LweDimensionMismatch {
+            step,
+            output: output_lwe_dimension,
+            expected: expected_lwe_dimension,
+        });
+    }
+
+    let (output_message_modulus, output_carry_modulus) = current.dest_moduli();
+    let (expected_message_modulus, expected_carry_modulus) = next.src_moduli();
+
+    if output_message_modulus != expected_message_modulus {
+        return Err(KeySwitchingKeyChainError::MessageModulusMismatch {
+            step,
+            output: output_message_modulus,
+            expected: expected_message_modulus,
+        });
+    }
+
+    if output_carry_modulus != expected_carry_modulus {
+        return Err(KeySwitchingKeyChainError::CarryModulusMismatch {
+            step,
+            output: output_carry_modulus,
+            expected: expected_carry_modulus,
+        });
+    }
+
+    // Keys built without ServerKeys do not record their source CiphertextModulus
+    if let Some(next_src_server_key) = next.server_keys.src() {
+        let output_ciphertext_modulus = current.key_switching_key.ciphertext_modulus();
+        let expected_ciphertext_modulus = next_src_server_key.ciphertext_modulus;
+        if output_ciphertext_modulus != expected_ciphertext_modulus {
+            return Err(KeySwitchingKeyChainError::CiphertextModulusMismatch {
+                step,
+                output: output_ciphertext_modulus,
+                expected: expected_ciphertext_modulus,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,305 @@
+//! This module defines the errors of the KeySwitchingKey API
+//!
+//! - [KeySwitchError] is returned when a casting key cannot be built or cannot cast a ciphertext.
+//! - [KeySwitchingKeyBytesError] is returned when decoding a casting key from bytes.
+
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::parameters::LweDimension;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+
+/// Error returned when a ciphertext cannot be cast by a
+/// [`KeySwitchingKey`](`super::KeySwitchingKey`), or when a casting key cannot be built.
+///
+/// The latter happens when the provided keys or raw parts are not compatible, see the `try_*`
+/// constructors and
+/// [`KeySwitchingKey::validate_raw_parts`](`super::KeySwitchingKey::validate_raw_parts`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeySwitchError {
+    LweDimensionMismatch {
+        input: LweDimension,
+        expected: LweDimension,
+    },
+    MessageModulusMismatch {
+        input: MessageModulus,
+        expected: MessageModulus,
+    },
+    CarryModulusMismatch {
+        input: CarryModulus,
+        expected: CarryModulus,
+    },
+    MessageTruncation {
+        degree: Degree,
+        dest_full_message_modulus: usize,
+    },
+    KeyswitchKeyInputLweDimensionMismatch {
+        src_server_key: LweDimension,
+        key_switching_key: LweDimension,
+    },
+    KeyswitchKeyOutputLweDimensionMismatch {
+        dest_server_key: LweDimension,
+        key_switching_key: LweDimension,
+    },
+    IncompatibleCiphertextModuli {
+        src_server_key: CiphertextModulus<u64>,
+        dest_server_key: CiphertextModulus<u64>,
+    },
+    KeyswitchKeyCiphertextModulusMismatch {
+        dest_server_key: CiphertextModulus<u64>,
+        key_switching_key: CiphertextModulus<u64>,
+    },
+    KeyswitchOnlyModuliMismatch {
+        src: (MessageModulus, CarryModulus),
+        dest: (MessageModulus, CarryModulus),
+    },
+    SharedServerKeyParametersMismatch,
+    RefreshParametersMismatch,
+    SrcFullMessageModulusOverflow {
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+    },
+    DestFullMessageModulusOverflow {
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+    },
+    MissingDestServerKey {
+        cast_rshift: i8,
+    },
+    SrcKeyPairMismatch,
+    DestKeyPairMismatch,
+    CastRshiftOutOfRange {
+        cast_rshift: i8,
+        full_message_modulus_nb_bits: i8,
+    },
+    CastRshiftMismatch {
+        cast_rshift: i8,
+        expected: i8,
+    },
+    ServerKeysNotStored,
+    DestServerKeyNotStored,
+}
+
+impl std::error::Error for KeySwitchError {}
+
+impl std::fmt::Display for KeySwitchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LweDimensionMismatch { input, expected } => {
+                write!(
+                    f,
+                    "The input ciphertext LweDimension (={}) does not match \
+                    the source ServerKey ciphertext LweDimension (={})",
+                    input.0, expected.0,
+                )
+            }
+            Self::MessageModulusMismatch { input, expected } => {
+                write!(
+                    f,
+                    "The input ciphertext MessageModulus (={}) does not match \
+                    the source ServerKey MessageModulus (={})",
+                    input.0, expected.0,
+                )
+            }
+            Self::CarryModulusMismatch { input, expected } => {
+                write!(
+                    f,
+                    "The input ciphertext CarryModulus (={}) does not match \
+                    the source ServerKey CarryModulus (={})",
+                    input.0, expected.0,
+                )
+            }
+            Self::MessageTruncation {
+                degree,
+                dest_full_message_modulus,
+            } => {
+                write!(
+                    f,
+                    "The input ciphertext degree (={}) does not fit in \
+                    the destination full message modulus (={}), the cast would truncate it",
+                    degree.get(),
+                    dest_full_message_modulus,
+                )
+            }
+            Self::KeyswitchKeyInputLweDimensionMismatch {
+                src_server_key,
+                key_switching_key,
+            } => {
+                write!(
+                    f,
+                    "Mismatch between the source ServerKey ciphertext LweDimension \
+                    ({src_server_key:?}) and the LweKeyswitchKey input LweDimension \
+                    ({key_switching_key:?})",
+                )
+            }
+            Self::KeyswitchKeyOutputLweDimensionMismatch {
+                dest_server_key,
+                key_switching_key,
+            } => {
+                write!(
+                    f,
+                    "Mismatch between the destination ServerKey ciphertext LweDimension \
+                    ({dest_server_key:?}) and the LweKeyswitchKey output LweDimension \
+                    ({key_switching_key:?})",
+                )
+            }
+            Self::IncompatibleCiphertextModuli {
+                src_server_key,
+                dest_server_key,
+            } => {
+                write!(
+                    f,
+                    "Casting between the source ServerKey CiphertextModulus ({src_server_key:?}) \
+                    and the destination ServerKey CiphertextModulus ({dest_server_key:?}) \
+                    is only supported for power of two moduli, the destination one dividing the \
+                    source one",
+                )
+            }
+            Self::KeyswitchKeyCiphertextModulusMismatch {
+                dest_server_key,
+                key_switching_key,
+            } => {
+                write!(
+                    f,
+                    "Mismatch between the LweKeyswitchKey CiphertextModulus \
+                    ({key_switching_key:?}) and the destination ServerKey CiphertextModulus \
+                    ({dest_server_key:?})",
+                )
+            }
+            Self::KeyswitchOnlyModuliMismatch { src, dest } => {
+                write!(
+                    f,
+                    "A keyswitch only KeySwitchingKey requires the same moduli on both sides, \
+                    got source moduli ({:?}, {:?}) and destination moduli ({:?}, {:?})",
+                    src.0, src.1, dest.0, dest.1,
+                )
+            }
+            Self::SharedServerKeyParametersMismatch => {
+                write!(
+                    f,
+                    "Mismatch between the ClientKey parameters and the shared ServerKey parameters"
+                )
+            }
+            Self::RefreshParametersMismatch => {
+                write!(
+                    f,
+                    "A refresh KeySwitchingKey requires both ClientKeys to use the same parameters"
+                )
+            }
+            Self::SrcFullMessageModulusOverflow {
+                message_modulus,
+                carry_modulus,
+            } => {
+                write!(
+                    f,
+                    "The source full message modulus {message_modulus:?} * {carry_modulus:?} \
+                    does not fit in a u64",
+                )
+            }
+            Self::DestFullMessageModulusOverflow {
+                message_modulus,
+                carry_modulus,
+            } => {
+                write!(
+                    f,
+                    "The destination full message modulus {message_modulus:?} * \
+                    {carry_modulus:?} does not fit in a u64",
+                )
+            }
+            Self::MissingDestServerKey { cast_rshift } => {
+                write!(
+                    f,
+                    "Casting to a bigger full message modulus (cast_rshift = {cast_rshift}) \
+                    requires a lookup table applied with the destination ServerKey",
+                )
+            }
+            Self::SrcKeyPairMismatch => write_key_pair_mismatch(f, "source"),
+            Self::DestKeyPairMismatch => write_key_pair_mismatch(f, "destination"),
+            Self::CastRshiftOutOfRange {
+                cast_rshift,
+                full_message_modulus_nb_bits,
+            } => {
+                write!(
+                    f,
+                    "The cast_rshift (={cast_rshift}) magnitude must be smaller than the number \
+                    of bits (={full_message_modulus_nb_bits}) of the full message modulus it is \
+                    applied in, otherwise all the message bits are shifted out",
+                )
+            }
+            Self::CastRshiftMismatch {
+                cast_rshift,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "The cast_rshift (={cast_rshift}) does not match the one derived from the \
+                    full message moduli of the source and destination ServerKeys (={expected})",
+                )
+            }
+            Self::ServerKeysNotStored => write!(f, "{}", super::MISSING_SERVER_KEY_MSG),
+            Self::DestServerKeyNotStored => write!(f, "{}", super::MISSING_DEST_SERVER_KEY_MSG),
+        }
+    }
+}
+
+fn write_key_pair_mismatch(f: &mut std::fmt::Formatter<'_>, side: &str) -> std::fmt::Result {
+    write!(
+        f,
+        "The {side} ServerKey was not generated from the {side} ClientKey: mismatched \
+        (MessageModulus, CarryModulus, CiphertextModulus, large LweDimension, small LweDimension)",
+    )
+}
+
+/// Error returned when decoding a [`KeySwitchingKey`](`super::KeySwitchingKey`) from the byte
+/// layout of [`KeySwitchingKey::to_bytes`](`super::KeySwitchingKey::to_bytes`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeySwitchingKeyBytesError {
+    InvalidMagic,
+    UnsupportedVersion(u8),
+    InvalidParameters,
+    InvalidCiphertextModulus(u128),
+    InvalidLength { expected: usize, got: usize },
+    IncompatibleKeys(KeySwitchError),
+}
+
+impl std::error::Error for KeySwitchingKeyBytesError {}
+
+impl std::fmt::Display for KeySwitchingKeyBytesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidMagic => {
+                write!(f, "The bytes do not start with the KeySwitchingKey magic")
+            }
+            Self::UnsupportedVersion(version) => {
+                write!(
+                    f,
+                    "Unsupported KeySwitchingKey bytes format version (={version})"
+                )
+            }
+            Self::InvalidParameters => {
+                write!(
+                    f,
+                    "The KeySwitchingKey bytes contain invalid decomposition parameters \
+                    or LweDimensions"
+                )
+            }
+            Self::InvalidCiphertextModulus(modulus) => {
+                write!(
+                    f,
+                    "The KeySwitchingKey bytes contain an invalid CiphertextModulus (={modulus})"
+                )
+            }
+            Self::InvalidLength { expected, got } => {
+                write!(
+                    f,
+                    "Invalid KeySwitchingKey bytes length, expected {expected} bytes, got {got}"
+                )
+            }
+            Self::IncompatibleKeys(err) => {
+                write!(
+                    f,
+                    "The KeySwitchingKey bytes are not compatible with the ServerKeys: {err}"
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! This module defines KeySwitchingKeyMaterial
+//!
+//! - [KeySwitchingKeyMaterial] holds the keyswitching material of a
+//!   [KeySwitchingKey](`super::KeySwitchingKey`) without its
+//!   [ServerKey](`crate::shortint::ServerKey`)s, so that it can be transmitted separately from
+//!   them.
+
+use crate::core_crypto::prelude::LweKeyswitchKeyOwned;
+use serde::{Deserialize, Serialize};
+
+/// The part of a [`KeySwitchingKey`](`super::KeySwitchingKey`) which is specific to the cast: the
+/// [`LweKeyswitchKeyOwned`] and `cast_rshift`.
+///
+/// When the server already has the source and destination
+/// [`ServerKey`](`crate::shortint::ServerKey`)s, publishing only this material avoids sending
+/// them a second time inside the [`KeySwitchingKey`](`super::KeySwitchingKey`).
+///
+/// See [`KeySwitchingKey::split_material`](`super::KeySwitchingKey::split_material`) and
+/// [`KeySwitchingKey::from_material`](`super::KeySwitchingKey::from_material`).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeySwitchingKeyMaterial {
+    pub(crate) key_switching_key: LweKeyswitchKeyOwned<u64>,
+    /// See [`KeySwitchingKey::cast_rshift`](`super::KeySwitchingKey::cast_rshift`).
+    pub cast_rshift: i8,
+}
+
+impl KeySwitchingKeyMaterial {
+    /// Construct a [`KeySwitchingKeyMaterial`] from its constituents.
+    ///
+    /// Nothing is checked here, the consistency with the
+    /// [`ServerKey`](`crate::shortint::ServerKey`)s is verified when reassembling the
+    /// [`KeySwitchingKey`](`super::KeySwitchingKey`).
+    pub fn from_raw_parts(key_switching_key: LweKeyswitchKeyOwned<u64>, cast_rshift: i8) -> Self {
+        Self {
+            key_switching_key,
+            cast_rshift,
+        }
+    }
+
+    /// Deconstruct a [`KeySwitchingKeyMaterial`] into its constituents.
+    pub fn into_raw_parts(self) -> (LweKeyswitchKeyOwned<u64>, i8) {
+        let Self {
+            key_switching_key,
+            cast_rshift,
+        } = self;
+
+        (key_switching_key, cast_rshift)
+    }
+
+    /// Return the [`LweKeyswitchKeyOwned`] switching from the source to the destination secret
+    /// key.
+    pub fn key_switching_key(&self) -> &LweKeyswitchKeyOwned<u64> {
+        &self.key_switching_key
+    }
+}
@@ -2,33 +2,314 @@
 //!
 //! - [KeySwitchingKey] allows switching the keys of a ciphertext, from a cleitn key to another.
 
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+};
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::engine::ShortintEngine;
-use crate::shortint::parameters::ShortintKeySwitchingParameters;
-use crate::shortint::{Ciphertext, ClientKey, ServerKey};
+use crate::shortint::parameters::{CarryModulus, MessageModulus, ShortintKeySwitchingParameters};
+use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::{Ciphertext, ClientKey, CompressedCiphertext, PBSOrder, ServerKey};
 
-use crate::core_crypto::prelude::{keyswitch_lwe_ciphertext, LweKeyswitchKeyOwned};
+use crate::core_crypto::prelude::{
+    decompress_seeded_lwe_ciphertext, keyswitch_lwe_ciphertext,
+    keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext,
+    programmable_bootstrap_lwe_ciphertext, ActivatedRandomGenerator, Container, ContainerMut,
+    ContiguousEntityContainerMut, FourierLweBootstrapKey, GlweCiphertext, LweCiphertext,
+    LweCiphertextCount, LweCiphertextList, LweKeyswitchKeyOwned, LwePackingKeyswitchKey,
+    SeededLweKeyswitchKeyOwned,
+};
 
+use concrete_fft::c64;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::sync::{Arc, OnceLock};
 
+mod builder;
+mod chain;
+mod error;
+mod material;
+mod pair;
 #[cfg(test)]
 mod test;
+mod versioning;
+
+pub use builder::{KeySwitchingKeyBuilder, KeySwitchingKeyBuilderError};
+pub use chain::{KeySwitchingKeyChain, KeySwitchingKeyChainError};
+pub use error::{KeySwitchError, KeySwitchingKeyBytesError};
+pub use material::KeySwitchingKeyMaterial;
+pub use pair::KeySwitchingKeyPair;
+pub use versioning::KeySwitchingKeyV0;
+
+/// Scratch memory for [`KeySwitchingKey::cast_into_with_buffer`].
+///
+/// Casting to a smaller full message modulus applies a lookup table on a copy of the input before
+/// the keyswitch, the buffer keeps that intermediate ciphertext so it is only allocated once when
+/// casting repeatedly.
+#[derive(Clone, Debug, Default)]
+pub struct CastBuffer {
+    shifted_cipher: Option<Ciphertext>,
+}
+
+impl CastBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copy `ct` in the buffer, reusing the existing allocation if it has the right size.
+    fn load(&mut self, ct: &Ciphertext) -> &mut Ciphertext {
+        let buffered = match self.shifted_cipher.take() {
+            Some(mut buffered)
+                if buffered.ct.lwe_size() == ct.ct.lwe_size()
+                    && buffered.ct.ciphertext_modulus() == ct.ct.ciphertext_modulus() =>
+            {
+                buffered.ct.as_mut().copy_from_slice(ct.ct.as_ref());
+                buffered.degree = ct.degree;
+                buffered.set_noise_level(ct.noise_level());
+                buffered.message_modulus = ct.message_modulus;
+                buffered.carry_modulus = ct.carry_modulus;
+                buffered.pbs_order = ct.pbs_order;
+                buffered
+            }
+            _ => ct.clone(),
+        };
+
+        self.shifted_cipher.insert(buffered)
+    }
+}
+
+/// The work performed by [`KeySwitchingKey::cast_into`], see [`KeySwitchingKey::cast_cost`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CastCost {
+    /// The full message moduli are equal, the cast is a single keyswitch
+    KeyswitchOnly,
+    /// The source full message modulus is greater, a programmable bootstrap is applied with the
+    /// source [`ServerKey`] before the keyswitch
+    SrcPbs,
+    /// The source full message modulus is smaller, a programmable bootstrap is applied with the
+    /// destination [`ServerKey`] after the keyswitch
+    DestPbs,
+}
+
+impl CastCost {
+    /// Return the number of programmable bootstraps performed by the cast.
+    pub const fn pbs_count(&self) -> usize {
+        match self {
+            Self::KeyswitchOnly => 0,
+            Self::SrcPbs | Self::DestPbs => 1,
+        }
+    }
+}
+
+/// The [`ServerKey`]s a [`KeySwitchingKey`] uses on its source and destination sides.
+///
+/// The [`ServerKey`]s are reference counted so that several [`KeySwitchingKey`]s between the same
+/// parameter sets can share them, e.g. the two keys of a
+/// [`KeySwitchingKeyPair`](`super::KeySwitchingKeyPair`). They are serialized as plain
+/// [`ServerKey`]s.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) enum CastServerKeys {
+    Distinct {
+        src: Arc<ServerKey>,
+        dest: Arc<ServerKey>,
+    },
+    /// The source and destination share the same [`ServerKey`]
+    Shared(Arc<ServerKey>),
+    /// No [`ServerKey`] is available, the source and destination have the same moduli and only a
+    /// keyswitch can be applied
+    KeyswitchOnly {
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+    },
+    /// Only the source [`ServerKey`] is available, the destination is described by its moduli,
+    /// which is enough for casts not requiring a destination side PBS
+    SrcOnly {
+        src: Arc<ServerKey>,
+        dest_message_modulus: MessageModulus,
+        dest_carry_modulus: CarryModulus,
+    },
+}
+
+impl CastServerKeys {
+    fn as_ref(&self) -> CastServerKeysRef<'_> {
+        match self {
+            Self::Distinct { src, dest } => CastServerKeysRef::Distinct { src, dest },
+            Self::Shared(server_key) => CastServerKeysRef::Shared(server_key),
+            Self::KeyswitchOnly {
+                message_modulus,
+                carry_modulus,
+            } => CastServerKeysRef::KeyswitchOnly {
+                message_modulus: *message_modulus,
+                carry_modulus: *carry_modulus,
+            },
+            Self::SrcOnly {
+                src,
+                dest_message_modulus,
+                dest_carry_modulus,
+            } => CastServerKeysRef::SrcOnly {
+                src,
+                dest_message_modulus: *dest_message_modulus,
+                dest_carry_modulus: *dest_carry_modulus,
+            },
+        }
+    }
+}
+
+/// Same as [`CastServerKeys`] borrowing the [`ServerKey`]s, as stored by a [`KeySwitchingKeyView`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum CastServerKeysRef<'keys> {
+    Distinct {
+        src: &'keys ServerKey,
+        dest: &'keys ServerKey,
+    },
+    Shared(&'keys ServerKey),
+    KeyswitchOnly {
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+    },
+    SrcOnly {
+        src: &'keys ServerKey,
+        dest_message_modulus: MessageModulus,
+        dest_carry_modulus: CarryModulus,
+    },
+}
+
+impl<'keys> CastServerKeysRef<'keys> {
+    fn src(self) -> Option<&'keys ServerKey> {
+        match self {
+            Self::Distinct { src, .. } | Self::SrcOnly { src, .. } => Some(src),
+            Self::Shared(server_key) => Some(server_key),
+            Self::KeyswitchOnly { .. } => None,
+        }
+    }
+
+    pub(crate) fn dest(self) -> Option<&'keys ServerKey> {
+        match self {
+            Self::Distinct { dest, .. } => Some(dest),
+            Self::Shared(server_key) => Some(server_key),
+            Self::KeyswitchOnly { .. } | Self::SrcOnly { .. } => None,
+        }
+    }
+}
+
+/// Lazily generated lookup table of the cast, see
+/// [`KeySwitchingKeyView::generate_cast_lookup_table`].
+///
+/// The lookup table only depends on the [`ServerKey`]s of the [`KeySwitchingKey`], it is generated
+/// on the first cast needing it and reused afterwards. [`OnceLock`] makes this safe when the
+/// first casts happen concurrently from several threads, only one of them generates the table.
+///
+/// The cache is not part of the value of the key: it is not serialized and is ignored when
+/// comparing keys. A [`KeySwitchingKey`] owns its cache, a view built with
+/// [`KeySwitchingKeyView::new`] borrows one which must only be used with the same [`ServerKey`]s.
+#[derive(Clone, Debug, Default)]
+pub struct CastLookupTableCache(OnceLock<Option<LookupTableOwned>>);
+
+impl CastLookupTableCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl PartialEq for CastLookupTableCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+const KEY_SWITCHING_KEY_BYTES_MAGIC: [u8; 4] = *b"TKSK";
+const KEY_SWITCHING_KEY_BYTES_VERSION: u8 = 1;
+const KEY_SWITCHING_KEY_BYTES_HEADER_LEN: usize = 54;
+
+const FNV1A_64_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV1A_64_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Update a 64 bits FNV-1a hash with `bytes`.
+fn fnv1a_64(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV1A_64_PRIME)
+    })
+}
+
+/// Check the ciphertext moduli of the source and destination parameters of a cast.
+///
+/// The messages are encoded with the same delta whatever the ciphertext modulus, so the input of
+/// the keyswitch is reduced modulo the destination modulus, see
+/// [`KeySwitchingKeyView::keyswitch_to_dest`]. This is only possible for power of two moduli when
+/// the destination one divides the source one.
+fn try_cast_ciphertext_moduli(
+    src_ciphertext_modulus: CiphertextModulus<u64>,
+    dest_ciphertext_modulus: CiphertextModulus<u64>,
+) -> Result<(), KeySwitchError> {
+    if src_ciphertext_modulus == dest_ciphertext_modulus
+        || (src_ciphertext_modulus.is_compatible_with_native_modulus()
+            && dest_ciphertext_modulus.is_compatible_with_native_modulus()
+            && src_ciphertext_modulus.get_power_of_two_scaling_to_native_torus()
+                <= dest_ciphertext_modulus.get_power_of_two_scaling_to_native_torus())
+    {
+        Ok(())
+    } else {
+        Err(KeySwitchError::IncompatibleCiphertextModuli {
+            src_server_key: src_ciphertext_modulus,
+            dest_server_key: dest_ciphertext_modulus,
+        })
+    }
+}
+
+fn assert_cast_ciphertext_moduli_compatible(
+    src_ciphertext_modulus: CiphertextModulus<u64>,
+    dest_ciphertext_modulus: CiphertextModulus<u64>,
+) {
+    try_cast_ciphertext_moduli(src_ciphertext_modulus, dest_ciphertext_modulus)
+        .unwrap_or_else(|err| panic!("{err}"));
+}
+
+const MISSING_SERVER_KEY_MSG: &str = "This KeySwitchingKey was built without ServerKeys \
+    (see KeySwitchingKey::new_keyswitch_only), only casts between identical message and carry \
+    moduli are supported";
+
+const MISSING_DEST_SERVER_KEY_MSG: &str = "This KeySwitchingKey was built without a destination \
+    ServerKey (see KeySwitchingKey::new_without_dest_server_key), operations requiring a \
+    destination side PBS are not supported";
 
 /// A structure containing the casting public key.
 ///
 /// The casting key is generated by the client and is meant to be published: the client
 /// sends it to the server so it can cast from one set of parameters to another.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+///
+/// The serialized key is tagged with the version of its layout, keys serialized before the tag
+/// was introduced are read with [`KeySwitchingKeyV0`].
+#[derive(Clone, Debug, PartialEq)]
 pub struct KeySwitchingKey {
     pub(crate) key_switching_key: LweKeyswitchKeyOwned<u64>,
-    pub(crate) dest_server_key: ServerKey,
-    pub(crate) src_server_key: ServerKey,
+    pub(crate) server_keys: CastServerKeys,
+    /// Difference between the number of bits of the destination and source full message moduli,
+    /// rounded up for moduli which are not a power of 2.
+    ///
+    /// The cast itself is driven by the full message moduli of the source and destination
+    /// [`ServerKey`]s, changing this field has no effect on it.
+    #[deprecated(note = "use KeySwitchingKey::cast_rshift() instead")]
     pub cast_rshift: i8,
+    pub(crate) cast_lookup_table: CastLookupTableCache,
 }
 
 impl KeySwitchingKey {
     /// Generate a casting key. This can cast to several kinds of keys (shortint, integer, hlapi),
     /// depending on input.
     ///
+    /// # Preconditions
+    ///
+    /// In each key pair the [`ServerKey`] must have been generated from the [`ClientKey`], e.g. by
+    /// [`gen_keys`](`crate::shortint::gen_keys`). The keyswitching key is built from the client
+    /// keys only, while the cast applies lookup tables with the server keys: mismatched pairs
+    /// produce a casting key returning garbage without any error.
+    ///
+    /// A server key does not expose the secret keys it was generated from, so only the
+    /// parameters of each pair (moduli, LWE dimensions and ciphertext modulus) are checked: two
+    /// pairs generated with the same parameters cannot be told apart.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -54,207 +335,3318 @@ impl KeySwitchingKey {
         key_pair_2: (&ClientKey, &ServerKey),
         params: ShortintKeySwitchingParameters,
     ) -> Self {
-        // Creation of the key switching key
-        let key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
-            engine.new_key_switching_key(key_pair_1.0, key_pair_2.0, params)
-        });
+        ShortintEngine::with_thread_local_mut(|engine| {
+            Self::new_with_engine(engine, key_pair_1, key_pair_2, params)
+        })
+    }
+
+    /// Generate a casting key using the given [`ShortintEngine`] instead of the thread local one.
+    ///
+    /// The randomness of the keyswitching key comes from the generators of `engine`: with an
+    /// engine created by [`ShortintEngine::new_from_seeder`] from a fixed seed, the output is a
+    /// deterministic function of that seed and of the client keys, e.g. for reproducible tests.
+    ///
+    /// See [`KeySwitchingKey::new`] for the requirements on the key pairs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::generators::DeterministicSeeder;
+    /// use tfhe::core_crypto::commons::math::random::Seed;
+    /// use tfhe::core_crypto::prelude::ActivatedRandomGenerator;
+    /// use tfhe::shortint::engine::ShortintEngine;
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let new_engine = || {
+    ///     let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(Seed(42));
+    ///     ShortintEngine::new_from_seeder(&mut seeder)
+    /// };
+    ///
+    /// // The same seed gives the same casting key
+    /// let ksk = KeySwitchingKey::new_with_engine(
+    ///     &mut new_engine(),
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    /// let ksk_2 = KeySwitchingKey::new_with_engine(
+    ///     &mut new_engine(),
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    /// assert_eq!(ksk, ksk_2);
+    ///
+    /// let cipher = ck1.encrypt(1);
+    /// assert_eq!(ck2.decrypt(&ksk.cast(&cipher)), 1);
+    /// ```
+    pub fn new_with_engine(
+        engine: &mut ShortintEngine,
+        key_pair_1: (&ClientKey, &ServerKey),
+        key_pair_2: (&ClientKey, &ServerKey),
+        params: ShortintKeySwitchingParameters,
+    ) -> Self {
+        Self::new_sharing_server_keys(
+            engine,
+            (key_pair_1.0, &Arc::new(key_pair_1.1.clone())),
+            (key_pair_2.0, &Arc::new(key_pair_2.1.clone())),
+            params,
+        )
+    }
 
-        let full_message_modulus_1 =
-            key_pair_1.0.parameters.carry_modulus().0 * key_pair_1.0.parameters.message_modulus().0;
-        let full_message_modulus_2 =
-            key_pair_2.0.parameters.carry_modulus().0 * key_pair_2.0.parameters.message_modulus().0;
-        assert!(
-            full_message_modulus_1.is_power_of_two() && full_message_modulus_2.is_power_of_two(),
-            "Cannot create casting key if the full messages moduli are not a power of 2"
+    /// Same as [`KeySwitchingKey::new_with_engine`], the casting key holding references to the
+    /// given [`ServerKey`]s instead of copies, so that other keys can share them.
+    pub(crate) fn new_sharing_server_keys(
+        engine: &mut ShortintEngine,
+        key_pair_1: (&ClientKey, &Arc<ServerKey>),
+        key_pair_2: (&ClientKey, &Arc<ServerKey>),
+        params: ShortintKeySwitchingParameters,
+    ) -> Self {
+        assert_key_pair_consistency(
+            (key_pair_1.0, key_pair_1.1),
+            KeySwitchError::SrcKeyPairMismatch,
+        );
+        assert_key_pair_consistency(
+            (key_pair_2.0, key_pair_2.1),
+            KeySwitchError::DestKeyPairMismatch,
+        );
+        assert_full_message_moduli_fit(key_pair_1.0, key_pair_2.0);
+        assert_cast_ciphertext_moduli_compatible(
+            key_pair_1.0.parameters.ciphertext_modulus(),
+            key_pair_2.0.parameters.ciphertext_modulus(),
         );
 
-        let nb_bits_1: i8 = full_message_modulus_1.ilog2().try_into().unwrap();
-        let nb_bits_2: i8 = full_message_modulus_2.ilog2().try_into().unwrap();
+        // Creation of the key switching key
+        let key_switching_key = engine.new_key_switching_key(key_pair_1.0, key_pair_2.0, params);
 
         // Pack the keys in the casting key set:
-        Self {
-            key_switching_key,
-            dest_server_key: key_pair_2.1.clone(),
-            src_server_key: key_pair_1.1.clone(),
-            cast_rshift: nb_bits_2 - nb_bits_1,
-        }
-    }
-
-    /// Deconstruct a [`KeySwitchingKey`] into its constituents.
-    pub fn into_raw_parts(self) -> (LweKeyswitchKeyOwned<u64>, ServerKey, ServerKey, i8) {
-        let Self {
-            key_switching_key,
-            dest_server_key,
-            src_server_key,
-            cast_rshift,
-        } = self;
-
-        (
+        Self::try_from_server_keys(
             key_switching_key,
-            dest_server_key,
-            src_server_key,
-            cast_rshift,
+            CastServerKeys::Distinct {
+                src: key_pair_1.1.clone(),
+                dest: key_pair_2.1.clone(),
+            },
         )
+        .unwrap_or_else(|err| panic!("{err}"))
     }
 
-    /// Construct a [`KeySwitchingKey`] from its constituents.
+    /// Generate a casting key from the two client keys only, without any [`ServerKey`].
+    ///
+    /// Such a key can only keyswitch: [`KeySwitchingKey::cast`] and [`KeySwitchingKey::cast_into`]
+    /// work as the source and destination have the same moduli, but anything requiring a
+    /// [`ServerKey`], like [`KeySwitchingKey::cast_into_with_bootstrap`], panics.
     ///
     /// # Panics
     ///
-    /// Panics if the provided raw parts are not compatible with each other, i.e.:
+    /// Panics if the client keys do not have the same message and carry moduli, as the cast would
+    /// then require a lookup table to shift the message.
     ///
-    /// if the provided source [`ServerKey`] ciphertext
-    /// [`LweDimension`](`crate::core_crypto::commons::parameters::LweDimension`) does not match the
-    /// input [`LweDimension`](`crate::core_crypto::commons::parameters::LweDimension`) of the
-    /// provided [`LweKeyswitchKeyOwned`] or if the provided destination [`ServerKey`]
-    /// ciphertext [`LweDimension`](`crate::core_crypto::commons::parameters::LweDimension`)
-    /// does not match the output
-    /// [`LweDimension`](`crate::core_crypto::commons::parameters::LweDimension`) of the
-    /// provided [`LweKeyswitchKeyOwned`].
-    pub fn from_raw_parts(
-        key_switching_key: LweKeyswitchKeyOwned<u64>,
-        dest_server_key: ServerKey,
-        src_server_key: ServerKey,
-        cast_rshift: i8,
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::KeySwitchingKey;
+    ///
+    /// // Generate the client keys only:
+    /// let ck1 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let ck2 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the casting key:
+    /// let ksk_params = ShortintKeySwitchingParameters::new(
+    ///     ck2.parameters.ks_base_log(),
+    ///     ck2.parameters.ks_level(),
+    /// );
+    /// let ksk = KeySwitchingKey::new_keyswitch_only((&ck1, &ck2), ksk_params);
+    ///
+    /// let cleartext = 3;
+    /// let cipher = ck1.encrypt(cleartext);
+    /// let cipher_2 = ksk.cast(&cipher);
+    ///
+    /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    /// ```
+    pub fn new_keyswitch_only(
+        key_pair: (&ClientKey, &ClientKey),
+        params: ShortintKeySwitchingParameters,
     ) -> Self {
-        let src_lwe_dimension = src_server_key.ciphertext_lwe_dimension();
-        let dst_lwe_dimension = dest_server_key.ciphertext_lwe_dimension();
+        Self::try_new_keyswitch_only(key_pair, params).unwrap_or_else(|err| panic!("{err}"))
+    }
 
-        assert_eq!(
-            src_lwe_dimension,
-            key_switching_key.input_key_lwe_dimension(),
-            "Mismatch between the source ServerKey ciphertext LweDimension ({:?}) \
-            and the LweKeyswitchKey input LweDimension ({:?})",
-            src_lwe_dimension,
-            key_switching_key.input_key_lwe_dimension(),
-        );
-        assert_eq!(
-            dst_lwe_dimension,
-            key_switching_key.output_key_lwe_dimension(),
-            "Mismatch between the destination ServerKey ciphertext LweDimension ({:?}) \
-            and the LweKeyswitchKey output LweDimension ({:?})",
-            dst_lwe_dimension,
-            key_switching_key.output_key_lwe_dimension(),
-        );
-        assert_eq!(
-            src_server_key.ciphertext_modulus, dest_server_key.ciphertext_modulus,
-            "Mismatch between the source ServerKey CiphertextModulus ({:?}) \
-            and the destination ServerKey CiphertextModulus ({:?})",
-            src_server_key.ciphertext_modulus, dest_server_key.ciphertext_modulus,
+    /// Same as [`KeySwitchingKey::new_keyswitch_only`], returning an error instead of panicking
+    /// if the client keys do not have the same message and carry moduli.
+    pub fn try_new_keyswitch_only(
+        key_pair: (&ClientKey, &ClientKey),
+        params: ShortintKeySwitchingParameters,
+    ) -> Result<Self, KeySwitchError> {
+        let (src_client_key, dest_client_key) = key_pair;
+
+        let src_moduli = (
+            src_client_key.parameters.message_modulus(),
+            src_client_key.parameters.carry_modulus(),
         );
-        assert_eq!(
-            key_switching_key.ciphertext_modulus(),
-            dest_server_key.ciphertext_modulus,
-            "Mismatch between the LweKeyswitchKey CiphertextModulus ({:?}) \
-            and the destination ServerKey CiphertextModulus ({:?})",
-            key_switching_key.ciphertext_modulus(),
-            dest_server_key.ciphertext_modulus,
+        let dest_moduli = (
+            dest_client_key.parameters.message_modulus(),
+            dest_client_key.parameters.carry_modulus(),
         );
 
-        Self {
-            key_switching_key,
-            dest_server_key,
-            src_server_key,
-            cast_rshift,
+        if src_moduli != dest_moduli {
+            return Err(KeySwitchError::KeyswitchOnlyModuliMismatch {
+                src: src_moduli,
+                dest: dest_moduli,
+            });
         }
+
+        let (message_modulus, carry_modulus) = src_moduli;
+        try_cast_rshift(src_client_key, dest_client_key)?;
+        try_cast_ciphertext_moduli(
+            src_client_key.parameters.ciphertext_modulus(),
+            dest_client_key.parameters.ciphertext_modulus(),
+        )?;
+
+        // Creation of the key switching key
+        let key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_key_switching_key(src_client_key, dest_client_key, params)
+        });
+
+        Self::try_from_server_keys(
+            key_switching_key,
+            CastServerKeys::KeyswitchOnly {
+                message_modulus,
+                carry_modulus,
+            },
+        )
     }
 
-    /// Cast a ciphertext from the source parameter set to the dest parameter set,
-    /// using provided &mut.
+    /// Generate a casting key storing only the source [`ServerKey`], the destination being
+    /// described by the parameters of `dest_client_key`.
     ///
-    /// # Example (the following code won't actually run because this function is private)
+    /// When casting to a smaller or equal full message modulus (`cast_rshift <= 0`) the
+    /// destination [`ServerKey`] is not needed to cast, this avoids keeping a copy of it in the
+    /// casting key. [`KeySwitchingKey::cast`] and [`KeySwitchingKey::cast_into`] work as with
+    /// [`KeySwitchingKey::new`], but anything requiring a destination side PBS, like
+    /// [`KeySwitchingKey::cast_into_with_bootstrap`], panics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the destination full message modulus is bigger than the source one, as the cast
+    /// would then require a lookup table applied with the destination [`ServerKey`].
+    ///
+    /// # Example
     ///
     /// ```rust
     /// use tfhe::shortint::parameters::{
-    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
     /// };
     /// use tfhe::shortint::prelude::*;
     /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
     ///
-    /// // Generate the client keys and server keys:
-    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
-    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// // Generate the source keys and the destination client key only:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let ck2 = ClientKey::new(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
     ///
-    /// // Generate the server key:
-    /// let ksk = KeySwitchingKey::new(
-    ///     (&ck1, &sk1),
-    ///     (&ck2, &sk2),
-    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// // Generate the casting key:
+    /// let ksk_params = ShortintKeySwitchingParameters::new(
+    ///     ck2.parameters.ks_base_log(),
+    ///     ck2.parameters.ks_level(),
     /// );
+    /// let ksk = KeySwitchingKey::new_without_dest_server_key((&ck1, &sk1), &ck2, ksk_params);
+    ///
+    /// assert_eq!(ksk.cast_rshift(), -2);
     ///
     /// let cleartext = 1;
     /// let cipher = ck1.encrypt(cleartext);
-    /// let mut cipher_2 = sk2.create_trivial(0);
-    /// ksk.cast_into(&cipher, &mut cipher_2);
+    /// let cipher_2 = ksk.cast(&cipher);
     ///
     /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
     /// ```
-    pub fn cast_into(&self, ct: &Ciphertext, ct_dest: &mut Ciphertext) {
-        match self.cast_rshift {
-            // Same bit size: only key switch
-            0 => keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut ct_dest.ct),
+    pub fn new_without_dest_server_key(
+        src_key_pair: (&ClientKey, &ServerKey),
+        dest_client_key: &ClientKey,
+        params: ShortintKeySwitchingParameters,
+    ) -> Self {
+        Self::try_new_without_dest_server_key(src_key_pair, dest_client_key, params)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
 
-            // Cast to bigger bit length: keyswitch, then right shift
-            i if i > 0 => {
-                keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut ct_dest.ct);
+    /// Same as [`KeySwitchingKey::new_without_dest_server_key`], returning an error instead of
+    /// panicking if the source [`ServerKey`] does not match the source [`ClientKey`] or if the cast
+    /// requires the destination [`ServerKey`].
+    pub fn try_new_without_dest_server_key(
+        src_key_pair: (&ClientKey, &ServerKey),
+        dest_client_key: &ClientKey,
+        params: ShortintKeySwitchingParameters,
+    ) -> Result<Self, KeySwitchError> {
+        try_key_pair_consistency(src_key_pair, KeySwitchError::SrcKeyPairMismatch)?;
 
-                let acc = self.dest_server_key.generate_lookup_table(|n| n >> i);
-                self.dest_server_key
-                    .apply_lookup_table_assign(ct_dest, &acc);
-            }
+        let cast_rshift = try_cast_rshift(src_key_pair.0, dest_client_key)?;
 
-            // Cast to smaller bit length: left shift, then keyswitch
-            i if i < 0 => {
-                // We want to avoid the padding bit to be dirty, hence the modulus
-                let acc = self.src_server_key.generate_lookup_table(|n| {
-                    (n << -i) % (ct.carry_modulus.0 * ct.message_modulus.0) as u64
-                });
-                let shifted_cipher = self.src_server_key.apply_lookup_table(ct, &acc);
-
-                keyswitch_lwe_ciphertext(
-                    &self.key_switching_key,
-                    &shifted_cipher.ct,
-                    &mut ct_dest.ct,
-                );
-            }
+        if cast_rshift > 0 {
+            return Err(KeySwitchError::MissingDestServerKey { cast_rshift });
+        }
 
-            _ => unreachable!(),
-        };
+        try_cast_ciphertext_moduli(
+            src_key_pair.0.parameters.ciphertext_modulus(),
+            dest_client_key.parameters.ciphertext_modulus(),
+        )?;
+
+        // Creation of the key switching key
+        let key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_key_switching_key(src_key_pair.0, dest_client_key, params)
+        });
+
+        Self::try_from_server_keys(
+            key_switching_key,
+            CastServerKeys::SrcOnly {
+                src: Arc::new(src_key_pair.1.clone()),
+                dest_message_modulus: dest_client_key.parameters.message_modulus(),
+                dest_carry_modulus: dest_client_key.parameters.carry_modulus(),
+            },
+        )
     }
 
-    /// Cast a ciphertext from the source parameter set to the dest parameter set,
-    /// returning a new ciphertext.
+    /// Generate a casting key between two client keys using the same parameters, the source and
+    /// destination sharing the given [`ServerKey`].
     ///
-    /// # Example (the following code won't actually run because this function is private)
+    /// Only the keyswitching material between the two client keys is generated and a single
+    /// [`ServerKey`] is stored, instead of the two copies kept by [`KeySwitchingKey::new`]. As the
+    /// parameters are the same on both sides the cast is only a keyswitch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client keys do not have the message modulus, carry modulus and large LWE
+    /// dimension of the [`ServerKey`].
+    ///
+    /// # Example
     ///
     /// ```rust
     /// use tfhe::shortint::parameters::{
-    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
     /// };
     /// use tfhe::shortint::prelude::*;
     /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
     ///
-    /// // Generate the client keys and server keys:
-    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
-    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// // Generate the client keys and the shared server key:
+    /// let (ck1, sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let ck2 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
     ///
-    /// // Generate the server key:
-    /// let ksk = KeySwitchingKey::new(
-    ///     (&ck1, &sk1),
-    ///     (&ck2, &sk2),
-    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// // Generate the casting key:
+    /// let ksk_params = ShortintKeySwitchingParameters::new(
+    ///     ck2.parameters.ks_base_log(),
+    ///     ck2.parameters.ks_level(),
     /// );
+    /// let ksk = KeySwitchingKey::new_with_shared_server_key(&ck1, &ck2, &sk, ksk_params);
     ///
-    /// let cleartext = 1;
-    ///
+    /// let cleartext = 3;
     /// let cipher = ck1.encrypt(cleartext);
     /// let cipher_2 = ksk.cast(&cipher);
     ///
     /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
     /// ```
-    pub fn cast(&self, ct: &Ciphertext) -> Ciphertext {
-        let mut ret = self.dest_server_key.create_trivial(0);
+    pub fn new_with_shared_server_key(
+        src_client_key: &ClientKey,
+        dest_client_key: &ClientKey,
+        server_key: &ServerKey,
+        params: ShortintKeySwitchingParameters,
+    ) -> Self {
+        Self::try_new_with_shared_server_key(src_client_key, dest_client_key, server_key, params)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Same as [`KeySwitchingKey::new_with_shared_server_key`], returning an error instead of
+    /// panicking if a client key does not match the [`ServerKey`].
+    pub fn try_new_with_shared_server_key(
+        src_client_key: &ClientKey,
+        dest_client_key: &ClientKey,
+        server_key: &ServerKey,
+        params: ShortintKeySwitchingParameters,
+    ) -> Result<Self, KeySwitchError> {
+        for client_key in [src_client_key, dest_client_key] {
+            if (
+                client_key.parameters.message_modulus(),
+                client_key.parameters.carry_modulus(),
+                client_key.large_lwe_secret_key().lwe_dimension(),
+            ) != (
+                server_key.message_modulus,
+                server_key.carry_modulus,
+                server_key.bootstrapping_key.output_lwe_dimension(),
+            ) {
+                return Err(KeySwitchError::SharedServerKeyParametersMismatch);
+            }
+        }
+
+        try_cast_rshift(src_client_key, dest_client_key)?;
+        try_cast_ciphertext_moduli(
+            src_client_key.parameters.ciphertext_modulus(),
+            dest_client_key.parameters.ciphertext_modulus(),
+        )?;
+
+        // Creation of the key switching key
+        let key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_key_switching_key(src_client_key, dest_client_key, params)
+        });
+
+        Self::try_from_server_keys(
+            key_switching_key,
+            CastServerKeys::Shared(Arc::new(server_key.clone())),
+        )
+    }
+
+    /// Generate a key rotation key between two key pairs generated from the same parameters.
+    ///
+    /// The cast is a keyswitch from the old secret key to the new one, ciphertexts can then be
+    /// bootstrapped with the new [`ServerKey`], e.g. with
+    /// [`KeySwitchingKey::cast_into_with_bootstrap`], to also refresh their noise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two client keys do not use the same parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the old and new key pairs:
+    /// let (ck_old, sk_old) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let (ck_new, sk_new) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the key rotation key:
+    /// let ksk_params = ShortintKeySwitchingParameters::new(
+    ///     ck_new.parameters.ks_base_log(),
+    ///     ck_new.parameters.ks_level(),
+    /// );
+    /// let ksk = KeySwitchingKey::new_refresh((&ck_old, &sk_old), (&ck_new, &sk_new), ksk_params);
+    ///
+    /// let cleartext = 3;
+    /// let cipher = ck_old.encrypt(cleartext);
+    /// let cipher_2 = ksk.cast(&cipher);
+    ///
+    /// assert_eq!(ck_new.decrypt(&cipher_2), cleartext);
+    /// ```
+    pub fn new_refresh(
+        old_key_pair: (&ClientKey, &ServerKey),
+        new_key_pair: (&ClientKey, &ServerKey),
+        params: ShortintKeySwitchingParameters,
+    ) -> Self {
+        Self::try_new_refresh(old_key_pair, new_key_pair, params)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Same as [`KeySwitchingKey::new_refresh`], returning an error instead of panicking if the
+    /// client keys do not use the same parameters or if a [`ServerKey`] does not match its
+    /// [`ClientKey`].
+    pub fn try_new_refresh(
+        old_key_pair: (&ClientKey, &ServerKey),
+        new_key_pair: (&ClientKey, &ServerKey),
+        params: ShortintKeySwitchingParameters,
+    ) -> Result<Self, KeySwitchError> {
+        if old_key_pair.0.parameters != new_key_pair.0.parameters {
+            return Err(KeySwitchError::RefreshParametersMismatch);
+        }
+        try_key_pair_consistency(old_key_pair, KeySwitchError::SrcKeyPairMismatch)?;
+        try_key_pair_consistency(new_key_pair, KeySwitchError::DestKeyPairMismatch)?;
+        try_cast_rshift(old_key_pair.0, new_key_pair.0)?;
+        try_cast_ciphertext_moduli(
+            old_key_pair.0.parameters.ciphertext_modulus(),
+            new_key_pair.0.parameters.ciphertext_modulus(),
+        )?;
+
+        // Creation of the key switching key
+        let key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_key_switching_key(old_key_pair.0, new_key_pair.0, params)
+        });
+
+        Self::try_from_server_keys(
+            key_switching_key,
+            CastServerKeys::Distinct {
+                src: Arc::new(old_key_pair.1.clone()),
+                dest: Arc::new(new_key_pair.1.clone()),
+            },
+        )
+    }
+
+    /// Deconstruct a [`KeySwitchingKey`] into its constituents.
+    ///
+    /// If the source and destination share a [`ServerKey`] it is cloned to be returned for both.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`KeySwitchingKey`] was built with [`KeySwitchingKey::new_keyswitch_only`] or
+    /// [`KeySwitchingKey::new_without_dest_server_key`], see
+    /// [`KeySwitchingKey::try_into_raw_parts`].
+    pub fn into_raw_parts(self) -> (LweKeyswitchKeyOwned<u64>, ServerKey, ServerKey, i8) {
+        self.try_into_raw_parts()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Same as [`KeySwitchingKey::into_raw_parts`], returning an error instead of panicking if the
+    /// [`KeySwitchingKey`] does not store both [`ServerKey`]s.
+    ///
+    /// The [`KeySwitchingKey`] is consumed even if an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::{gen_keys, ClientKey, KeySwitchError, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let ck2 = ClientKey::new(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    ///
+    /// // Casting to a smaller full message modulus only bootstraps with the source ServerKey
+    /// let ksk = KeySwitchingKey::new_without_dest_server_key(
+    ///     (&ck1, &sk1),
+    ///     &ck2,
+    ///     ShortintKeySwitchingParameters::new(
+    ///         ck2.parameters.ks_base_log(),
+    ///         ck2.parameters.ks_level(),
+    ///     ),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     ksk.try_into_raw_parts().unwrap_err(),
+    ///     KeySwitchError::DestServerKeyNotStored
+    /// );
+    /// ```
+    pub fn try_into_raw_parts(
+        self,
+    ) -> Result<(LweKeyswitchKeyOwned<u64>, ServerKey, ServerKey, i8), KeySwitchError> {
+        let cast_rshift = self.cast_rshift();
+
+        let Self {
+            key_switching_key,
+            server_keys,
+            ..
+        } = self;
+
+        let (dest_server_key, src_server_key) = match server_keys {
+            CastServerKeys::Distinct { src, dest } => (
+                unwrap_or_clone_server_key(dest),
+                unwrap_or_clone_server_key(src),
+            ),
+            CastServerKeys::Shared(server_key) => {
+                let server_key = unwrap_or_clone_server_key(server_key);
+                (server_key.clone(), server_key)
+            }
+            CastServerKeys::KeyswitchOnly { .. } => {
+                return Err(KeySwitchError::ServerKeysNotStored)
+            }
+            CastServerKeys::SrcOnly { .. } => return Err(KeySwitchError::DestServerKeyNotStored),
+        };
+
+        Ok((
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+            cast_rshift,
+        ))
+    }
+
+    /// Check that raw parts are consistent with each other, returning the error
+    /// [`KeySwitchingKey::from_raw_parts`] would panic with otherwise.
+    ///
+    /// This allows validating keys reconstructed from untrusted storage before using them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::{gen_keys, KeySwitchError, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let (raw_ksk, dest_sk, src_sk, cast_rshift) = ksk.into_raw_parts();
+    ///
+    /// assert!(KeySwitchingKey::validate_raw_parts(&raw_ksk, &dest_sk, &src_sk).is_ok());
+    ///
+    /// // Swapping the ServerKeys is detected
+    /// assert!(matches!(
+    ///     KeySwitchingKey::validate_raw_parts(&raw_ksk, &src_sk, &dest_sk),
+    ///     Err(KeySwitchError::KeyswitchKeyInputLweDimensionMismatch { .. })
+    /// ));
+    ///
+    /// let _ = KeySwitchingKey::from_raw_parts(raw_ksk, dest_sk, src_sk, cast_rshift);
+    /// ```
+    pub fn validate_raw_parts(
+        key_switching_key: &LweKeyswitchKeyOwned<u64>,
+        dest_server_key: &ServerKey,
+        src_server_key: &ServerKey,
+    ) -> Result<(), KeySwitchError> {
+        let src_lwe_dimension = src_server_key.ciphertext_lwe_dimension();
+        let dst_lwe_dimension = dest_server_key.ciphertext_lwe_dimension();
+
+        if src_lwe_dimension != key_switching_key.input_key_lwe_dimension() {
+            return Err(KeySwitchError::KeyswitchKeyInputLweDimensionMismatch {
+                src_server_key: src_lwe_dimension,
+                key_switching_key: key_switching_key.input_key_lwe_dimension(),
+            });
+        }
+
+        if dst_lwe_dimension != key_switching_key.output_key_lwe_dimension() {
+            return Err(KeySwitchError::KeyswitchKeyOutputLweDimensionMismatch {
+                dest_server_key: dst_lwe_dimension,
+                key_switching_key: key_switching_key.output_key_lwe_dimension(),
+            });
+        }
+
+        let dest_ciphertext_modulus = dest_server_key.ciphertext_modulus;
+
+        try_cast_ciphertext_moduli(src_server_key.ciphertext_modulus, dest_ciphertext_modulus)?;
+
+        if key_switching_key.ciphertext_modulus() != dest_ciphertext_modulus {
+            return Err(KeySwitchError::KeyswitchKeyCiphertextModulusMismatch {
+                dest_server_key: dest_ciphertext_modulus,
+                key_switching_key: key_switching_key.ciphertext_modulus(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Construct a [`KeySwitchingKey`] from its constituents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided raw parts are not compatible with each other (see
+    /// [`KeySwitchingKey::validate_raw_parts`] for a non panicking check), i.e.:
+    ///
+    /// if the provided source [`ServerKey`] ciphertext
+    /// [`LweDimension`](`crate::core_crypto::commons::parameters::LweDimension`) does not match the
+    /// input [`LweDimension`](`crate::core_crypto::commons::parameters::LweDimension`) of the
+    /// provided [`LweKeyswitchKeyOwned`] or if the provided destination [`ServerKey`]
+    /// ciphertext [`LweDimension`](`crate::core_crypto::commons::parameters::LweDimension`)
+    /// does not match the output
+    /// [`LweDimension`](`crate::core_crypto::commons::parameters::LweDimension`) of the
+    /// provided [`LweKeyswitchKeyOwned`].
+    ///
+    /// The source and destination [`ServerKey`]s may use different
+    /// [`CiphertextModulus`](`crate::shortint::parameters::CiphertextModulus`) as long as both are
+    /// powers of two and the destination one is not bigger than the source one, the
+    /// [`LweKeyswitchKeyOwned`] must use the destination one.
+    ///
+    /// Also panics if the magnitude of `cast_rshift` is not smaller than the number of bits of the
+    /// full message modulus it is applied in, i.e. the destination one for a positive shift and
+    /// the source one for a negative shift, as all the message bits would be shifted out, or if it
+    /// differs from the one derived from the [`ServerKey`]s, see [`KeySwitchingKey::cast_rshift`].
+    pub fn from_raw_parts(
+        key_switching_key: LweKeyswitchKeyOwned<u64>,
+        dest_server_key: ServerKey,
+        src_server_key: ServerKey,
+        cast_rshift: i8,
+    ) -> Self {
+        Self::try_from_raw_parts(
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+            cast_rshift,
+        )
+        .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Same as [`KeySwitchingKey::from_raw_parts`], returning the error of
+    /// [`KeySwitchingKey::validate_raw_parts`] instead of panicking if the raw parts are not
+    /// compatible with each other, [`KeySwitchError::CastRshiftOutOfRange`] if `cast_rshift` is
+    /// too large or [`KeySwitchError::CastRshiftMismatch`] if it does not match the [`ServerKey`]s.
+    pub fn try_from_raw_parts(
+        key_switching_key: LweKeyswitchKeyOwned<u64>,
+        dest_server_key: ServerKey,
+        src_server_key: ServerKey,
+        cast_rshift: i8,
+    ) -> Result<Self, KeySwitchError> {
+        Self::validate_raw_parts(&key_switching_key, &dest_server_key, &src_server_key)?;
+        check_cast_rshift(cast_rshift, &src_server_key, &dest_server_key)?;
+
+        Self::try_from_server_keys(
+            key_switching_key,
+            CastServerKeys::Distinct {
+                src: Arc::new(src_server_key),
+                dest: Arc::new(dest_server_key),
+            },
+        )
+    }
+
+    /// Split a [`KeySwitchingKey`] into its [`KeySwitchingKeyMaterial`] and its destination and
+    /// source [`ServerKey`]s, in that order.
+    ///
+    /// The material can then be serialized and transmitted without the [`ServerKey`]s, to be
+    /// reassembled with [`KeySwitchingKey::from_material`]. If the source and destination share a
+    /// [`ServerKey`] it is cloned to be returned for both.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`KeySwitchingKey`] was built with [`KeySwitchingKey::new_keyswitch_only`] or
+    /// [`KeySwitchingKey::new_without_dest_server_key`], see
+    /// [`KeySwitchingKey::try_split_material`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey, KeySwitchingKeyMaterial};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// // Only the material is sent, the server already has sk1 and sk2
+    /// let (material, _, _) = ksk.split_material();
+    /// let serialized_material = bincode::serialize(&material).unwrap();
+    ///
+    /// let material: KeySwitchingKeyMaterial = bincode::deserialize(&serialized_material).unwrap();
+    /// let ksk = KeySwitchingKey::from_material(material, sk2, sk1);
+    ///
+    /// let cipher = ck1.encrypt(1);
+    /// let cipher_2 = ksk.cast(&cipher);
+    ///
+    /// assert_eq!(ck2.decrypt(&cipher_2), 1);
+    /// ```
+    pub fn split_material(self) -> (KeySwitchingKeyMaterial, ServerKey, ServerKey) {
+        self.try_split_material()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Same as [`KeySwitchingKey::split_material`], returning the error of
+    /// [`KeySwitchingKey::try_into_raw_parts`] instead of panicking if the [`KeySwitchingKey`] does
+    /// not store both [`ServerKey`]s.
+    pub fn try_split_material(
+        self,
+    ) -> Result<(KeySwitchingKeyMaterial, ServerKey, ServerKey), KeySwitchError> {
+        let (key_switching_key, dest_server_key, src_server_key, cast_rshift) =
+            self.try_into_raw_parts()?;
+
+        Ok((
+            KeySwitchingKeyMaterial::from_raw_parts(key_switching_key, cast_rshift),
+            dest_server_key,
+            src_server_key,
+        ))
+    }
+
+    /// Reassemble a [`KeySwitchingKey`] from its [`KeySwitchingKeyMaterial`] and its destination
+    /// and source [`ServerKey`]s.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the material is not compatible with the [`ServerKey`]s, see
+    /// [`KeySwitchingKey::from_raw_parts`].
+    pub fn from_material(
+        material: KeySwitchingKeyMaterial,
+        dest_server_key: ServerKey,
+        src_server_key: ServerKey,
+    ) -> Self {
+        Self::try_from_material(material, dest_server_key, src_server_key)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Same as [`KeySwitchingKey::from_material`], returning the error of
+    /// [`KeySwitchingKey::validate_raw_parts`] instead of panicking if the material is not
+    /// compatible with the [`ServerKey`]s.
+    pub fn try_from_material(
+        material: KeySwitchingKeyMaterial,
+        dest_server_key: ServerKey,
+        src_server_key: ServerKey,
+    ) -> Result<Self, KeySwitchError> {
+        let (key_switching_key, cast_rshift) = material.into_raw_parts();
+
+        Self::try_from_raw_parts(
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+            cast_rshift,
+        )
+    }
+
+    /// Serialize the keyswitching material of the [`KeySwitchingKey`] and its `cast_rshift` in a
+    /// fixed little-endian layout, independent of serde.
+    ///
+    /// The [`ServerKey`]s are not included and have to be serialized separately.
+    ///
+    /// The layout is, with all integers in little-endian:
+    ///
+    /// | Offset | Size    | Content                                               |
+    /// |--------|---------|-------------------------------------------------------|
+    /// | 0      | 4       | the magic bytes `b"TKSK"`                             |
+    /// | 4      | 1       | the format version, currently 1                       |
+    /// | 5      | 1       | `cast_rshift` as an `i8`                              |
+    /// | 6      | 8       | the decomposition base log as a `u64`                 |
+    /// | 14     | 8       | the decomposition level count as a `u64`              |
+    /// | 22     | 8       | the input key [`LweDimension`] as a `u64`             |
+    /// | 30     | 8       | the output key [`LweDimension`] as a `u64`            |
+    /// | 38     | 16      | the ciphertext modulus as a `u128`, 0 for native      |
+    /// | 54     | 8 * len | the [`LweKeyswitchKeyOwned`] container as `u64`s      |
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let bytes = ksk.to_bytes();
+    /// let ksk_2 = KeySwitchingKey::from_bytes(&bytes, sk2, sk1).unwrap();
+    ///
+    /// assert_eq!(ksk, ksk_2);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let data = self.key_switching_key.as_ref();
+        let mut bytes =
+            Vec::with_capacity(KEY_SWITCHING_KEY_BYTES_HEADER_LEN + std::mem::size_of_val(data));
+
+        bytes.extend_from_slice(&self.bytes_header());
+
+        for value in data {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        bytes
+    }
+
+    /// Return the header of the layout of [`KeySwitchingKey::to_bytes`].
+    fn bytes_header(&self) -> [u8; KEY_SWITCHING_KEY_BYTES_HEADER_LEN] {
+        let ksk = &self.key_switching_key;
+        let ciphertext_modulus = ksk.ciphertext_modulus();
+        let raw_ciphertext_modulus = if ciphertext_modulus.is_native_modulus() {
+            0
+        } else {
+            ciphertext_modulus.get_custom_modulus()
+        };
+
+        let mut header = Vec::with_capacity(KEY_SWITCHING_KEY_BYTES_HEADER_LEN);
+
+        header.extend_from_slice(&KEY_SWITCHING_KEY_BYTES_MAGIC);
+        header.push(KEY_SWITCHING_KEY_BYTES_VERSION);
+        header.extend_from_slice(&self.cast_rshift().to_le_bytes());
+        header.extend_from_slice(&(ksk.decomposition_base_log().0 as u64).to_le_bytes());
+        header.extend_from_slice(&(ksk.decomposition_level_count().0 as u64).to_le_bytes());
+        header.extend_from_slice(&(ksk.input_key_lwe_dimension().0 as u64).to_le_bytes());
+        header.extend_from_slice(&(ksk.output_key_lwe_dimension().0 as u64).to_le_bytes());
+        header.extend_from_slice(&raw_ciphertext_modulus.to_le_bytes());
+
+        // The header length is fixed by the layout
+        header.try_into().unwrap()
+    }
+
+    /// Compute an accidental-corruption checksum of the keyswitching material of the
+    /// [`KeySwitchingKey`], to detect storage or transmission errors in a key loaded back with a
+    /// checksum stored alongside it.
+    ///
+    /// The checksum is the 64 bits FNV-1a hash of the bytes returned by
+    /// [`KeySwitchingKey::to_bytes`], computed without allocating them, it is therefore equal to
+    /// [`KeySwitchingKey::integrity_digest_of_bytes`] called on these bytes and stable across
+    /// platforms and versions of this crate using the same byte layout. The [`ServerKey`]s are
+    /// not covered, as they are not part of that layout.
+    ///
+    /// This is not an integrity check against an attacker: FNV-1a is not a cryptographic hash and
+    /// a key can trivially be modified to keep the same checksum. Keys from untrusted sources must
+    /// be authenticated by other means, e.g. a cryptographic MAC or signature over the bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// // Store the checksum with the key
+    /// let digest = ksk.integrity_digest();
+    /// let mut bytes = ksk.to_bytes();
+    ///
+    /// // The bytes can be checked before decoding them
+    /// assert_eq!(KeySwitchingKey::integrity_digest_of_bytes(&bytes), digest);
+    ///
+    /// // An accidentally corrupted key is detected
+    /// *bytes.last_mut().unwrap() ^= 1;
+    /// assert_ne!(KeySwitchingKey::integrity_digest_of_bytes(&bytes), digest);
+    /// ```
+    pub fn integrity_digest(&self) -> u64 {
+        let hash = fnv1a_64(FNV1A_64_OFFSET_BASIS, &self.bytes_header());
+
+        self.key_switching_key
+            .as_ref()
+            .iter()
+            .fold(hash, |hash, value| fnv1a_64(hash, &value.to_le_bytes()))
+    }
+
+    /// Compute the accidental-corruption checksum of bytes in the layout of
+    /// [`KeySwitchingKey::to_bytes`].
+    ///
+    /// See [`KeySwitchingKey::integrity_digest`], this is not a cryptographic hash.
+    pub fn integrity_digest_of_bytes(bytes: &[u8]) -> u64 {
+        fnv1a_64(FNV1A_64_OFFSET_BASIS, bytes)
+    }
+
+    /// Deserialize a [`KeySwitchingKey`] from the layout of [`KeySwitchingKey::to_bytes`] and the
+    /// separately stored [`ServerKey`]s.
+    ///
+    /// Returns [`KeySwitchingKeyBytesError::IncompatibleKeys`] with the error of
+    /// [`KeySwitchingKey::try_from_raw_parts`] if the decoded keyswitching material is not
+    /// compatible with the [`ServerKey`]s.
+    pub fn from_bytes(
+        bytes: &[u8],
+        dest_server_key: ServerKey,
+        src_server_key: ServerKey,
+    ) -> Result<Self, KeySwitchingKeyBytesError> {
+        if bytes.len() < KEY_SWITCHING_KEY_BYTES_HEADER_LEN {
+            return Err(KeySwitchingKeyBytesError::InvalidLength {
+                expected: KEY_SWITCHING_KEY_BYTES_HEADER_LEN,
+                got: bytes.len(),
+            });
+        }
+
+        let (header, data) = bytes.split_at(KEY_SWITCHING_KEY_BYTES_HEADER_LEN);
+
+        if header[0..4] != KEY_SWITCHING_KEY_BYTES_MAGIC {
+            return Err(KeySwitchingKeyBytesError::InvalidMagic);
+        }
+
+        if header[4] != KEY_SWITCHING_KEY_BYTES_VERSION {
+            return Err(KeySwitchingKeyBytesError::UnsupportedVersion(header[4]));
+        }
+
+        let read_usize = |offset: usize| {
+            let value = u64::from_le_bytes(header[offset..offset + 8].try_into().unwrap());
+            usize::try_from(value).map_err(|_| KeySwitchingKeyBytesError::InvalidParameters)
+        };
+
+        let cast_rshift = i8::from_le_bytes([header[5]]);
+        let decomp_base_log = read_usize(6)?;
+        let decomp_level_count = read_usize(14)?;
+        let input_key_lwe_dimension = read_usize(22)?;
+        let output_key_lwe_dimension = read_usize(30)?;
+        let raw_ciphertext_modulus = u128::from_le_bytes(header[38..54].try_into().unwrap());
+
+        if decomp_base_log == 0
+            || decomp_level_count == 0
+            || decomp_base_log.saturating_mul(decomp_level_count) >= u64::BITS as usize
+            || input_key_lwe_dimension == 0
+            || output_key_lwe_dimension == 0
+        {
+            return Err(KeySwitchingKeyBytesError::InvalidParameters);
+        }
+
+        let ciphertext_modulus = CiphertextModulus::<u64>::try_new(raw_ciphertext_modulus)
+            .map_err(|_| {
+                KeySwitchingKeyBytesError::InvalidCiphertextModulus(raw_ciphertext_modulus)
+            })?;
+
+        let expected_len = input_key_lwe_dimension
+            .checked_mul(decomp_level_count)
+            .and_then(|len| len.checked_mul(output_key_lwe_dimension.checked_add(1)?))
+            .and_then(|len| len.checked_mul(std::mem::size_of::<u64>()))
+            .ok_or(KeySwitchingKeyBytesError::InvalidParameters)?;
+
+        if data.len() != expected_len {
+            return Err(KeySwitchingKeyBytesError::InvalidLength {
+                expected: KEY_SWITCHING_KEY_BYTES_HEADER_LEN + expected_len,
+                got: bytes.len(),
+            });
+        }
+
+        let container: Vec<u64> = data
+            .chunks_exact(std::mem::size_of::<u64>())
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+
+        let key_switching_key = LweKeyswitchKeyOwned::from_container(
+            container,
+            DecompositionBaseLog(decomp_base_log),
+            DecompositionLevelCount(decomp_level_count),
+            LweDimension(output_key_lwe_dimension).to_lwe_size(),
+            ciphertext_modulus,
+        );
+
+        Self::try_from_raw_parts(
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+            cast_rshift,
+        )
+        .map_err(KeySwitchingKeyBytesError::IncompatibleKeys)
+    }
+
+    /// Return the size in bytes of the [`KeySwitchingKey`] serialized with bincode, without
+    /// serializing it to a buffer.
+    ///
+    /// The size is computed by [`bincode::serialized_size`], which walks the key without
+    /// allocating. It covers the [`LweKeyswitchKeyOwned`], the stored [`ServerKey`]s (a shared
+    /// [`ServerKey`] is stored, and therefore counted, once), `cast_rshift` and all the
+    /// parameters and metadata.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let size = ksk.serialized_size_bytes();
+    /// assert_eq!(size, bincode::serialize(&ksk).unwrap().len());
+    /// ```
+    pub fn serialized_size_bytes(&self) -> usize {
+        // Computing the size of keys made of plain data does not fail
+        bincode::serialized_size(self).unwrap() as usize
+    }
+
+    /// Assemble a [`KeySwitchingKey`] from its keys, the deprecated `cast_rshift` field being
+    /// derived from the moduli of `server_keys`.
+    #[allow(deprecated)]
+    pub(crate) fn try_from_server_keys(
+        key_switching_key: LweKeyswitchKeyOwned<u64>,
+        server_keys: CastServerKeys,
+    ) -> Result<Self, KeySwitchError> {
+        let mut ksk = Self {
+            key_switching_key,
+            server_keys,
+            cast_rshift: 0,
+            cast_lookup_table: CastLookupTableCache::default(),
+        };
+
+        let view = ksk.as_view();
+        ksk.cast_rshift = try_cast_rshift_from_moduli(view.src_moduli(), view.dest_moduli())?;
+
+        Ok(ksk)
+    }
+
+    /// Return a [`KeySwitchingKeyView`] borrowing the data of the [`KeySwitchingKey`].
+    pub fn as_view(&self) -> KeySwitchingKeyView<'_> {
+        KeySwitchingKeyView {
+            key_switching_key: &self.key_switching_key,
+            server_keys: self.server_keys.as_ref(),
+            cast_lookup_table: &self.cast_lookup_table,
+        }
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// using provided &mut.
+    ///
+    /// When the source and destination parameter sets use different power of two
+    /// [`CiphertextModulus`](`crate::shortint::parameters::CiphertextModulus`), the ciphertext is
+    /// reduced modulo the smaller destination modulus before the keyswitch, so the message must
+    /// fit in the destination modulus.
+    ///
+    /// `ct_dest` does not need to be a fresh ciphertext: whether the cast only keyswitches or also
+    /// bootstraps on the source or destination side, the mask and body of `ct_dest` are fully
+    /// overwritten, its degree and noise level are recomputed and its message and carry moduli are
+    /// set to the destination ones, so nothing from its previous contents remains in the result.
+    /// Its [`PBSOrder`](`crate::shortint::PBSOrder`) is kept as is and must match the destination
+    /// parameters, as for a ciphertext obtained with `create_trivial` on the destination
+    /// [`ServerKey`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input ciphertext does not have the message and carry moduli of the source
+    /// [`ServerKey`].
+    ///
+    /// # Example (the following code won't actually run because this function is private)
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let cleartext = 1;
+    /// let cipher = ck1.encrypt(cleartext);
+    /// // Any ciphertext with the destination parameters can be used as output, its previous
+    /// // contents are overwritten
+    /// let mut cipher_2 = ck2.encrypt(3);
+    /// ksk.cast_into(&cipher, &mut cipher_2);
+    ///
+    /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    /// ```
+    pub fn cast_into(&self, ct: &Ciphertext, ct_dest: &mut Ciphertext) {
+        self.as_view().cast_into(ct, ct_dest);
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// using provided &mut and a [`CastBuffer`] for the intermediate values.
+    ///
+    /// Reusing the same destination ciphertext and buffer across calls avoids allocating in
+    /// loops.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, CastBuffer, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk_params = ShortintKeySwitchingParameters::new(
+    ///     ck2.parameters.ks_base_log(),
+    ///     ck2.parameters.ks_level(),
+    /// );
+    /// let ksk = KeySwitchingKey::new((&ck1, &sk1), (&ck2, &sk2), ksk_params);
+    ///
+    /// // Allocated once, reused for all the casts
+    /// let mut cipher_2 = sk2.create_trivial(0);
+    /// let mut buffer = CastBuffer::new();
+    ///
+    /// for cleartext in 0..2 {
+    ///     let cipher = ck1.encrypt(cleartext);
+    ///     ksk.cast_into_with_buffer(&cipher, &mut cipher_2, &mut buffer);
+    ///
+    ///     assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    /// }
+    /// ```
+    pub fn cast_into_with_buffer(
+        &self,
+        ct: &Ciphertext,
+        ct_dest: &mut Ciphertext,
+        buffer: &mut CastBuffer,
+    ) {
+        self.as_view().cast_into_with_buffer(ct, ct_dest, buffer);
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// using provided &mut, with the result always coming out of a bootstrap from the
+    /// destination [`ServerKey`].
+    ///
+    /// When the cast only requires a keyswitch or when the bootstrap happens on the source side,
+    /// an identity lookup table is applied with the destination [`ServerKey`] after the
+    /// keyswitch, so the destination ciphertext has a nominal noise level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let cleartext = 1;
+    /// let cipher = ck1.encrypt(cleartext);
+    /// let mut cipher_2 = sk2.create_trivial(0);
+    /// ksk.cast_into_with_bootstrap(&cipher, &mut cipher_2);
+    ///
+    /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    /// ```
+    pub fn cast_into_with_bootstrap(&self, ct: &Ciphertext, ct_dest: &mut Ciphertext) {
+        self.as_view().cast_into_with_bootstrap(ct, ct_dest);
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// using provided &mut, with the carries of the result cleared.
+    ///
+    /// The message extraction is folded in the lookup table of the cast so that a single bootstrap
+    /// is performed. When the source and destination full message moduli are equal, which
+    /// otherwise only requires a keyswitch, a bootstrap with the destination [`ServerKey`] is
+    /// added.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk_params = ShortintKeySwitchingParameters::new(
+    ///     ck2.parameters.ks_base_log(),
+    ///     ck2.parameters.ks_level(),
+    /// );
+    /// let ksk = KeySwitchingKey::new((&ck1, &sk1), (&ck2, &sk2), ksk_params);
+    ///
+    /// // 7 = 0b0111: message 3 with a carry
+    /// let cipher = ck1.unchecked_encrypt(7);
+    /// let mut cipher_2 = sk2.create_trivial(0);
+    /// ksk.cast_into_clean_carries(&cipher, &mut cipher_2);
+    ///
+    /// assert_eq!(cipher_2.degree.get(), 3);
+    /// assert_eq!(ck2.decrypt_message_and_carry(&cipher_2), 3);
+    /// ```
+    pub fn cast_into_clean_carries(&self, ct: &Ciphertext, ct_dest: &mut Ciphertext) {
+        self.as_view().cast_into_clean_carries(ct, ct_dest);
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// using provided &mut, assuming the message of the input fits in the dest full message
+    /// modulus.
+    ///
+    /// When casting to a smaller full message modulus, the source side PBS scaling the message up
+    /// is replaced by a multiplication of the ciphertext by the ratio of the full message moduli
+    /// with [`ServerKey::unchecked_scalar_mul`], followed by the keyswitch. In the other cases
+    /// this is the same as [`KeySwitchingKey::cast_into`].
+    ///
+    /// # Correctness
+    ///
+    /// Nothing is checked on the value of the input: if its carries are dirty, or more generally
+    /// if its message is not smaller than the dest full message modulus, the result is wrong and
+    /// the padding bit of the intermediate ciphertext may be set. The noise of the input is
+    /// multiplied by the ratio of the full message moduli instead of being reset, the
+    /// [`NoiseLevel`] of the output accounts for it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input ciphertext does not have the message and carry moduli of the source
+    /// [`ServerKey`], or when casting to a smaller full message modulus which does not divide the
+    /// source one or with a ratio larger than [`u8::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk_params = ShortintKeySwitchingParameters::new(
+    ///     ck2.parameters.ks_base_log(),
+    ///     ck2.parameters.ks_level(),
+    /// );
+    /// let ksk = KeySwitchingKey::new((&ck1, &sk1), (&ck2, &sk2), ksk_params);
+    ///
+    /// // 3 fits in the 2 bits of the dest full message modulus
+    /// let cleartext = 3;
+    /// let cipher = ck1.encrypt(cleartext);
+    /// let mut cipher_2 = sk2.create_trivial(0);
+    /// ksk.cast_into_assume_clean(&cipher, &mut cipher_2);
+    ///
+    /// assert_eq!(ck2.decrypt_message_and_carry(&cipher_2), cleartext);
+    /// ```
+    pub fn cast_into_assume_clean(&self, ct: &Ciphertext, ct_dest: &mut Ciphertext) {
+        self.as_view().cast_into_assume_clean(ct, ct_dest);
+    }
+
+    /// Cast a slice of ciphertexts from the source parameter set to the dest parameter set,
+    /// using provided &mut slice.
+    ///
+    /// The lookup table required when the bit sizes differ is shared by the whole slice, like for
+    /// all the casts with this key it is only generated on the first one.
+    ///
+    /// The metadata of each output block is computed from its own input block, e.g. the blocks of
+    /// a radix ciphertext keep their respective degrees, see
+    /// [`KeySwitchingKey::cast_blocks_degree_and_noise_level`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input and output slices do not have the same length or if an input
+    /// ciphertext does not have the message and carry moduli of the source [`ServerKey`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let cleartexts = [0, 1];
+    /// let ciphers: Vec<_> = cleartexts.iter().map(|&m| ck1.encrypt(m)).collect();
+    /// let mut ciphers_2: Vec<_> = cleartexts.iter().map(|_| sk2.create_trivial(0)).collect();
+    /// ksk.cast_into_slice(&ciphers, &mut ciphers_2);
+    ///
+    /// for (cipher_2, cleartext) in ciphers_2.iter().zip(cleartexts) {
+    ///     assert_eq!(ck2.decrypt(cipher_2), cleartext);
+    /// }
+    /// ```
+    pub fn cast_into_slice(&self, cts: &[Ciphertext], cts_dest: &mut [Ciphertext]) {
+        assert_eq!(
+            cts.len(),
+            cts_dest.len(),
+            "Mismatch between the number of input ciphertexts ({}) \
+            and the number of output ciphertexts ({})",
+            cts.len(),
+            cts_dest.len(),
+        );
+
+        let view = self.as_view();
+
+        for ct in cts {
+            view.assert_input_moduli(ct);
+        }
+
+        let acc = view.cast_lookup_table();
+        let mut buffer = CastBuffer::new();
+
+        for (ct, ct_dest) in cts.iter().zip(cts_dest.iter_mut()) {
+            view.cast_into_with_lookup_table(ct, ct_dest, acc, &mut buffer);
+        }
+    }
+
+    /// Cast a slice of ciphertexts from the source parameter set to the dest parameter set, then
+    /// pack them in the `dest` [`GlweCiphertext`] with the given [`LwePackingKeyswitchKey`].
+    ///
+    /// The i-th ciphertext ends up encrypted in the i-th coefficient of the body of `dest`, the
+    /// other coefficients encrypt 0, see
+    /// [`keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext`]. The casts are written in a
+    /// single [`LweCiphertextList`] fed to the packing, no [`Ciphertext`] is allocated per block.
+    ///
+    /// `lwe_pksk` must switch from the secret key of the cast outputs, i.e. the large LWE secret
+    /// key of the destination [`ClientKey`] for
+    /// [`PBSOrder::KeyswitchBootstrap`](`crate::shortint::PBSOrder::KeyswitchBootstrap`)
+    /// destination parameters, to the secret key of `dest`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an input ciphertext does not have the message and carry moduli of the source
+    /// [`ServerKey`], if the slice is empty or has more ciphertexts than the
+    /// [`PolynomialSize`](`crate::core_crypto::commons::parameters::PolynomialSize`) of `dest`,
+    /// or if the dimensions of `lwe_pksk` do not match the cast outputs and `dest`.
+    pub fn cast_and_pack_into<KeyCont, OutputCont>(
+        &self,
+        cts: &[Ciphertext],
+        lwe_pksk: &LwePackingKeyswitchKey<KeyCont>,
+        dest: &mut GlweCiphertext<OutputCont>,
+    ) where
+        KeyCont: Container<Element = u64>,
+        OutputCont: ContainerMut<Element = u64>,
+    {
+        assert!(!cts.is_empty(), "Cannot pack an empty slice of ciphertexts");
+
+        let view = self.as_view();
+
+        for ct in cts {
+            view.assert_input_moduli(ct);
+        }
+
+        let acc = view.cast_lookup_table();
+        let mut buffer = CastBuffer::new();
+
+        let mut cast_output = view.new_dest_ciphertext();
+        let mut cast_outputs = LweCiphertextList::new(
+            0u64,
+            cast_output.ct.lwe_size(),
+            LweCiphertextCount(cts.len()),
+            cast_output.ct.ciphertext_modulus(),
+        );
+
+        for (ct, mut lwe) in cts.iter().zip(cast_outputs.iter_mut()) {
+            view.cast_into_with_lookup_table(ct, &mut cast_output, acc, &mut buffer);
+            lwe.as_mut().copy_from_slice(cast_output.ct.as_ref());
+        }
+
+        keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext(lwe_pksk, &cast_outputs, dest);
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// returning a new ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input ciphertext does not have the message and carry moduli of the source
+    /// [`ServerKey`], see [`KeySwitchingKey::try_cast`] for a non panicking version.
+    ///
+    /// # Example (the following code won't actually run because this function is private)
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let cleartext = 1;
+    ///
+    /// let cipher = ck1.encrypt(cleartext);
+    /// let cipher_2 = ksk.cast(&cipher);
+    ///
+    /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    /// ```
+    pub fn cast(&self, ct: &Ciphertext) -> Ciphertext {
+        self.as_view().cast(ct)
+    }
+
+    /// Decompress a [`CompressedCiphertext`] and cast it, returning a new ciphertext under the
+    /// destination parameters.
+    ///
+    /// The decompressed ciphertext only lives for the duration of the call. Decompression
+    /// regenerates the mask from the seed and adds no noise: the output is the same as the one of
+    /// [`KeySwitchingKey::cast`] called on the decompressed ciphertext. When the cast performs a
+    /// PBS the output noise level is [`NoiseLevel::NOMINAL`], otherwise the keyswitch is applied
+    /// to the noise of the fresh encryption, see
+    /// [`KeySwitchingKey::cast_output_degree_and_noise_level`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input ciphertext does not have the message and carry moduli of the source
+    /// [`ServerKey`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let cleartext = 1;
+    /// let compressed_cipher = ck1.encrypt_compressed(cleartext);
+    /// let cipher_2 = ksk.cast_compressed(&compressed_cipher);
+    ///
+    /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    /// ```
+    pub fn cast_compressed(&self, ct: &CompressedCiphertext) -> Ciphertext {
+        // Decompress from the reference, CompressedCiphertext::decompress would require a clone
+        let mut lwe = LweCiphertext::new(0u64, ct.ct.lwe_size(), ct.ct.ciphertext_modulus());
+        decompress_seeded_lwe_ciphertext::<_, _, ActivatedRandomGenerator>(&mut lwe, &ct.ct);
+
+        let decompressed = Ciphertext {
+            ct: lwe,
+            degree: ct.degree,
+            noise_level: ct.noise_level,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+            pbs_order: ct.pbs_order,
+        };
+
+        self.cast(&decompressed)
+    }
+
+    /// Cast an [`LweCiphertext`] from the source parameter set to the dest parameter set, using
+    /// the given keys and accumulator for the bootstrap instead of the [`ServerKey`]s.
+    ///
+    /// This is meant for ciphertexts handled at the core_crypto level, no degree or noise level
+    /// is tracked. The message is shifted by a bootstrap made of a keyswitch with
+    /// `bootstrap_keyswitch_key` followed by a PBS with `fourier_bsk` and `accumulator`, the same
+    /// way a [`ServerKey`] with the [`PBSOrder::KeyswitchBootstrap`] order applies a lookup table:
+    /// - if [`KeySwitchingKey::cast_rshift`] is positive, the bootstrap is applied after the
+    ///   keyswitch, the keys must be the ones of the destination parameters;
+    /// - if it is negative, the bootstrap is applied before the keyswitch, the keys must be the
+    ///   ones of the source parameters;
+    /// - if it is 0 only the keyswitch is applied and the bootstrap arguments are not used.
+    ///
+    /// The accumulator is used as is, it is up to the caller to encode the shift in it, e.g. with
+    /// `x >> cast_rshift` when casting to a bigger full message modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::server_key::ShortintBootstrappingKey;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// // Casting to a bigger full message modulus: shift with the dest keys
+    /// assert_eq!(ksk.cast_rshift(), 2);
+    /// let ShortintBootstrappingKey::Classic(fourier_bsk) = &sk2.bootstrapping_key else {
+    ///     unreachable!()
+    /// };
+    /// let rshift = ksk.cast_rshift();
+    /// let accumulator = sk2.generate_lookup_table(|x| x >> rshift);
+    ///
+    /// let cleartext = 1;
+    /// let cipher = ck1.encrypt(cleartext);
+    /// let mut cipher_2 = sk2.create_trivial(0);
+    /// ksk.cast_lwe_ciphertext(
+    ///     &cipher.ct,
+    ///     &mut cipher_2.ct,
+    ///     &sk2.key_switching_key,
+    ///     fourier_bsk,
+    ///     &accumulator.acc,
+    /// );
+    ///
+    /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    /// ```
+    pub fn cast_lwe_ciphertext<InputCont, OutputCont, BskCont, AccCont>(
+        &self,
+        input: &LweCiphertext<InputCont>,
+        output: &mut LweCiphertext<OutputCont>,
+        bootstrap_keyswitch_key: &LweKeyswitchKeyOwned<u64>,
+        fourier_bsk: &FourierLweBootstrapKey<BskCont>,
+        accumulator: &GlweCiphertext<AccCont>,
+    ) where
+        InputCont: Container<Element = u64>,
+        OutputCont: ContainerMut<Element = u64>,
+        BskCont: Container<Element = c64>,
+        AccCont: Container<Element = u64>,
+    {
+        self.as_view().cast_lwe_ciphertext(
+            input,
+            output,
+            bootstrap_keyswitch_key,
+            fourier_bsk,
+            accumulator,
+        );
+    }
+
+    /// Encrypt `msg` with `ck_src`, cast it and return whether the result decrypts with `ck_dest`
+    /// to the encrypted message.
+    ///
+    /// `msg` is encrypted with [`ClientKey::encrypt`] and is therefore reduced modulo the source
+    /// message modulus, it is compared to the output of [`ClientKey::decrypt`]. This returns
+    /// `false` when the message does not fit in the destination message modulus.
+    ///
+    /// This is meant to check the correctness of a cast in tests or to reproduce a failing cast
+    /// with a single call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ck_src` does not have the source parameters of the [`KeySwitchingKey`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// assert!(ksk.debug_check_cast(&ck1, &ck2, 0));
+    /// assert!(ksk.debug_check_cast(&ck1, &ck2, 1));
+    /// ```
+    pub fn debug_check_cast(&self, ck_src: &ClientKey, ck_dest: &ClientKey, msg: u64) -> bool {
+        let expected = msg % ck_src.parameters.message_modulus().0 as u64;
+
+        let ct = ck_src.encrypt(msg);
+        let ct_dest = self.cast(&ct);
+
+        ck_dest.decrypt(&ct_dest) == expected
+    }
+
+    /// Cast a slice of ciphertexts from the source parameter set to the dest parameter set in
+    /// parallel, returning a new vector of ciphertexts.
+    ///
+    /// The lookup table required when the bit sizes differ is generated once, on the first cast
+    /// with this key, and shared by all the threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an input ciphertext does not have the message and carry moduli of the source
+    /// [`ServerKey`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let cleartexts = [0, 1];
+    /// let ciphers: Vec<_> = cleartexts.iter().map(|&m| ck1.encrypt(m)).collect();
+    /// let ciphers_2 = ksk.par_cast(&ciphers);
+    ///
+    /// for (cipher_2, cleartext) in ciphers_2.iter().zip(cleartexts) {
+    ///     assert_eq!(ck2.decrypt(cipher_2), cleartext);
+    /// }
+    /// ```
+    pub fn par_cast(&self, cts: &[Ciphertext]) -> Vec<Ciphertext> {
+        let view = self.as_view();
+
+        for ct in cts {
+            view.assert_input_moduli(ct);
+        }
+
+        let acc = view.cast_lookup_table();
+
+        cts.par_iter()
+            .map_init(CastBuffer::new, |buffer, ct| {
+                let mut ret = view.new_dest_ciphertext();
+                view.cast_into_with_lookup_table(ct, &mut ret, acc, buffer);
+                ret
+            })
+            .collect()
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// returning a new ciphertext, or an error if the input ciphertext is not compatible with
+    /// the source [`ServerKey`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let cleartext = 1;
+    ///
+    /// let cipher = ck1.encrypt(cleartext);
+    /// let cipher_2 = ksk.try_cast(&cipher).unwrap();
+    /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    ///
+    /// // A ciphertext from the destination parameter set is rejected
+    /// assert!(ksk.try_cast(&cipher_2).is_err());
+    /// ```
+    pub fn try_cast(&self, ct: &Ciphertext) -> Result<Ciphertext, KeySwitchError> {
+        self.as_view().try_cast(ct)
+    }
+
+    /// Check that a ciphertext can be cast, without performing the cast.
+    ///
+    /// This performs the checks of [`KeySwitchingKey::try_cast`] on the LWE dimension and on the
+    /// message and carry moduli of the input ciphertext, but no keyswitch or bootstrap, so it is
+    /// cheap enough to validate every incoming ciphertext before casting it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// assert!(ksk.can_cast(&ck1.encrypt(1)).is_ok());
+    ///
+    /// // A ciphertext from the destination parameter set is rejected
+    /// assert!(ksk.can_cast(&ck2.encrypt(1)).is_err());
+    /// ```
+    pub fn can_cast(&self, ct: &Ciphertext) -> Result<(), KeySwitchError> {
+        self.as_view().can_cast(ct)
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// returning a new ciphertext, or an error if the message could be truncated.
+    ///
+    /// When casting to a smaller full message modulus, the part of the message which does not
+    /// fit in the destination full message modulus is silently dropped by
+    /// [`KeySwitchingKey::cast`]. This function returns an error instead if the degree of the
+    /// input ciphertext shows it may not fit, on top of the checks done by
+    /// [`KeySwitchingKey::try_cast`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     ShortintKeySwitchingParameters::new(
+    ///         PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+    ///         PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+    ///     ),
+    /// );
+    ///
+    /// let cleartext = 1;
+    ///
+    /// // The degree of an unchecked encryption covers the carries
+    /// let cipher = ck1.unchecked_encrypt(cleartext);
+    /// assert!(ksk.checked_cast(&cipher).is_err());
+    ///
+    /// // Once the carries are known to be empty the cast is accepted
+    /// let cipher = sk1.message_extract(&cipher);
+    /// let cipher_2 = ksk.checked_cast(&cipher).unwrap();
+    ///
+    /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    /// ```
+    pub fn checked_cast(&self, ct: &Ciphertext) -> Result<Ciphertext, KeySwitchError> {
+        self.as_view().checked_cast(ct)
+    }
+
+    /// Return the estimated [`Degree`] and [`NoiseLevel`] of the output of a cast of the given
+    /// ciphertext, without performing the cast.
+    ///
+    /// These are the values set on the output of [`KeySwitchingKey::cast_into`] and
+    /// [`KeySwitchingKey::cast`]. When the source and dest full message moduli are equal the
+    /// cast is only a keyswitch and the input noise level is kept, the caller may then decide to
+    /// bootstrap the output, e.g. with [`KeySwitchingKey::cast_into_with_bootstrap`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let cipher = ck1.encrypt(1);
+    /// let (degree, noise_level) = ksk.cast_output_degree_and_noise_level(&cipher);
+    ///
+    /// let cipher_2 = ksk.cast(&cipher);
+    /// assert_eq!(cipher_2.degree, degree);
+    /// assert_eq!(cipher_2.noise_level(), noise_level);
+    /// ```
+    pub fn cast_output_degree_and_noise_level(&self, ct: &Ciphertext) -> (Degree, NoiseLevel) {
+        self.as_view().cast_output_degree_and_noise_level(ct)
+    }
+
+    /// Return the [`Degree`] and [`NoiseLevel`] of each block after a cast, given the [`Degree`]
+    /// and [`NoiseLevel`] of the source blocks, e.g. the blocks of a radix ciphertext.
+    ///
+    /// This only needs the metadata of the blocks, so integer operations can plan where to
+    /// bootstrap before casting. The output matches the values set by [`KeySwitchingKey::cast`]
+    /// on each block. With `full_src` and `full_dest` the full message moduli (message modulus
+    /// times carry modulus) of the source and destination parameters, a block `(degree,
+    /// noise_level)` becomes:
+    ///
+    /// - `full_src == full_dest`: `(degree, noise_level)`, the cast is a keyswitch only;
+    /// - `full_src < full_dest`: `(degree, NoiseLevel::NOMINAL)`, the destination PBS
+    ///   rescaling the message resets the noise;
+    /// - `full_src > full_dest`: `(min(degree, full_dest - 1), NoiseLevel::NOMINAL)`, the source
+    ///   PBS reduces the message modulo `full_dest` and resets the noise before the keyswitch.
+    ///
+    /// [`NoiseLevel`] counts multiples of the nominal noise of a PBS output, the additive noise of
+    /// a keyswitch has no unit in it and is covered by the parameters, as for the keyswitch
+    /// starting every KS-PBS. In the keyswitch only case the output therefore carries one more
+    /// keyswitch noise than its [`NoiseLevel`] indicates, before the keyswitch of its next PBS. If
+    /// no such margin is acceptable, use [`KeySwitchingKey::cast_into_with_bootstrap`] which
+    /// always returns blocks with a [`NoiseLevel::NOMINAL`] noise level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::NoiseLevel;
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// // Blocks of a radix ciphertext, the second one resulting from an addition
+    /// let blocks = [ck1.encrypt(1), sk1.unchecked_add(&ck1.encrypt(1), &ck1.encrypt(0))];
+    /// assert_eq!(blocks[1].noise_level(), NoiseLevel::NOMINAL * 2);
+    ///
+    /// let metadata: Vec<_> = blocks
+    ///     .iter()
+    ///     .map(|block| (block.degree, block.noise_level()))
+    ///     .collect();
+    /// let cast_metadata = ksk.cast_blocks_degree_and_noise_level(&metadata);
+    ///
+    /// // The destination PBS resets the noise of every block
+    /// for (block, (degree, noise_level)) in blocks.iter().zip(cast_metadata) {
+    ///     let cast_block = ksk.cast(block);
+    ///     assert_eq!(cast_block.degree, degree);
+    ///     assert_eq!(cast_block.noise_level(), noise_level);
+    ///     assert_eq!(noise_level, NoiseLevel::NOMINAL);
+    /// }
+    /// ```
+    pub fn cast_blocks_degree_and_noise_level(
+        &self,
+        blocks: &[(Degree, NoiseLevel)],
+    ) -> Vec<(Degree, NoiseLevel)> {
+        let view = self.as_view();
+
+        blocks
+            .iter()
+            .map(|&(degree, noise_level)| view.cast_degree_and_noise_level(degree, noise_level))
+            .collect()
+    }
+
+    /// Return the [`CastCost`] of [`Self::cast_into`], i.e. whether casting a ciphertext performs
+    /// a programmable bootstrap and on which side of the keyswitch.
+    ///
+    /// For power of two parameters this follows the sign of `cast_rshift`: no bootstrap when it is
+    /// 0, a source side bootstrap when it is negative and a destination side bootstrap when it is
+    /// positive. Otherwise the full message moduli are compared directly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, CastCost, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// // The message is rescaled after the keyswitch
+    /// assert_eq!(ksk.cast_cost(), CastCost::DestPbs);
+    /// assert_eq!(ksk.cast_cost().pbs_count(), 1);
+    /// ```
+    pub fn cast_cost(&self) -> CastCost {
+        self.as_view().cast_cost()
+    }
+
+    /// Return the lookup table applied by [`KeySwitchingKey::cast_into`] to rescale the message,
+    /// or [`None`] if the cast only requires a keyswitch.
+    ///
+    /// The lookup table is generated on the first call or cast and then reused. As for
+    /// [`KeySwitchingKey::cast_cost`], for power of two parameters the branch follows the sign of
+    /// `cast_rshift`:
+    /// - when it is positive, the lookup table is applied with the destination [`ServerKey`] after
+    ///   the keyswitch and decodes each coefficient to the closest source message, with the
+    ///   decision boundaries at the exact midpoints between the source encodings;
+    /// - when it is negative, the lookup table is applied with the source [`ServerKey`] before the
+    ///   keyswitch and maps `n` to `(n % dest_full_message_modulus) * 2^-cast_rshift`;
+    /// - when it is 0, [`None`] is returned.
+    ///
+    /// The same holds for full message moduli which are not powers of two, the output is then
+    /// encoded with the exact destination delta, see [`KeySwitchingKey::cast_rshift`] for the
+    /// sign.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// // The message is rescaled after the keyswitch, with the destination ServerKey, and stays
+    /// // below the source full message modulus
+    /// assert_eq!(ksk.cast_rshift(), 2);
+    /// let lookup_table = ksk.cast_lookup_table().unwrap();
+    /// assert_eq!(lookup_table.degree.get(), 3);
+    /// ```
+    pub fn cast_lookup_table(&self) -> Option<&LookupTableOwned> {
+        self.as_view().cast_lookup_table()
+    }
+
+    /// Return the full message modulus (message modulus times carry modulus) of the source
+    /// parameters.
+    ///
+    /// Together with [`KeySwitchingKey::dest_full_message_modulus`] this is what drives the cast:
+    /// [`KeySwitchingKey::cast_rshift`] is derived from both moduli and only kept for information,
+    /// it is rounded up for moduli which are not a power of 2 and does not have to be a small
+    /// shift.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the full message modulus does not fit in a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// assert_eq!(ksk.src_full_message_modulus(), 4);
+    /// assert_eq!(ksk.dest_full_message_modulus(), 16);
+    /// assert_eq!(
+    ///     ksk.cast_rshift() as u32,
+    ///     ksk.dest_full_message_modulus().ilog2() - ksk.src_full_message_modulus().ilog2()
+    /// );
+    /// ```
+    pub fn src_full_message_modulus(&self) -> u64 {
+        self.as_view().src_full_message_modulus()
+    }
+
+    /// Return the full message modulus (message modulus times carry modulus) of the destination
+    /// parameters.
+    ///
+    /// See [`KeySwitchingKey::src_full_message_modulus`].
+    pub fn dest_full_message_modulus(&self) -> u64 {
+        self.as_view().dest_full_message_modulus()
+    }
+
+    /// Return the difference between the number of bits of the destination and source full
+    /// message moduli, rounded up for moduli which are not a power of 2.
+    ///
+    /// This is derived from [`KeySwitchingKey::src_full_message_modulus`] and
+    /// [`KeySwitchingKey::dest_full_message_modulus`], so it cannot get out of sync with the keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a full message modulus does not fit in a `u64`.
+    pub fn cast_rshift(&self) -> i8 {
+        self.as_view().cast_rshift()
+    }
+}
+
+/// A structure containing the seeded casting public key.
+///
+/// Only the keyswitching key is compressed: its mask is stored as a seed, which makes it much
+/// smaller to send to the server. The two [`ServerKey`]s are stored in full and make up most of
+/// the size of this key. It has to be decompressed into a [`KeySwitchingKey`] to be used.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+// The fields are named after the keys they hold, like the raw parts of the key
+#[allow(clippy::struct_field_names)]
+pub struct SeededKeySwitchingKey {
+    pub(crate) key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
+    pub(crate) dest_server_key: ServerKey,
+    pub(crate) src_server_key: ServerKey,
+}
+
+impl SeededKeySwitchingKey {
+    /// Generate a seeded casting key. This can cast to several kinds of keys (shortint, integer,
+    /// hlapi), depending on input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, SeededKeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the seeded server key:
+    /// let seeded_ksk = SeededKeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let ksk = seeded_ksk.decompress();
+    ///
+    /// let cleartext = 1;
+    ///
+    /// let cipher = ck1.encrypt(cleartext);
+    /// let cipher_2 = ksk.cast(&cipher);
+    ///
+    /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    /// ```
+    pub fn new(
+        key_pair_1: (&ClientKey, &ServerKey),
+        key_pair_2: (&ClientKey, &ServerKey),
+        params: ShortintKeySwitchingParameters,
+    ) -> Self {
+        assert_full_message_moduli_fit(key_pair_1.0, key_pair_2.0);
+        assert_cast_ciphertext_moduli_compatible(
+            key_pair_1.0.parameters.ciphertext_modulus(),
+            key_pair_2.0.parameters.ciphertext_modulus(),
+        );
+
+        // Creation of the seeded key switching key
+        let key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_seeded_key_switching_key(key_pair_1.0, key_pair_2.0, params)
+        });
+
+        // Pack the keys in the casting key set:
+        Self {
+            key_switching_key,
+            dest_server_key: key_pair_2.1.clone(),
+            src_server_key: key_pair_1.1.clone(),
+        }
+    }
+
+    /// Return the difference between the number of bits of the destination and source full
+    /// message moduli, see [`KeySwitchingKey::cast_rshift`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a full message modulus does not fit in a `u64`.
+    pub fn cast_rshift(&self) -> i8 {
+        server_keys_cast_rshift(&self.src_server_key, &self.dest_server_key)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Deconstruct a [`SeededKeySwitchingKey`] into its constituents.
+    pub fn into_raw_parts(self) -> (SeededLweKeyswitchKeyOwned<u64>, ServerKey, ServerKey, i8) {
+        let cast_rshift = self.cast_rshift();
+
+        let Self {
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+        } = self;
+
+        (
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+            cast_rshift,
+        )
+    }
+
+    /// Construct a [`SeededKeySwitchingKey`] from its constituents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided raw parts are not compatible with each other, see
+    /// [`KeySwitchingKey::from_raw_parts`].
+    pub fn from_raw_parts(
+        key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
+        dest_server_key: ServerKey,
+        src_server_key: ServerKey,
+        cast_rshift: i8,
+    ) -> Self {
+        let src_lwe_dimension = src_server_key.ciphertext_lwe_dimension();
+        let dst_lwe_dimension = dest_server_key.ciphertext_lwe_dimension();
+
+        assert_eq!(
+            src_lwe_dimension,
+            key_switching_key.input_key_lwe_dimension(),
+            "Mismatch between the source ServerKey ciphertext LweDimension ({:?}) \
+            and the SeededLweKeyswitchKey input LweDimension ({:?})",
+            src_lwe_dimension,
+            key_switching_key.input_key_lwe_dimension(),
+        );
+        assert_eq!(
+            dst_lwe_dimension,
+            key_switching_key.output_key_lwe_dimension(),
+            "Mismatch between the destination ServerKey ciphertext LweDimension ({:?}) \
+            and the SeededLweKeyswitchKey output LweDimension ({:?})",
+            dst_lwe_dimension,
+            key_switching_key.output_key_lwe_dimension(),
+        );
+        assert_cast_ciphertext_moduli_compatible(
+            src_server_key.ciphertext_modulus,
+            dest_server_key.ciphertext_modulus,
+        );
+        assert_eq!(
+            key_switching_key.ciphertext_modulus(),
+            dest_server_key.ciphertext_modulus,
+            "Mismatch between the SeededLweKeyswitchKey CiphertextModulus ({:?}) \
+            and the destination ServerKey CiphertextModulus ({:?})",
+            key_switching_key.ciphertext_modulus(),
+            dest_server_key.ciphertext_modulus,
+        );
+        check_cast_rshift(cast_rshift, &src_server_key, &dest_server_key)
+            .unwrap_or_else(|err| panic!("{err}"));
+
+        Self {
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+        }
+    }
+
+    /// Serialize the [`SeededKeySwitchingKey`] into `writer`.
+    ///
+    /// The key is written incrementally, no intermediate serialization buffer is allocated, which
+    /// keeps the memory footprint low when shipping many casting keys. The output is the same as
+    /// the one produced by `bincode::serialize` and can be read back with
+    /// [`SeededKeySwitchingKey::read_compressed`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, SeededKeySwitchingKey};
+    ///
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let seeded_ksk = SeededKeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let mut buffer = vec![];
+    /// seeded_ksk.write_compressed(&mut buffer).unwrap();
+    ///
+    /// let read_ksk = SeededKeySwitchingKey::read_compressed(buffer.as_slice()).unwrap();
+    /// assert_eq!(read_ksk, seeded_ksk);
+    ///
+    /// let ksk = read_ksk.decompress();
+    ///
+    /// let cipher = ck1.encrypt(1);
+    /// assert_eq!(ck2.decrypt(&ksk.cast(&cipher)), 1);
+    /// ```
+    pub fn write_compressed<W: std::io::Write>(&self, writer: W) -> bincode::Result<()> {
+        bincode::serialize_into(writer, self)
+    }
+
+    /// Deserialize a [`SeededKeySwitchingKey`] written by
+    /// [`SeededKeySwitchingKey::write_compressed`] from `reader`.
+    pub fn read_compressed<R: std::io::Read>(reader: R) -> bincode::Result<Self> {
+        bincode::deserialize_from(reader)
+    }
+
+    /// Decompress the [`SeededKeySwitchingKey`] into a [`KeySwitchingKey`].
+    pub fn decompress(self) -> KeySwitchingKey {
+        let Self {
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+        } = self;
+
+        KeySwitchingKey::try_from_server_keys(
+            key_switching_key.par_decompress_into_lwe_keyswitch_key(),
+            CastServerKeys::Distinct {
+                src: Arc::new(src_server_key),
+                dest: Arc::new(dest_server_key),
+            },
+        )
+        .unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl From<SeededKeySwitchingKey> for KeySwitchingKey {
+    fn from(value: SeededKeySwitchingKey) -> Self {
+        value.decompress()
+    }
+}
+
+/// A view of a [`KeySwitchingKey`] borrowing the keys it is made of.
+///
+/// The view is cheap to copy and can be handed out to worker threads instead of cloning the
+/// [`KeySwitchingKey`] and the two [`ServerKey`]s it owns. It is obtained with
+/// [`KeySwitchingKey::as_view`], or built with [`KeySwitchingKeyView::new`] from the keys
+/// themselves when they are not owned by a [`KeySwitchingKey`].
+#[derive(Clone, Copy, Debug)]
+pub struct KeySwitchingKeyView<'keys> {
+    pub(crate) key_switching_key: &'keys LweKeyswitchKeyOwned<u64>,
+    pub(crate) server_keys: CastServerKeysRef<'keys>,
+    pub(crate) cast_lookup_table: &'keys CastLookupTableCache,
+}
+
+impl<'keys> KeySwitchingKeyView<'keys> {
+    /// Build a view casting with `key_switching_key` from the parameters of `src_server_key` to
+    /// the parameters of `dest_server_key`, or of `src_server_key` if the source and destination
+    /// share it.
+    ///
+    /// The keys are checked like [`KeySwitchingKey::validate_raw_parts`], the full message moduli
+    /// of the [`ServerKey`]s must also fit in a u64, see [`KeySwitchingKey::cast_rshift`]. The
+    /// lookup table of the cast is generated on the first cast needing it and stored in
+    /// `cast_lookup_table`, only views of the same [`ServerKey`]s may share it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::key_switching_key::CastLookupTableCache;
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey, KeySwitchingKeyView};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Only keep the raw keys:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    /// let (raw_ksk, dest_sk, src_sk, _) = ksk.into_raw_parts();
+    ///
+    /// let cache = CastLookupTableCache::new();
+    /// let ksk_view = KeySwitchingKeyView::new(&raw_ksk, &src_sk, Some(&dest_sk), &cache).unwrap();
+    ///
+    /// let cleartext = 1;
+    /// let cipher = ck1.encrypt(cleartext);
+    /// let cipher_2 = ksk_view.cast(&cipher);
+    ///
+    /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    /// ```
+    pub fn new(
+        key_switching_key: &'keys LweKeyswitchKeyOwned<u64>,
+        src_server_key: &'keys ServerKey,
+        dest_server_key: Option<&'keys ServerKey>,
+        cast_lookup_table: &'keys CastLookupTableCache,
+    ) -> Result<Self, KeySwitchError> {
+        let server_keys = dest_server_key.map_or(
+            CastServerKeysRef::Shared(src_server_key),
+            |dest_server_key| CastServerKeysRef::Distinct {
+                src: src_server_key,
+                dest: dest_server_key,
+            },
+        );
+        let dest_server_key = dest_server_key.unwrap_or(src_server_key);
+
+        KeySwitchingKey::validate_raw_parts(key_switching_key, dest_server_key, src_server_key)?;
+        server_keys_cast_rshift(src_server_key, dest_server_key)?;
+
+        Ok(Self {
+            key_switching_key,
+            server_keys,
+            cast_lookup_table,
+        })
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// using provided &mut.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let ksk_view = ksk.as_view();
+    ///
+    /// let cleartext = 1;
+    /// let cipher = ck1.encrypt(cleartext);
+    /// let mut cipher_2 = sk2.create_trivial(0);
+    /// ksk_view.cast_into(&cipher, &mut cipher_2);
+    ///
+    /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    /// ```
+    pub fn cast_into(&self, ct: &Ciphertext, ct_dest: &mut Ciphertext) {
+        self.cast_into_with_buffer(ct, ct_dest, &mut CastBuffer::new());
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// using provided &mut and a [`CastBuffer`] for the intermediate values.
+    ///
+    /// See [`KeySwitchingKey::cast_into_with_buffer`].
+    pub fn cast_into_with_buffer(
+        &self,
+        ct: &Ciphertext,
+        ct_dest: &mut Ciphertext,
+        buffer: &mut CastBuffer,
+    ) {
+        self.assert_input_moduli(ct);
+
+        let acc = self.cast_lookup_table();
+        self.cast_into_with_lookup_table(ct, ct_dest, acc, buffer);
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// using provided &mut, with the result always coming out of a bootstrap from the
+    /// destination [`ServerKey`].
+    ///
+    /// See [`KeySwitchingKey::cast_into_with_bootstrap`].
+    pub fn cast_into_with_bootstrap(&self, ct: &Ciphertext, ct_dest: &mut Ciphertext) {
+        self.cast_into(ct, ct_dest);
+
+        // The bootstrap already happened with the dest server key when casting to a bigger full
+        // message modulus
+        if self.src_full_message_modulus() >= self.dest_full_message_modulus() {
+            let dest_server_key = self.dest_server_key();
+            let identity = dest_server_key.generate_lookup_table(|x| x);
+            dest_server_key.apply_lookup_table_assign(ct_dest, &identity);
+        }
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// using provided &mut, with the carries of the result cleared.
+    ///
+    /// See [`KeySwitchingKey::cast_into_clean_carries`].
+    pub fn cast_into_clean_carries(&self, ct: &Ciphertext, ct_dest: &mut Ciphertext) {
+        self.assert_input_moduli(ct);
+
+        let acc = self.generate_clean_carries_cast_lookup_table();
+        self.cast_into_with_lookup_table(ct, ct_dest, Some(&acc), &mut CastBuffer::new());
+
+        let (dest_message_modulus, _) = self.dest_moduli();
+        self.set_dest_metadata(
+            ct_dest,
+            Degree::new(ct.degree.get().min(dest_message_modulus.0 - 1)),
+            NoiseLevel::NOMINAL,
+        );
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// using provided &mut, assuming the message of the input fits in the dest full message
+    /// modulus.
+    ///
+    /// See [`KeySwitchingKey::cast_into_assume_clean`].
+    pub fn cast_into_assume_clean(&self, ct: &Ciphertext, ct_dest: &mut Ciphertext) {
+        let src_full_message_modulus = self.src_full_message_modulus();
+        let dest_full_message_modulus = self.dest_full_message_modulus();
+
+        // Without a source side PBS there is nothing to skip
+        if src_full_message_modulus <= dest_full_message_modulus {
+            self.cast_into(ct, ct_dest);
+            return;
+        }
+
+        self.assert_input_moduli(ct);
+
+        assert_eq!(
+            src_full_message_modulus % dest_full_message_modulus,
+            0,
+            "The dest full message modulus ({dest_full_message_modulus}) must divide the source \
+            full message modulus ({src_full_message_modulus}) to scale the message with a \
+            multiplication",
+        );
+        let scale = u8::try_from(src_full_message_modulus / dest_full_message_modulus)
+            .unwrap_or_else(|_| {
+                panic!(
+                    "The ratio between the source full message modulus \
+                    ({src_full_message_modulus}) and the dest full message modulus \
+                    ({dest_full_message_modulus}) does not fit in a u8"
+                )
+            });
+
+        let scaled_cipher = self.src_server_key().unchecked_scalar_mul(ct, scale);
+
+        self.keyswitch_to_dest(&scaled_cipher.ct, &mut ct_dest.ct);
+
+        self.set_dest_metadata(
+            ct_dest,
+            Degree::new(ct.degree.get().min(dest_full_message_modulus as usize - 1)),
+            scaled_cipher.noise_level(),
+        );
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// returning a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+    ///
+    /// // Generate the client keys and server keys:
+    /// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key:
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let ksk_view = ksk.as_view();
+    ///
+    /// let cleartext = 1;
+    ///
+    /// let cipher = ck1.encrypt(cleartext);
+    /// let cipher_2 = ksk_view.cast(&cipher);
+    ///
+    /// assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+    /// ```
+    pub fn cast(&self, ct: &Ciphertext) -> Ciphertext {
+        let mut ret = self.new_dest_ciphertext();
         self.cast_into(ct, &mut ret);
         ret
     }
+
+    /// Cast an [`LweCiphertext`] from the source parameter set to the dest parameter set, using
+    /// the given keys and accumulator for the bootstrap instead of the [`ServerKey`]s.
+    ///
+    /// See [`KeySwitchingKey::cast_lwe_ciphertext`].
+    pub fn cast_lwe_ciphertext<InputCont, OutputCont, BskCont, AccCont>(
+        &self,
+        input: &LweCiphertext<InputCont>,
+        output: &mut LweCiphertext<OutputCont>,
+        bootstrap_keyswitch_key: &LweKeyswitchKeyOwned<u64>,
+        fourier_bsk: &FourierLweBootstrapKey<BskCont>,
+        accumulator: &GlweCiphertext<AccCont>,
+    ) where
+        InputCont: Container<Element = u64>,
+        OutputCont: ContainerMut<Element = u64>,
+        BskCont: Container<Element = c64>,
+        AccCont: Container<Element = u64>,
+    {
+        match self
+            .src_full_message_modulus()
+            .cmp(&self.dest_full_message_modulus())
+        {
+            Ordering::Equal => {
+                self.keyswitch_to_dest(input, output);
+            }
+            // Cast to bigger full message modulus: keyswitch, then shift
+            Ordering::Less => {
+                let mut casted = LweCiphertext::new(
+                    0u64,
+                    self.key_switching_key.output_lwe_size(),
+                    self.key_switching_key.ciphertext_modulus(),
+                );
+                self.keyswitch_to_dest(input, &mut casted);
+
+                keyswitch_programmable_bootstrap_lwe_ciphertext(
+                    &casted,
+                    output,
+                    bootstrap_keyswitch_key,
+                    fourier_bsk,
+                    accumulator,
+                );
+            }
+            // Cast to smaller full message modulus: shift, then keyswitch
+            Ordering::Greater => {
+                let mut shifted = LweCiphertext::new(
+                    0u64,
+                    fourier_bsk.output_lwe_dimension().to_lwe_size(),
+                    input.ciphertext_modulus(),
+                );
+                keyswitch_programmable_bootstrap_lwe_ciphertext(
+                    input,
+                    &mut shifted,
+                    bootstrap_keyswitch_key,
+                    fourier_bsk,
+                    accumulator,
+                );
+
+                self.keyswitch_to_dest(&shifted, output);
+            }
+        }
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// returning a new ciphertext, or an error if the input ciphertext is not compatible with
+    /// the source [`ServerKey`].
+    pub fn try_cast(&self, ct: &Ciphertext) -> Result<Ciphertext, KeySwitchError> {
+        self.can_cast(ct)?;
+        Ok(self.cast(ct))
+    }
+
+    /// Check that a ciphertext can be cast, without performing the cast.
+    ///
+    /// See [`KeySwitchingKey::can_cast`].
+    pub fn can_cast(&self, ct: &Ciphertext) -> Result<(), KeySwitchError> {
+        self.check_input_ciphertext(ct)
+    }
+
+    /// Cast a ciphertext from the source parameter set to the dest parameter set,
+    /// returning a new ciphertext, or an error if the message could be truncated.
+    ///
+    /// See [`KeySwitchingKey::checked_cast`].
+    pub fn checked_cast(&self, ct: &Ciphertext) -> Result<Ciphertext, KeySwitchError> {
+        self.check_input_ciphertext(ct)?;
+
+        let dest_full_message_modulus = self.dest_full_message_modulus() as usize;
+        if ct.degree.get() >= dest_full_message_modulus {
+            return Err(KeySwitchError::MessageTruncation {
+                degree: ct.degree,
+                dest_full_message_modulus,
+            });
+        }
+
+        Ok(self.cast(ct))
+    }
+
+    /// Return the estimated [`Degree`] and [`NoiseLevel`] of the output of a cast of the given
+    /// ciphertext, without performing the cast.
+    ///
+    /// See [`KeySwitchingKey::cast_output_degree_and_noise_level`].
+    pub fn cast_output_degree_and_noise_level(&self, ct: &Ciphertext) -> (Degree, NoiseLevel) {
+        self.cast_degree_and_noise_level(ct.degree, ct.noise_level())
+    }
+
+    /// Return the estimated [`Degree`] and [`NoiseLevel`] of the output of a cast of a block with
+    /// the given [`Degree`] and [`NoiseLevel`].
+    ///
+    /// See [`KeySwitchingKey::cast_blocks_degree_and_noise_level`].
+    pub fn cast_degree_and_noise_level(
+        &self,
+        degree: Degree,
+        noise_level: NoiseLevel,
+    ) -> (Degree, NoiseLevel) {
+        let dest_full_message_modulus = self.dest_full_message_modulus() as usize;
+
+        match self
+            .src_full_message_modulus()
+            .cmp(&self.dest_full_message_modulus())
+        {
+            // Only a keyswitch, the message and the noise level are kept
+            Ordering::Equal => (degree, noise_level),
+            // The dest lookup table recovers the message after the keyswitch
+            Ordering::Less => (degree, NoiseLevel::NOMINAL),
+            // The src lookup table reduces the message modulo the dest full message modulus
+            Ordering::Greater => (
+                Degree::new(degree.get().min(dest_full_message_modulus - 1)),
+                NoiseLevel::NOMINAL,
+            ),
+        }
+    }
+
+    /// Return the [`CastCost`] of [`Self::cast_into`].
+    ///
+    /// See [`KeySwitchingKey::cast_cost`].
+    pub fn cast_cost(&self) -> CastCost {
+        // Must stay in sync with the dispatch in cast_into_with_lookup_table
+        match self
+            .src_full_message_modulus()
+            .cmp(&self.dest_full_message_modulus())
+        {
+            Ordering::Equal => CastCost::KeyswitchOnly,
+            Ordering::Less => CastCost::DestPbs,
+            Ordering::Greater => CastCost::SrcPbs,
+        }
+    }
+
+    /// Return the lookup table rescaling the message when the source and dest full message moduli
+    /// differ, generating it on the first call.
+    ///
+    /// See [`KeySwitchingKey::cast_lookup_table`].
+    pub fn cast_lookup_table(&self) -> Option<&'keys LookupTableOwned> {
+        let cache: &'keys CastLookupTableCache = self.cast_lookup_table;
+
+        cache
+            .0
+            .get_or_init(|| self.generate_cast_lookup_table())
+            .as_ref()
+    }
+
+    pub(crate) fn src_server_key(&self) -> &'keys ServerKey {
+        self.server_keys.src().expect(MISSING_SERVER_KEY_MSG)
+    }
+
+    pub(crate) fn dest_server_key(&self) -> &'keys ServerKey {
+        match self.server_keys {
+            CastServerKeysRef::SrcOnly { .. } => panic!("{MISSING_DEST_SERVER_KEY_MSG}"),
+            _ => self.server_keys.dest().expect(MISSING_SERVER_KEY_MSG),
+        }
+    }
+
+    pub(crate) fn src_moduli(&self) -> (MessageModulus, CarryModulus) {
+        if let CastServerKeysRef::KeyswitchOnly {
+            message_modulus,
+            carry_modulus,
+        } = self.server_keys
+        {
+            (message_modulus, carry_modulus)
+        } else {
+            let src_server_key = self.src_server_key();
+            (src_server_key.message_modulus, src_server_key.carry_modulus)
+        }
+    }
+
+    pub(crate) fn dest_moduli(&self) -> (MessageModulus, CarryModulus) {
+        match self.server_keys {
+            CastServerKeysRef::KeyswitchOnly {
+                message_modulus,
+                carry_modulus,
+            } => (message_modulus, carry_modulus),
+            CastServerKeysRef::SrcOnly {
+                dest_message_modulus,
+                dest_carry_modulus,
+                ..
+            } => (dest_message_modulus, dest_carry_modulus),
+            _ => {
+                let dest_server_key = self.dest_server_key();
+                (
+                    dest_server_key.message_modulus,
+                    dest_server_key.carry_modulus,
+                )
+            }
+        }
+    }
+
+    /// Return the full message modulus (message modulus times carry modulus) of the source
+    /// parameters.
+    ///
+    /// See [`KeySwitchingKey::src_full_message_modulus`].
+    pub fn src_full_message_modulus(&self) -> u64 {
+        let (message_modulus, carry_modulus) = self.src_moduli();
+        full_message_modulus(message_modulus, carry_modulus)
+    }
+
+    /// Return the full message modulus (message modulus times carry modulus) of the destination
+    /// parameters.
+    ///
+    /// See [`KeySwitchingKey::dest_full_message_modulus`].
+    pub fn dest_full_message_modulus(&self) -> u64 {
+        let (message_modulus, carry_modulus) = self.dest_moduli();
+        full_message_modulus(message_modulus, carry_modulus)
+    }
+
+    /// Return the difference between the number of bits of the destination and source full
+    /// message moduli.
+    ///
+    /// See [`KeySwitchingKey::cast_rshift`].
+    pub fn cast_rshift(&self) -> i8 {
+        full_message_modulus_nb_bits(self.dest_full_message_modulus())
+            - full_message_modulus_nb_bits(self.src_full_message_modulus())
+    }
+
+    fn src_ciphertext_lwe_dimension(&self) -> LweDimension {
+        self.server_keys.src().map_or_else(
+            || self.key_switching_key.input_key_lwe_dimension(),
+            ServerKey::ciphertext_lwe_dimension,
+        )
+    }
+
+    /// Allocate a trivial zero ciphertext with the destination parameters.
+    fn new_dest_ciphertext(&self) -> Ciphertext {
+        self.server_keys.dest().map_or_else(
+            || {
+                let (message_modulus, carry_modulus) = self.dest_moduli();
+
+                Ciphertext::new(
+                    LweCiphertext::new(
+                        0u64,
+                        self.key_switching_key.output_lwe_size(),
+                        self.key_switching_key.ciphertext_modulus(),
+                    ),
+                    Degree::new(0),
+                    NoiseLevel::ZERO,
+                    message_modulus,
+                    carry_modulus,
+                    // The keyswitch outputs ciphertexts under the large key
+                    PBSOrder::KeyswitchBootstrap,
+                )
+            },
+            |dest_server_key| dest_server_key.create_trivial(0),
+        )
+    }
+
+    /// Generate the lookup table rescaling the message when the source and dest full message
+    /// moduli differ.
+    ///
+    /// Returns [`None`] if the cast only requires a keyswitch.
+    fn generate_cast_lookup_table(&self) -> Option<LookupTableOwned> {
+        let src_full_message_modulus = self.src_full_message_modulus();
+        let dest_full_message_modulus = self.dest_full_message_modulus();
+
+        match src_full_message_modulus.cmp(&dest_full_message_modulus) {
+            Ordering::Equal => None,
+            // Scale down applied with the dest server key after the keyswitch
+            Ordering::Less => Some(generate_cast_accumulator(
+                self.dest_server_key(),
+                src_full_message_modulus,
+                dest_full_message_modulus,
+                |msg| msg,
+            )),
+            // Scale up applied with the src server key before the keyswitch, the message is
+            // reduced first to avoid the padding bit to be dirty
+            Ordering::Greater => Some(generate_cast_accumulator(
+                self.src_server_key(),
+                src_full_message_modulus,
+                dest_full_message_modulus,
+                |msg| msg % dest_full_message_modulus,
+            )),
+        }
+    }
+
+    /// Generate the lookup table of [`KeySwitchingKeyView::generate_cast_lookup_table`] combined
+    /// with the extraction of the destination message.
+    ///
+    /// With equal full message moduli the lookup table is applied with the dest server key after
+    /// the keyswitch.
+    fn generate_clean_carries_cast_lookup_table(&self) -> LookupTableOwned {
+        let src_full_message_modulus = self.src_full_message_modulus();
+        let dest_full_message_modulus = self.dest_full_message_modulus();
+        let dest_message_modulus = self.dest_moduli().0 .0 as u64;
+
+        match src_full_message_modulus.cmp(&dest_full_message_modulus) {
+            Ordering::Equal => self
+                .dest_server_key()
+                .generate_lookup_table(|n| n % dest_message_modulus),
+            Ordering::Less => generate_cast_accumulator(
+                self.dest_server_key(),
+                src_full_message_modulus,
+                dest_full_message_modulus,
+                |msg| msg % dest_message_modulus,
+            ),
+            // The dest message modulus divides the dest full message modulus, reducing by the
+            // former also avoids the padding bit to be dirty
+            Ordering::Greater => generate_cast_accumulator(
+                self.src_server_key(),
+                src_full_message_modulus,
+                dest_full_message_modulus,
+                |msg| msg % dest_message_modulus,
+            ),
+        }
+    }
+
+    fn cast_into_with_lookup_table(
+        &self,
+        ct: &Ciphertext,
+        ct_dest: &mut Ciphertext,
+        acc: Option<&LookupTableOwned>,
+        buffer: &mut CastBuffer,
+    ) {
+        match (
+            self.src_full_message_modulus()
+                .cmp(&self.dest_full_message_modulus()),
+            acc,
+        ) {
+            // Same full message modulus: only key switch
+            (Ordering::Equal, None) => {
+                self.keyswitch_to_dest(&ct.ct, &mut ct_dest.ct);
+            }
+
+            // Cast to bigger full message modulus: keyswitch, then scale down, a lookup table with
+            // the same full message modulus is applied the same way
+            (Ordering::Equal | Ordering::Less, Some(acc)) => {
+                self.keyswitch_to_dest(&ct.ct, &mut ct_dest.ct);
+
+                self.dest_server_key()
+                    .apply_lookup_table_assign(ct_dest, acc);
+            }
+
+            // Cast to smaller full message modulus: scale up, then keyswitch
+            (Ordering::Greater, Some(acc)) => {
+                let shifted_cipher = buffer.load(ct);
+                self.src_server_key()
+                    .apply_lookup_table_assign(shifted_cipher, acc);
+
+                self.keyswitch_to_dest(&shifted_cipher.ct, &mut ct_dest.ct);
+            }
+
+            _ => unreachable!(),
+        }
+
+        let (degree, noise_level) = self.cast_output_degree_and_noise_level(ct);
+        self.set_dest_metadata(ct_dest, degree, noise_level);
+    }
+
+    /// Keyswitch `input`, under the source key and ciphertext modulus, into `output`, under the
+    /// destination ones.
+    ///
+    /// The messages are encoded with the same delta whatever the ciphertext modulus, so when the
+    /// destination modulus is smaller the input is first reduced modulo the destination modulus,
+    /// which keeps the plaintext, rather than letting the keyswitch round the body to the
+    /// destination modulus, which would scale the plaintext down with the modulus.
+    fn keyswitch_to_dest<InputCont, OutputCont>(
+        &self,
+        input: &LweCiphertext<InputCont>,
+        output: &mut LweCiphertext<OutputCont>,
+    ) where
+        InputCont: Container<Element = u64>,
+        OutputCont: ContainerMut<Element = u64>,
+    {
+        let src_ciphertext_modulus = input.ciphertext_modulus();
+        let dest_ciphertext_modulus = self.key_switching_key.ciphertext_modulus();
+
+        if src_ciphertext_modulus == dest_ciphertext_modulus {
+            keyswitch_lwe_ciphertext(self.key_switching_key, input, output);
+            return;
+        }
+
+        // Power of two moduli are stored in the MSBs, the reduction shifts out the bits above the
+        // destination modulus
+        let scaling = dest_ciphertext_modulus.get_power_of_two_scaling_to_native_torus()
+            / src_ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+        let reduced = LweCiphertext::from_container(
+            input
+                .as_ref()
+                .iter()
+                .map(|value| value.wrapping_mul(scaling))
+                .collect::<Vec<_>>(),
+            dest_ciphertext_modulus,
+        );
+
+        keyswitch_lwe_ciphertext(self.key_switching_key, &reduced, output);
+    }
+
+    /// Set the [`Degree`], [`NoiseLevel`] and the destination message and carry moduli of the
+    /// output of a cast.
+    fn set_dest_metadata(&self, ct_dest: &mut Ciphertext, degree: Degree, noise_level: NoiseLevel) {
+        let (dest_message_modulus, dest_carry_modulus) = self.dest_moduli();
+
+        ct_dest.degree = degree;
+        ct_dest.set_noise_level(noise_level);
+        ct_dest.message_modulus = dest_message_modulus;
+        ct_dest.carry_modulus = dest_carry_modulus;
+    }
+
+    fn assert_input_moduli(&self, ct: &Ciphertext) {
+        let (src_message_modulus, src_carry_modulus) = self.src_moduli();
+
+        assert_eq!(
+            (ct.message_modulus, ct.carry_modulus),
+            (src_message_modulus, src_carry_modulus),
+            "Mismatch between the input ciphertext moduli ({:?}, {:?}) \
+            and the source ServerKey moduli ({:?}, {:?}), the ciphertext to cast must be \
+            encrypted with the source parameters of the KeySwitchingKey",
+            ct.message_modulus,
+            ct.carry_modulus,
+            src_message_modulus,
+            src_carry_modulus,
+        );
+    }
+
+    fn check_input_ciphertext(&self, ct: &Ciphertext) -> Result<(), KeySwitchError> {
+        let input_lwe_dimension = ct.ct.lwe_size().to_lwe_dimension();
+        let expected_lwe_dimension = self.src_ciphertext_lwe_dimension();
+        if input_lwe_dimension != expected_lwe_dimension {
+            return Err(KeySwitchError::LweDimensionMismatch {
+                input: input_lwe_dimension,
+                expected: expected_lwe_dimension,
+            });
+        }
+
+        let (src_message_modulus, src_carry_modulus) = self.src_moduli();
+
+        if ct.message_modulus != src_message_modulus {
+            return Err(KeySwitchError::MessageModulusMismatch {
+                input: ct.message_modulus,
+                expected: src_message_modulus,
+            });
+        }
+
+        if ct.carry_modulus != src_carry_modulus {
+            return Err(KeySwitchError::CarryModulusMismatch {
+                input: ct.carry_modulus,
+                expected: src_carry_modulus,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Check that the parameters of `server_key` are the ones of `client_key`, returning `mismatch`
+/// otherwise.
+fn try_key_pair_consistency(
+    key_pair: (&ClientKey, &ServerKey),
+    mismatch: KeySwitchError,
+) -> Result<(), KeySwitchError> {
+    let (client_key, server_key) = key_pair;
+
+    if (
+        client_key.parameters.message_modulus(),
+        client_key.parameters.carry_modulus(),
+        client_key.parameters.ciphertext_modulus(),
+        client_key.large_lwe_secret_key().lwe_dimension(),
+        client_key.small_lwe_secret_key().lwe_dimension(),
+    ) != (
+        server_key.message_modulus,
+        server_key.carry_modulus,
+        server_key.ciphertext_modulus,
+        server_key.bootstrapping_key.output_lwe_dimension(),
+        server_key.bootstrapping_key.input_lwe_dimension(),
+    ) {
+        return Err(mismatch);
+    }
+
+    Ok(())
+}
+
+fn assert_key_pair_consistency(key_pair: (&ClientKey, &ServerKey), mismatch: KeySwitchError) {
+    try_key_pair_consistency(key_pair, mismatch).unwrap_or_else(|err| panic!("{err}"));
+}
+
+/// Keyswitch `input` with `keyswitch_key` and bootstrap the result into `output`, as a
+/// [`ServerKey`] with the [`PBSOrder::KeyswitchBootstrap`] order does.
+fn keyswitch_programmable_bootstrap_lwe_ciphertext<InputCont, OutputCont, BskCont, AccCont>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    keyswitch_key: &LweKeyswitchKeyOwned<u64>,
+    fourier_bsk: &FourierLweBootstrapKey<BskCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    BskCont: Container<Element = c64>,
+    AccCont: Container<Element = u64>,
+{
+    let mut after_ks = LweCiphertext::new(
+        0u64,
+        keyswitch_key.output_lwe_size(),
+        keyswitch_key.ciphertext_modulus(),
+    );
+    keyswitch_lwe_ciphertext(keyswitch_key, input, &mut after_ks);
+
+    programmable_bootstrap_lwe_ciphertext(&after_ks, output, accumulator, fourier_bsk);
+}
+
+/// Generate a lookup table applied with `server_key` during a cast, mapping a message encoded in
+/// the source full message modulus to `f` of that message encoded in the destination full message
+/// modulus.
+///
+/// The boxes of [`ServerKey::generate_lookup_table`] are centered on the encodings of the full
+/// message modulus of `server_key`. When it is the destination one, the encodings of the source
+/// messages are not aligned with them if the ratio of the full message moduli is not a power of
+/// two, and a source message next to a box edge would only keep a fraction of its noise margin.
+/// Here each coefficient is decoded to the source message closest to its phase instead, so the
+/// decision boundaries are at the exact midpoints between the source encodings and the full noise
+/// margin of the source encoding is kept. The output is encoded with the exact destination delta,
+/// not rounded to a multiple of the delta of `server_key`.
+fn generate_cast_accumulator<F>(
+    server_key: &ServerKey,
+    src_full_message_modulus: u64,
+    dest_full_message_modulus: u64,
+    f: F,
+) -> LookupTableOwned
+where
+    F: Fn(u64) -> u64,
+{
+    let server_key_full_message_modulus =
+        full_message_modulus(server_key.message_modulus, server_key.carry_modulus);
+    let polynomial_size = server_key.bootstrapping_key.polynomial_size().0 as u64;
+
+    let src_delta = (1_u64 << 63) / src_full_message_modulus;
+    let dest_delta = (1_u64 << 63) / dest_full_message_modulus;
+    let server_key_delta = (1_u64 << 63) / server_key_full_message_modulus;
+
+    // The blind rotation reads the coefficient `index` for the phase `index * 2^63 / N`
+    let decode = |index: u64| {
+        let phase = scale_and_round(index, 1 << 63, polynomial_size);
+        scale_and_round(phase, 1, src_delta)
+    };
+
+    let mut lookup_table = server_key.generate_lookup_table_no_encode(|index| {
+        let msg = decode(index);
+
+        // The phases closer to the encoding of src_full_message_modulus than to the previous one
+        // are the negative phases of 0, read from the end of the negacyclic accumulator
+        if msg >= src_full_message_modulus {
+            (f(0) * dest_delta).wrapping_neg()
+        } else {
+            f(msg) * dest_delta
+        }
+    });
+
+    // The degree is expressed in the encoding of server_key, rounded up
+    let max_value = (0..polynomial_size)
+        .map(decode)
+        .filter(|&msg| msg < src_full_message_modulus)
+        .map(|msg| (f(msg) * dest_delta).div_ceil(server_key_delta))
+        .max()
+        .unwrap_or(0);
+    lookup_table.degree = Degree::new(max_value as usize);
+
+    lookup_table
+}
+
+/// Take the [`ServerKey`] out of `server_key`, cloning it only if it is shared with another
+/// [`KeySwitchingKey`].
+fn unwrap_or_clone_server_key(server_key: Arc<ServerKey>) -> ServerKey {
+    Arc::try_unwrap(server_key).unwrap_or_else(|server_key| (*server_key).clone())
+}
+
+/// Compute `round(value * numerator / denominator)`.
+///
+/// For power of 2 moduli this is a bit shift of the value.
+///
+/// The intermediate values are computed on 128 bits so that this holds for any full message
+/// moduli fitting in a `u64`.
+fn scale_and_round(value: u64, numerator: u64, denominator: u64) -> u64 {
+    let (value, numerator, denominator) = (value as u128, numerator as u128, denominator as u128);
+
+    ((2 * value * numerator + denominator) / (2 * denominator))
+        .try_into()
+        .unwrap()
+}
+
+/// Compute the full message modulus `message_modulus * carry_modulus` as a `u64`, returning
+/// [`None`] if it does not fit.
+fn checked_full_message_modulus(
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+) -> Option<u64> {
+    (message_modulus.0 as u64).checked_mul(carry_modulus.0 as u64)
+}
+
+/// Compute the full message modulus `message_modulus * carry_modulus` as a `u64`.
+///
+/// # Panics
+///
+/// Panics if the full message modulus does not fit in a `u64`.
+fn full_message_modulus(message_modulus: MessageModulus, carry_modulus: CarryModulus) -> u64 {
+    checked_full_message_modulus(message_modulus, carry_modulus).unwrap_or_else(|| {
+        panic!(
+            "The full message modulus {message_modulus:?} * {carry_modulus:?} does not fit \
+            in a u64"
+        )
+    })
+}
+
+/// Number of bits of a full message modulus, rounded up for moduli which are not a power of 2.
+fn full_message_modulus_nb_bits(full_message_modulus: u64) -> i8 {
+    // At most 64, always fits in an i8
+    (u64::BITS - full_message_modulus.saturating_sub(1).leading_zeros()) as i8
+}
+
+/// Compute the difference between the number of bits of the full message moduli of the
+/// destination and source moduli, rounded up for moduli which are not a power of 2.
+///
+/// Returns an error naming the side whose full message modulus does not fit in a `u64`. Both
+/// numbers of bits are then in `0..=64`, so the difference always fits in an `i8`.
+fn try_cast_rshift_from_moduli(
+    src_moduli: (MessageModulus, CarryModulus),
+    dest_moduli: (MessageModulus, CarryModulus),
+) -> Result<i8, KeySwitchError> {
+    let (src_nb_bits, dest_nb_bits) = try_full_message_moduli_nb_bits(src_moduli, dest_moduli)?;
+
+    Ok(dest_nb_bits - src_nb_bits)
+}
+
+/// Compute the numbers of bits of the source and destination full message moduli, see
+/// [`full_message_modulus_nb_bits`].
+///
+/// Returns an error naming the side whose full message modulus does not fit in a `u64`.
+fn try_full_message_moduli_nb_bits(
+    src_moduli: (MessageModulus, CarryModulus),
+    dest_moduli: (MessageModulus, CarryModulus),
+) -> Result<(i8, i8), KeySwitchError> {
+    let (src_message_modulus, src_carry_modulus) = src_moduli;
+    let (dest_message_modulus, dest_carry_modulus) = dest_moduli;
+
+    let src_full_message_modulus =
+        checked_full_message_modulus(src_message_modulus, src_carry_modulus).ok_or(
+            KeySwitchError::SrcFullMessageModulusOverflow {
+                message_modulus: src_message_modulus,
+                carry_modulus: src_carry_modulus,
+            },
+        )?;
+    let dest_full_message_modulus =
+        checked_full_message_modulus(dest_message_modulus, dest_carry_modulus).ok_or(
+            KeySwitchError::DestFullMessageModulusOverflow {
+                message_modulus: dest_message_modulus,
+                carry_modulus: dest_carry_modulus,
+            },
+        )?;
+
+    Ok((
+        full_message_modulus_nb_bits(src_full_message_modulus),
+        full_message_modulus_nb_bits(dest_full_message_modulus),
+    ))
+}
+
+/// Check that the magnitude of `cast_rshift` is smaller than the number of bits of the full
+/// message modulus the shift is applied in: the destination one for a positive shift, scaling the
+/// message down after the keyswitch, and the source one for a negative shift, scaling it up before
+/// the keyswitch.
+///
+/// A larger shift would move all the message bits out of the full message modulus.
+fn check_cast_rshift_magnitude(
+    cast_rshift: i8,
+    src_server_key: &ServerKey,
+    dest_server_key: &ServerKey,
+) -> Result<(), KeySwitchError> {
+    let (src_nb_bits, dest_nb_bits) = try_full_message_moduli_nb_bits(
+        (src_server_key.message_modulus, src_server_key.carry_modulus),
+        (
+            dest_server_key.message_modulus,
+            dest_server_key.carry_modulus,
+        ),
+    )?;
+
+    let full_message_modulus_nb_bits = if cast_rshift >= 0 {
+        dest_nb_bits
+    } else {
+        src_nb_bits
+    };
+
+    // No shift is always valid, even for a degenerate full message modulus of 1
+    if cast_rshift != 0 && cast_rshift.unsigned_abs() >= full_message_modulus_nb_bits as u8 {
+        return Err(KeySwitchError::CastRshiftOutOfRange {
+            cast_rshift,
+            full_message_modulus_nb_bits,
+        });
+    }
+
+    Ok(())
+}
+
+/// Check that a `cast_rshift` provided with raw parts is in range, see
+/// [`check_cast_rshift_magnitude`], and matches the one derived from the [`ServerKey`]s.
+fn check_cast_rshift(
+    cast_rshift: i8,
+    src_server_key: &ServerKey,
+    dest_server_key: &ServerKey,
+) -> Result<(), KeySwitchError> {
+    check_cast_rshift_magnitude(cast_rshift, src_server_key, dest_server_key)?;
+
+    let expected = server_keys_cast_rshift(src_server_key, dest_server_key)?;
+    if cast_rshift != expected {
+        return Err(KeySwitchError::CastRshiftMismatch {
+            cast_rshift,
+            expected,
+        });
+    }
+
+    Ok(())
+}
+
+/// Same as [`try_cast_rshift_from_moduli`] from the parameters of the source and destination
+/// [`ClientKey`]s.
+fn try_cast_rshift(
+    src_client_key: &ClientKey,
+    dest_client_key: &ClientKey,
+) -> Result<i8, KeySwitchError> {
+    let moduli = |client_key: &ClientKey| {
+        (
+            client_key.parameters.message_modulus(),
+            client_key.parameters.carry_modulus(),
+        )
+    };
+
+    try_cast_rshift_from_moduli(moduli(src_client_key), moduli(dest_client_key))
+}
+
+/// Check that the full message moduli of the source and destination [`ClientKey`]s fit in a
+/// `u64`, panicking with the error of [`try_cast_rshift`] otherwise.
+fn assert_full_message_moduli_fit(src_client_key: &ClientKey, dest_client_key: &ClientKey) {
+    if let Err(err) = try_cast_rshift(src_client_key, dest_client_key) {
+        panic!("{err}");
+    }
+}
+
+/// Same as [`try_cast_rshift_from_moduli`] from the moduli of the source and destination
+/// [`ServerKey`]s.
+fn server_keys_cast_rshift(
+    src_server_key: &ServerKey,
+    dest_server_key: &ServerKey,
+) -> Result<i8, KeySwitchError> {
+    try_cast_rshift_from_moduli(
+        (src_server_key.message_modulus, src_server_key.carry_modulus),
+        (
+            dest_server_key.message_modulus,
+            dest_server_key.carry_modulus,
+        ),
+    )
 }
@@ -0,0 +1,179 @@
+//! This module defines KeySwitchingKeyPair
+//!
+//! - [KeySwitchingKeyPair] holds the two [KeySwitchingKey]s needed to cast ciphertexts back and
+//!   forth between two parameter sets.
+
+use super::{CastServerKeys, KeySwitchingKey};
+use crate::core_crypto::prelude::LweKeyswitchKeyOwned;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::ShortintKeySwitchingParameters;
+use crate::shortint::{Ciphertext, ClientKey, ServerKey};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// The [`KeySwitchingKey`]s casting from a parameter set `a` to a parameter set `b` and back.
+///
+/// Both [`KeySwitchingKey`]s share the same two [`ServerKey`]s, which are stored once in memory and
+/// serialized once.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::{
+///     ShortintKeySwitchingParameters, PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+/// };
+/// use tfhe::shortint::{gen_keys, KeySwitchingKeyPair};
+///
+/// // Generate the client keys and server keys:
+/// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+/// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// // Generate the casting keys for both directions:
+/// let ksk_pair = KeySwitchingKeyPair::new(
+///     (&ck1, &sk1),
+///     (&ck2, &sk2),
+///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+///     ShortintKeySwitchingParameters::new(
+///         PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+///         PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+///     ),
+/// );
+///
+/// let cipher = ck1.encrypt(1);
+/// let cipher_2 = ksk_pair.cast_a_to_b(&cipher);
+/// assert_eq!(ck2.decrypt(&cipher_2), 1);
+///
+/// let cipher_1 = ksk_pair.cast_b_to_a(&cipher_2);
+/// assert_eq!(ck1.decrypt(&cipher_1), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeySwitchingKeyPair {
+    a_to_b: KeySwitchingKey,
+    b_to_a: KeySwitchingKey,
+}
+
+impl KeySwitchingKeyPair {
+    /// Generate the [`KeySwitchingKey`]s casting from `key_pair_a` to `key_pair_b` with
+    /// `params_a_to_b` and from `key_pair_b` to `key_pair_a` with `params_b_to_a`.
+    ///
+    /// See [`KeySwitchingKey::new`].
+    pub fn new(
+        key_pair_a: (&ClientKey, &ServerKey),
+        key_pair_b: (&ClientKey, &ServerKey),
+        params_a_to_b: ShortintKeySwitchingParameters,
+        params_b_to_a: ShortintKeySwitchingParameters,
+    ) -> Self {
+        let server_key_a = Arc::new(key_pair_a.1.clone());
+        let server_key_b = Arc::new(key_pair_b.1.clone());
+
+        ShortintEngine::with_thread_local_mut(|engine| Self {
+            a_to_b: KeySwitchingKey::new_sharing_server_keys(
+                engine,
+                (key_pair_a.0, &server_key_a),
+                (key_pair_b.0, &server_key_b),
+                params_a_to_b,
+            ),
+            b_to_a: KeySwitchingKey::new_sharing_server_keys(
+                engine,
+                (key_pair_b.0, &server_key_b),
+                (key_pair_a.0, &server_key_a),
+                params_b_to_a,
+            ),
+        })
+    }
+
+    /// Return the [`KeySwitchingKey`] casting from the parameters `a` to the parameters `b`.
+    pub fn a_to_b(&self) -> &KeySwitchingKey {
+        &self.a_to_b
+    }
+
+    /// Return the [`KeySwitchingKey`] casting from the parameters `b` to the parameters `a`.
+    pub fn b_to_a(&self) -> &KeySwitchingKey {
+        &self.b_to_a
+    }
+
+    /// Deconstruct a [`KeySwitchingKeyPair`] into its `(a_to_b, b_to_a)` [`KeySwitchingKey`]s.
+    pub fn into_keys(self) -> (KeySwitchingKey, KeySwitchingKey) {
+        (self.a_to_b, self.b_to_a)
+    }
+
+    /// Cast a ciphertext from the parameters `a` to the parameters `b`.
+    ///
+    /// See [`KeySwitchingKey::cast`].
+    pub fn cast_a_to_b(&self, ct: &Ciphertext) -> Ciphertext {
+        self.a_to_b.cast(ct)
+    }
+
+    /// Cast a ciphertext from the parameters `b` to the parameters `a`.
+    ///
+    /// See [`KeySwitchingKey::cast`].
+    pub fn cast_b_to_a(&self, ct: &Ciphertext) -> Ciphertext {
+        self.b_to_a.cast(ct)
+    }
+}
+
+// Only the keyswitching key of b_to_a is serialized, its ServerKeys are the ones of a_to_b
+#[derive(Serialize)]
+#[serde(rename = "KeySwitchingKeyPair")]
+struct KeySwitchingKeyPairRef<'a> {
+    a_to_b: &'a KeySwitchingKey,
+    b_to_a_key_switching_key: &'a LweKeyswitchKeyOwned<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "KeySwitchingKeyPair")]
+struct KeySwitchingKeyPairOwned {
+    a_to_b: KeySwitchingKey,
+    b_to_a_key_switching_key: LweKeyswitchKeyOwned<u64>,
+}
+
+impl Serialize for KeySwitchingKeyPair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        KeySwitchingKeyPairRef {
+            a_to_b: &self.a_to_b,
+            b_to_a_key_switching_key: &self.b_to_a.key_switching_key,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KeySwitchingKeyPair {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let KeySwitchingKeyPairOwned {
+            a_to_b,
+            b_to_a_key_switching_key,
+        } = KeySwitchingKeyPairOwned::deserialize(deserializer)?;
+
+        let CastServerKeys::Distinct {
+            src: server_key_a,
+            dest: server_key_b,
+        } = &a_to_b.server_keys
+        else {
+            return Err(serde::de::Error::custom(
+                "The KeySwitchingKey from a to b of a KeySwitchingKeyPair must store both \
+                ServerKeys",
+            ));
+        };
+
+        KeySwitchingKey::validate_raw_parts(&b_to_a_key_switching_key, server_key_a, server_key_b)
+            .map_err(serde::de::Error::custom)?;
+
+        let b_to_a = KeySwitchingKey::try_from_server_keys(
+            b_to_a_key_switching_key,
+            CastServerKeys::Distinct {
+                src: server_key_b.clone(),
+                dest: server_key_a.clone(),
+            },
+        )
+        .map_err(serde::de::Error::custom)?;
+
+        Ok(Self { a_to_b, b_to_a })
+    }
+}
@@ -1,8 +1,31 @@
+use crate::core_crypto::commons::generators::DeterministicSeeder;
+use crate::core_crypto::commons::math::random::Seed;
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_lwe_packing_keyswitch_key, decrypt_glwe_ciphertext,
+    keyswitch_lwe_ciphertext, ActivatedRandomGenerator, ContiguousEntityContainer, GlweCiphertext,
+    LweKeyswitchKeyOwned, LwePackingKeyswitchKey, PlaintextCount, PlaintextList, SignedDecomposer,
+};
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::key_switching_key::{
+    CastServerKeys, KeySwitchError, KeySwitchingKeyBytesError, KeySwitchingKeyV0,
+};
 use crate::shortint::keycache::{KEY_CACHE, KEY_CACHE_KSK};
-use crate::shortint::parameters::ShortintKeySwitchingParameters;
+use crate::shortint::parameters::{
+    ShortintKeySwitchingParameters, ShortintKeySwitchingParametersError,
+};
 use crate::shortint::prelude::*;
+use crate::shortint::server_key::ShortintBootstrappingKey;
+use crate::shortint::{
+    CastBuffer, CastCost, CastLookupTableCache, KeySwitchingKeyBuilder,
+    KeySwitchingKeyBuilderError, KeySwitchingKeyChain, KeySwitchingKeyChainError,
+    KeySwitchingKeyMaterial, KeySwitchingKeyPair, KeySwitchingKeyView, SeededKeySwitchingKey,
+};
+use rand::Rng;
+use std::sync::Arc;
 
 #[test]
+#[allow(deprecated)]
 fn gen_multi_keys_test_fresh_ci_run_filter() {
     let keys = KEY_CACHE_KSK.get_from_param((
         PARAM_MESSAGE_1_CARRY_1_KS_PBS,
@@ -53,6 +76,7 @@ fn gen_multi_keys_test_fresh_ci_run_filter() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn gen_multi_keys_test_fresh_2_ci_run_filter() {
     let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_3_CARRY_3_KS_PBS);
     let (ck2, sk2) = (keys2.client_key(), keys2.server_key());
@@ -136,6 +160,7 @@ fn gen_multi_keys_test_add_with_overflow_ci_run_filter() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn gen_multi_keys_test_no_shift_ci_run_filter() {
     let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
     let ck2 = keys2.client_key();
@@ -156,6 +181,7 @@ fn gen_multi_keys_test_no_shift_ci_run_filter() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn gen_multi_keys_test_truncate_ci_run_filter() {
     let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
     let (ck2, sk2) = (keys2.client_key(), keys2.server_key());
@@ -225,3 +251,2949 @@ fn gen_multi_keys_test_truncate_ci_run_filter() {
     let carry = ck2.decrypt(&ct_carry);
     assert_eq!(carry, 0);
 }
+
+#[test]
+fn gen_multi_keys_test_cast_into_slice_ci_run_filter() {
+    let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let ck2 = keys2.client_key();
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+
+    // Truncation and extension, to use both the src and the dest lookup tables
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ksk_params,
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let ck1 = keys.client_key_1();
+        let (ck2, sk2) = (keys.client_key_2(), keys.server_key_2());
+        let ksk = keys.key_switching_key();
+
+        let msg_modulus = ck1
+            .parameters
+            .message_modulus()
+            .0
+            .min(ck2.parameters.message_modulus().0) as u64;
+
+        let clears: Vec<u64> = (0..msg_modulus).collect();
+        let ciphers: Vec<_> = clears.iter().map(|&m| ck1.encrypt(m)).collect();
+        let mut outputs_of_cast: Vec<_> = clears.iter().map(|_| sk2.create_trivial(0)).collect();
+
+        ksk.cast_into_slice(&ciphers, &mut outputs_of_cast);
+
+        for (output_of_cast, clear) in outputs_of_cast.iter().zip(clears) {
+            assert_eq!(ck2.decrypt(output_of_cast), clear);
+            assert_eq!(output_of_cast, &ksk.cast(&ciphers[clear as usize]));
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_cast_and_pack_into_ci_run_filter() {
+    // Same bit size and extension, the destination ciphertexts use the large LWE key
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+            ),
+        ),
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+        let ksk = keys.key_switching_key();
+
+        // Pack in a GLWE under the destination GLWE secret key
+        let lwe_pksk = ShortintEngine::with_thread_local_mut(|engine| {
+            allocate_and_generate_new_lwe_packing_keyswitch_key(
+                &ck2.large_lwe_secret_key(),
+                &ck2.glwe_secret_key,
+                ck2.parameters.pbs_base_log(),
+                ck2.parameters.pbs_level(),
+                ck2.parameters.glwe_noise_distribution(),
+                ck2.parameters.ciphertext_modulus(),
+                &mut engine.encryption_generator,
+            )
+        });
+
+        let msg_modulus = ck1.parameters.message_modulus().0 as u64;
+        let clears: Vec<u64> = (0..msg_modulus).collect();
+        let ciphers: Vec<_> = clears.iter().map(|&m| ck1.encrypt(m)).collect();
+
+        let polynomial_size = ck2.parameters.polynomial_size();
+        let mut packed = GlweCiphertext::new(
+            0u64,
+            ck2.parameters.glwe_dimension().to_glwe_size(),
+            polynomial_size,
+            ck2.parameters.ciphertext_modulus(),
+        );
+
+        ksk.cast_and_pack_into(&ciphers, &lwe_pksk, &mut packed);
+
+        let mut decrypted = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+        decrypt_glwe_ciphertext(&ck2.glwe_secret_key, &packed, &mut decrypted);
+
+        let dest_msg_modulus = ck2.parameters.message_modulus().0 as u64;
+        let delta = (1u64 << 63) / (dest_msg_modulus * ck2.parameters.carry_modulus().0 as u64);
+        let decoder = SignedDecomposer::new(
+            DecompositionBaseLog(delta.leading_zeros() as usize + 1),
+            DecompositionLevelCount(1),
+        );
+        let decoded: Vec<_> = decrypted
+            .iter()
+            .map(|x| (decoder.closest_representable(*x.0) / delta) % dest_msg_modulus)
+            .collect();
+
+        // The casts in the first coefficients, 0 in the other ones
+        assert_eq!(&decoded[..clears.len()], clears.as_slice());
+        assert!(decoded[clears.len()..].iter().all(|&x| x == 0));
+    }
+}
+
+#[test]
+#[should_panic(expected = "Cannot pack an empty slice of ciphertexts")]
+fn gen_multi_keys_test_cast_and_pack_into_empty_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ck2 = keys.client_key_2();
+    let ksk = keys.key_switching_key();
+
+    let lwe_pksk = LwePackingKeyswitchKey::new(
+        0u64,
+        ck2.parameters.pbs_base_log(),
+        ck2.parameters.pbs_level(),
+        ck2.large_lwe_secret_key().lwe_dimension(),
+        ck2.parameters.glwe_dimension(),
+        ck2.parameters.polynomial_size(),
+        ck2.parameters.ciphertext_modulus(),
+    );
+    let mut packed = GlweCiphertext::new(
+        0u64,
+        ck2.parameters.glwe_dimension().to_glwe_size(),
+        ck2.parameters.polynomial_size(),
+        ck2.parameters.ciphertext_modulus(),
+    );
+
+    ksk.cast_and_pack_into(&[], &lwe_pksk, &mut packed);
+}
+
+#[test]
+fn gen_multi_keys_test_par_cast_ci_run_filter() {
+    let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let ck2 = keys2.client_key();
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ksk_params,
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let ck1 = keys.client_key_1();
+        let ck2 = keys.client_key_2();
+        let ksk = keys.key_switching_key();
+
+        let msg_modulus = ck1
+            .parameters
+            .message_modulus()
+            .0
+            .min(ck2.parameters.message_modulus().0) as u64;
+
+        let clears: Vec<u64> = (0..msg_modulus).collect();
+        let ciphers: Vec<_> = clears.iter().map(|&m| ck1.encrypt(m)).collect();
+
+        let outputs_of_cast = ksk.par_cast(&ciphers);
+        assert_eq!(outputs_of_cast.len(), ciphers.len());
+
+        for (output_of_cast, clear) in outputs_of_cast.iter().zip(clears) {
+            assert_eq!(ck2.decrypt(output_of_cast), clear);
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_cast_into_assume_clean_ci_run_filter() {
+    // 2_2 -> 1_1: the source side PBS is replaced by a multiplication by 4
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        ShortintKeySwitchingParameters::new(
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+        ),
+    ));
+    let ck1 = keys.client_key_1();
+    let ck2 = keys.client_key_2();
+    let sk2 = keys.server_key_2();
+    let ksk = keys.key_switching_key();
+
+    // Messages fitting in the 2 bits of the dest full message modulus
+    for cleartext in 0..4 {
+        let mut cipher = ck1.unchecked_encrypt(cleartext);
+        // unchecked_encrypt uses the maximum degree, the caller knows the actual message
+        cipher.degree = Degree::new(cleartext as usize);
+        let mut cipher_2 = sk2.create_trivial(0);
+        ksk.cast_into_assume_clean(&cipher, &mut cipher_2);
+
+        assert_eq!(ck2.decrypt_message_and_carry(&cipher_2), cleartext);
+        assert_eq!(cipher_2.degree.get(), cleartext as usize);
+        // The noise is scaled instead of being reset by a PBS
+        assert_eq!(cipher_2.noise_level(), NoiseLevel::NOMINAL * 4);
+    }
+
+    // 1_1 -> 2_2: same as cast_into
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ck1 = keys.client_key_1();
+    let ck2 = keys.client_key_2();
+    let sk2 = keys.server_key_2();
+    let ksk = keys.key_switching_key();
+
+    for cleartext in 0..2 {
+        let cipher = ck1.encrypt(cleartext);
+        let mut cipher_2 = sk2.create_trivial(0);
+        ksk.cast_into_assume_clean(&cipher, &mut cipher_2);
+
+        assert_eq!(ck2.decrypt(&cipher_2), cleartext);
+        assert_eq!(cipher_2.noise_level(), NoiseLevel::NOMINAL);
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_can_cast_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ck1 = keys.client_key_1();
+    let ck2 = keys.client_key_2();
+    let ksk = keys.key_switching_key();
+
+    let cipher = ck1.encrypt(1);
+    assert_eq!(ksk.can_cast(&cipher), Ok(()));
+
+    // can_cast reports the same errors as try_cast
+    let mut wrong_moduli = cipher.clone();
+    wrong_moduli.message_modulus = MessageModulus(4);
+    assert_eq!(
+        ksk.can_cast(&wrong_moduli),
+        Err(KeySwitchError::MessageModulusMismatch {
+            input: MessageModulus(4),
+            expected: MessageModulus(2),
+        })
+    );
+    assert_eq!(
+        ksk.can_cast(&wrong_moduli),
+        ksk.try_cast(&wrong_moduli).map(|_| ())
+    );
+
+    let mut wrong_moduli = cipher;
+    wrong_moduli.carry_modulus = CarryModulus(4);
+    assert_eq!(
+        ksk.can_cast(&wrong_moduli),
+        Err(KeySwitchError::CarryModulusMismatch {
+            input: CarryModulus(4),
+            expected: CarryModulus(2),
+        })
+    );
+
+    let dest_cipher = ck2.encrypt(1);
+    assert!(matches!(
+        ksk.can_cast(&dest_cipher),
+        Err(KeySwitchError::LweDimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn gen_multi_keys_test_try_cast_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ck1 = keys.client_key_1();
+    let ck2 = keys.client_key_2();
+    let ksk = keys.key_switching_key();
+
+    let cipher = ck1.encrypt(1);
+    let output_of_cast = ksk.try_cast(&cipher).unwrap();
+    assert_eq!(ck2.decrypt(&output_of_cast), 1);
+
+    // Same LweDimension, wrong moduli
+    let mut wrong_moduli = cipher.clone();
+    wrong_moduli.message_modulus = MessageModulus(4);
+    assert_eq!(
+        ksk.try_cast(&wrong_moduli),
+        Err(KeySwitchError::MessageModulusMismatch {
+            input: MessageModulus(4),
+            expected: MessageModulus(2),
+        })
+    );
+
+    let mut wrong_moduli = cipher;
+    wrong_moduli.carry_modulus = CarryModulus(4);
+    assert_eq!(
+        ksk.try_cast(&wrong_moduli),
+        Err(KeySwitchError::CarryModulusMismatch {
+            input: CarryModulus(4),
+            expected: CarryModulus(2),
+        })
+    );
+
+    // Ciphertext from the destination parameter set
+    let dest_cipher = ck2.encrypt(1);
+    assert!(matches!(
+        ksk.try_cast(&dest_cipher),
+        Err(KeySwitchError::LweDimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn gen_multi_keys_test_view_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ck1 = keys.client_key_1();
+    let ck2 = keys.client_key_2();
+    let ksk = keys.key_switching_key();
+    let ksk_view = ksk.as_view();
+
+    assert_eq!(ksk_view.cast_rshift(), ksk.cast_rshift());
+
+    rayon::scope(|s| {
+        for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+            let cipher = ck1.encrypt(msg);
+            s.spawn(move |_| {
+                let output_of_cast = ksk_view.cast(&cipher);
+                assert_eq!(ck2.decrypt(&output_of_cast), msg);
+            });
+        }
+    });
+}
+
+#[test]
+fn gen_multi_keys_test_view_from_raw_keys_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let (ck1, sk1) = (keys.client_key_1(), keys.server_key_1());
+    let (ck2, sk2) = (keys.client_key_2(), keys.server_key_2());
+    let raw_ksk = &keys.key_switching_key().key_switching_key;
+
+    let cache = CastLookupTableCache::new();
+    let ksk_view = KeySwitchingKeyView::new(raw_ksk, sk1, Some(sk2), &cache).unwrap();
+
+    assert_eq!(
+        ksk_view.cast_rshift(),
+        keys.key_switching_key().cast_rshift()
+    );
+
+    for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+        let cipher = ck1.encrypt(msg);
+        assert_eq!(ck2.decrypt(&ksk_view.cast(&cipher)), msg);
+    }
+
+    // Swapped ServerKeys
+    assert!(matches!(
+        KeySwitchingKeyView::new(raw_ksk, sk2, Some(sk1), &cache),
+        Err(KeySwitchError::KeyswitchKeyInputLweDimensionMismatch { .. })
+    ));
+
+    // Shared ServerKey
+    let (ck_a, sk) = (ck2, sk2);
+    let ck_b = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let ksk_shared = KeySwitchingKey::new_with_shared_server_key(
+        ck_a,
+        &ck_b,
+        sk,
+        ShortintKeySwitchingParameters::new(
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+        ),
+    );
+
+    let cache = CastLookupTableCache::new();
+    let ksk_view =
+        KeySwitchingKeyView::new(&ksk_shared.key_switching_key, sk, None, &cache).unwrap();
+
+    for msg in 0..ck_a.parameters.message_modulus().0 as u64 {
+        let cipher = ck_a.encrypt(msg);
+        assert_eq!(ck_b.decrypt(&ksk_view.cast(&cipher)), msg);
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_non_power_of_two_ci_run_filter() {
+    // Full message modulus of 12
+    let param_non_power_of_two = ClassicPBSParameters {
+        message_modulus: MessageModulus(3),
+        ..PARAM_MESSAGE_2_CARRY_2_KS_PBS
+    };
+
+    let (ck_non_power_of_two, sk_non_power_of_two) = gen_keys(param_non_power_of_two);
+    let (ck_power_of_two, sk_power_of_two) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+    );
+
+    // Extension then truncation
+    for ((ck1, sk1), (ck2, sk2)) in [
+        (
+            (&ck_non_power_of_two, &sk_non_power_of_two),
+            (&ck_power_of_two, &sk_power_of_two),
+        ),
+        (
+            (&ck_power_of_two, &sk_power_of_two),
+            (&ck_non_power_of_two, &sk_non_power_of_two),
+        ),
+    ] {
+        let ksk = KeySwitchingKey::new((ck1, sk1), (ck2, sk2), ksk_params);
+
+        // Both moduli round up to 4 bits but the message still needs to be rescaled
+        assert_eq!(ksk.cast_rshift(), 0);
+        assert_eq!(ksk.cast_cost().pbs_count(), 1);
+
+        let msg_modulus = ck1
+            .parameters
+            .message_modulus()
+            .0
+            .min(ck2.parameters.message_modulus().0) as u64;
+
+        for clear in 0..msg_modulus {
+            let cipher = ck1.encrypt(clear);
+            let output_of_cast = ksk.cast(&cipher);
+            assert_eq!(ck2.decrypt(&output_of_cast), clear);
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_non_power_of_two_all_messages_ci_run_filter() {
+    const NB_TESTS: usize = 10;
+
+    // Full message modulus of 12, cast to and from a full message modulus of 16, the ratio of the
+    // deltas is not a power of two
+    let param_non_power_of_two = ClassicPBSParameters {
+        message_modulus: MessageModulus(3),
+        ..PARAM_MESSAGE_2_CARRY_2_KS_PBS
+    };
+
+    let (ck_non_power_of_two, sk_non_power_of_two) = gen_keys(param_non_power_of_two);
+    let (ck_power_of_two, sk_power_of_two) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+    );
+
+    for ((ck1, sk1), (ck2, sk2)) in [
+        (
+            (&ck_non_power_of_two, &sk_non_power_of_two),
+            (&ck_power_of_two, &sk_power_of_two),
+        ),
+        (
+            (&ck_power_of_two, &sk_power_of_two),
+            (&ck_non_power_of_two, &sk_non_power_of_two),
+        ),
+    ] {
+        let ksk = KeySwitchingKey::new((ck1, sk1), (ck2, sk2), ksk_params);
+        let src_full_message_modulus = ksk.src_full_message_modulus();
+        let dest_full_message_modulus = ksk.dest_full_message_modulus();
+
+        // Every message, carries included, is decoded at the exact midpoints of its encoding
+        for clear in 0..src_full_message_modulus {
+            for _ in 0..NB_TESTS {
+                let cipher = ck1.unchecked_encrypt(clear);
+                let output_of_cast = ksk.cast(&cipher);
+                assert_eq!(
+                    ck2.decrypt_message_and_carry(&output_of_cast),
+                    clear % dest_full_message_modulus
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_seeded_ci_run_filter() {
+    let keys1 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let (ck1, sk1) = (keys1.client_key(), keys1.server_key());
+    let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (ck2, sk2) = (keys2.client_key(), keys2.server_key());
+
+    let seeded_ksk = SeededKeySwitchingKey::new(
+        (ck1, sk1),
+        (ck2, sk2),
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    );
+    assert_eq!(seeded_ksk.cast_rshift(), 2);
+
+    let seeded_ksk_size = bincode::serialize(&seeded_ksk).unwrap().len();
+
+    let ksk = seeded_ksk.decompress();
+    assert_eq!(ksk.cast_rshift(), 2);
+    assert!(seeded_ksk_size < bincode::serialize(&ksk).unwrap().len());
+
+    for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+        let cipher = ck1.encrypt(msg);
+        let output_of_cast = ksk.cast(&cipher);
+        assert_eq!(ck2.decrypt(&output_of_cast), msg);
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_seeded_write_read_compressed_ci_run_filter() {
+    let keys1 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let (ck1, sk1) = (keys1.client_key(), keys1.server_key());
+    let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (ck2, sk2) = (keys2.client_key(), keys2.server_key());
+
+    let seeded_ksk = SeededKeySwitchingKey::new(
+        (ck1, sk1),
+        (ck2, sk2),
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    );
+
+    let mut buffer = vec![];
+    seeded_ksk.write_compressed(&mut buffer).unwrap();
+    assert_eq!(buffer, bincode::serialize(&seeded_ksk).unwrap());
+
+    let read_ksk = SeededKeySwitchingKey::read_compressed(buffer.as_slice()).unwrap();
+    assert_eq!(read_ksk, seeded_ksk);
+
+    // A truncated stream is reported as an error
+    assert!(SeededKeySwitchingKey::read_compressed(&buffer[..buffer.len() / 2]).is_err());
+
+    let ksk = read_ksk.decompress();
+    for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+        let cipher = ck1.encrypt(msg);
+        let output_of_cast = ksk.cast(&cipher);
+        assert_eq!(ck2.decrypt(&output_of_cast), msg);
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_different_ciphertext_moduli_ci_run_filter() {
+    let param_native = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    let mut param_2_62 = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    param_2_62.ciphertext_modulus = CiphertextModulus::try_new_power_of_2(62).unwrap();
+
+    let (ck_native, sk_native) = gen_keys(param_native);
+    let (ck_2_62, sk_2_62) = gen_keys(param_2_62);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck_native.parameters.ks_base_log(),
+        ck_native.parameters.ks_level(),
+    );
+
+    // The message cannot be kept when casting to a bigger modulus
+    assert_eq!(
+        KeySwitchingKey::try_new_without_dest_server_key(
+            (&ck_2_62, &sk_2_62),
+            &ck_native,
+            ksk_params
+        ),
+        Err(KeySwitchError::IncompatibleCiphertextModuli {
+            src_server_key: param_2_62.ciphertext_modulus,
+            dest_server_key: param_native.ciphertext_modulus,
+        })
+    );
+
+    // The input is reduced modulo the destination modulus before the keyswitch
+    for ((ck1, sk1), (ck2, sk2)) in [
+        ((&ck_native, &sk_native), (&ck_2_62, &sk_2_62)),
+        ((&ck_2_62, &sk_2_62), (&ck_2_62, &sk_2_62)),
+    ] {
+        let ksk_params = ShortintKeySwitchingParameters::new(
+            ck2.parameters.ks_base_log(),
+            ck2.parameters.ks_level(),
+        );
+        let ksk = KeySwitchingKey::new((ck1, sk1), (ck2, sk2), ksk_params);
+
+        let (key_switching_key, dest_server_key, src_server_key, cast_rshift) =
+            ksk.clone().into_raw_parts();
+        let ksk_from_raw_parts = KeySwitchingKey::from_raw_parts(
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+            cast_rshift,
+        );
+        assert_eq!(ksk_from_raw_parts, ksk);
+
+        for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+            let cipher = ck1.encrypt(msg);
+            let output_of_cast = ksk.cast(&cipher);
+            assert_eq!(
+                output_of_cast.ct.ciphertext_modulus(),
+                param_2_62.ciphertext_modulus
+            );
+            assert_eq!(ck2.decrypt(&output_of_cast), msg);
+
+            // The cast ciphertext can be bootstrapped with the destination server key
+            let refreshed =
+                sk2.apply_lookup_table(&output_of_cast, &sk2.generate_lookup_table(|x| x));
+            assert_eq!(ck2.decrypt(&refreshed), msg);
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_refresh_ci_run_filter() {
+    let (ck_old, sk_old) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (ck_new, sk_new) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck_new.parameters.ks_base_log(),
+        ck_new.parameters.ks_level(),
+    );
+    let ksk = KeySwitchingKey::new_refresh((&ck_old, &sk_old), (&ck_new, &sk_new), ksk_params);
+    assert_eq!(ksk.cast_rshift(), 0);
+
+    for msg in 0..ck_old.parameters.message_modulus().0 as u64 {
+        let cipher = ck_old.encrypt(msg);
+
+        let output_of_cast = ksk.cast(&cipher);
+        assert_eq!(ck_new.decrypt(&output_of_cast), msg);
+
+        let mut refreshed = sk_new.create_trivial(0);
+        ksk.cast_into_with_bootstrap(&cipher, &mut refreshed);
+        assert_eq!(refreshed.noise_level(), NoiseLevel::NOMINAL);
+        assert_eq!(ck_new.decrypt(&refreshed), msg);
+    }
+}
+
+#[test]
+#[should_panic(expected = "requires both ClientKeys to use the same parameters")]
+fn gen_multi_keys_test_refresh_parameters_mismatch_ci_run_filter() {
+    let keys1 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let (ck1, sk1) = (keys1.client_key(), keys1.server_key());
+    let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (ck2, sk2) = (keys2.client_key(), keys2.server_key());
+
+    let _ = KeySwitchingKey::new_refresh(
+        (ck1, sk1),
+        (ck2, sk2),
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    );
+}
+
+#[test]
+fn gen_multi_keys_test_cast_into_clean_carries_ci_run_filter() {
+    // Same bit size, extension and truncation
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+            ),
+        ),
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+            ),
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let ck1 = keys.client_key_1();
+        let (ck2, sk2) = (keys.client_key_2(), keys.server_key_2());
+        let ksk = keys.key_switching_key();
+
+        let src_full_message_modulus =
+            (ck1.parameters.message_modulus().0 * ck1.parameters.carry_modulus().0) as u64;
+        let dest_message_modulus = ck2.parameters.message_modulus().0 as u64;
+
+        let mut output_of_cast = sk2.create_trivial(0);
+        for msg in 0..src_full_message_modulus {
+            let cipher = ck1.unchecked_encrypt(msg);
+            ksk.cast_into_clean_carries(&cipher, &mut output_of_cast);
+
+            assert!(output_of_cast.degree.get() < dest_message_modulus as usize);
+            assert_eq!(output_of_cast.noise_level(), NoiseLevel::NOMINAL);
+            assert_eq!(
+                ck2.decrypt_message_and_carry(&output_of_cast),
+                msg % dest_message_modulus
+            );
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_cast_into_with_bootstrap_ci_run_filter() {
+    let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let ck2 = keys2.client_key();
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+
+    // Same bit size, extension and truncation
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ksk_params,
+        ),
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ksk_params,
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let (ck1, sk1) = (keys.client_key_1(), keys.server_key_1());
+        let (ck2, sk2) = (keys.client_key_2(), keys.server_key_2());
+        let ksk = keys.key_switching_key();
+
+        let msg_modulus = ck1
+            .parameters
+            .message_modulus()
+            .0
+            .min(ck2.parameters.message_modulus().0) as u64;
+
+        for clear in 0..msg_modulus {
+            // Increase the noise level of the input
+            let cipher = sk1.unchecked_add(&ck1.encrypt(clear), &ck1.encrypt(0));
+            assert!(cipher.noise_level().get() > NoiseLevel::NOMINAL.get());
+
+            let mut output_of_cast = sk2.create_trivial(0);
+            ksk.cast_into_with_bootstrap(&cipher, &mut output_of_cast);
+
+            assert_eq!(ck2.decrypt(&output_of_cast), clear);
+            assert_eq!(output_of_cast.noise_level(), NoiseLevel::NOMINAL);
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_checked_cast_truncation_ci_run_filter() {
+    let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let ck2 = keys2.client_key();
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        ksk_params,
+    ));
+    let (ck1, sk1) = (keys.client_key_1(), keys.server_key_1());
+    let ck2 = keys.client_key_2();
+    let ksk = keys.key_switching_key();
+
+    for clear in 0..ck1.parameters.message_modulus().0 as u64 {
+        // The degree of a fresh ciphertext fits, the one of an unchecked encryption covers the
+        // carries
+        assert!(ksk.checked_cast(&ck1.encrypt(clear)).is_ok());
+
+        let cipher = ck1.unchecked_encrypt(clear);
+        assert_eq!(
+            ksk.checked_cast(&cipher),
+            Err(KeySwitchError::MessageTruncation {
+                degree: cipher.degree,
+                dest_full_message_modulus: 4,
+            })
+        );
+
+        let cipher = sk1.message_extract(&cipher);
+        let output_of_cast = ksk.checked_cast(&cipher).unwrap();
+        assert_eq!(ck2.decrypt(&output_of_cast), clear % 2);
+    }
+
+    // Extension never truncates
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ck1 = keys.client_key_1();
+    let ck2 = keys.client_key_2();
+    let ksk = keys.key_switching_key();
+
+    let cipher = ck1.encrypt(1);
+    let output_of_cast = ksk.checked_cast(&cipher).unwrap();
+    assert_eq!(ck2.decrypt(&output_of_cast), 1);
+}
+
+#[test]
+fn gen_multi_keys_test_cast_output_degree_and_noise_level_ci_run_filter() {
+    let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let ck2 = keys2.client_key();
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+
+    // Same bit size, extension and truncation
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ksk_params,
+        ),
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ksk_params,
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let (ck1, sk1) = (keys.client_key_1(), keys.server_key_1());
+        let ck2 = keys.client_key_2();
+        let ksk = keys.key_switching_key();
+
+        let src_full_message_modulus =
+            ck1.parameters.message_modulus().0 * ck1.parameters.carry_modulus().0;
+        let dest_full_message_modulus =
+            ck2.parameters.message_modulus().0 * ck2.parameters.carry_modulus().0;
+
+        let msg_modulus = ck1
+            .parameters
+            .message_modulus()
+            .0
+            .min(ck2.parameters.message_modulus().0) as u64;
+
+        for clear in 0..msg_modulus {
+            let cipher = sk1.unchecked_add(&ck1.encrypt(clear), &ck1.encrypt(0));
+
+            let (degree, noise_level) = ksk.cast_output_degree_and_noise_level(&cipher);
+
+            if src_full_message_modulus == dest_full_message_modulus {
+                assert_eq!(degree, cipher.degree);
+                assert_eq!(noise_level, cipher.noise_level());
+            } else {
+                assert_eq!(
+                    degree.get(),
+                    cipher.degree.get().min(dest_full_message_modulus - 1)
+                );
+                assert_eq!(noise_level, NoiseLevel::NOMINAL);
+            }
+
+            let output_of_cast = ksk.cast(&cipher);
+            assert_eq!(ck2.decrypt(&output_of_cast), clear);
+            assert_eq!(output_of_cast.degree, degree);
+            assert_eq!(output_of_cast.noise_level(), noise_level);
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_shared_server_key_ci_run_filter() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (ck1, sk) = (keys.client_key(), keys.server_key());
+    let ck2 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+
+    let shared_ksk = KeySwitchingKey::new_with_shared_server_key(ck1, &ck2, sk, ksk_params);
+    assert_eq!(shared_ksk.cast_rshift(), 0);
+
+    let ksk = KeySwitchingKey::new((ck1, sk), (&ck2, sk), ksk_params);
+    assert!(
+        bincode::serialize(&shared_ksk).unwrap().len() < bincode::serialize(&ksk).unwrap().len()
+    );
+
+    for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+        let cipher = ck1.encrypt(msg);
+        let output_of_cast = shared_ksk.cast(&cipher);
+        assert_eq!(ck2.decrypt(&output_of_cast), msg);
+
+        // The shared key is used on both sides
+        let ct_res = sk.unchecked_add(&output_of_cast, &ck2.encrypt(1));
+        assert_eq!(
+            ck2.decrypt(&ct_res),
+            (msg + 1) % ck2.parameters.message_modulus().0 as u64
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "Mismatch between the ClientKey parameters")]
+fn gen_multi_keys_test_shared_server_key_mismatch_ci_run_filter() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (ck1, sk) = (keys.client_key(), keys.server_key());
+    let ck2 = KEY_CACHE
+        .get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS)
+        .client_key()
+        .clone();
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+
+    let _ = KeySwitchingKey::new_with_shared_server_key(ck1, &ck2, sk, ksk_params);
+}
+
+#[test]
+fn gen_multi_keys_test_cast_into_with_buffer_ci_run_filter() {
+    let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let ck2 = keys2.client_key();
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ksk_params,
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let ck1 = keys.client_key_1();
+        let (ck2, sk2) = (keys.client_key_2(), keys.server_key_2());
+        let ksk = keys.key_switching_key();
+
+        let msg_modulus = ck1
+            .parameters
+            .message_modulus()
+            .0
+            .min(ck2.parameters.message_modulus().0) as u64;
+
+        let mut output_of_cast = sk2.create_trivial(0);
+        let mut buffer = CastBuffer::new();
+        let mut buffer_ptr = None;
+
+        for clear in 0..msg_modulus {
+            let cipher = ck1.encrypt(clear);
+            ksk.cast_into_with_buffer(&cipher, &mut output_of_cast, &mut buffer);
+            assert_eq!(ck2.decrypt(&output_of_cast), clear);
+
+            // The buffer is only used when casting to a smaller full message modulus, and then
+            // keeps the same allocation across casts
+            let current_ptr = buffer
+                .shifted_cipher
+                .as_ref()
+                .map(|ct| ct.ct.as_ref().as_ptr());
+            assert_eq!(current_ptr.is_some(), ksk.cast_rshift() < 0);
+            if clear > 0 {
+                assert_eq!(current_ptr, buffer_ptr);
+            }
+            buffer_ptr = current_ptr;
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_keyswitch_only_ci_run_filter() {
+    let ck1 = KEY_CACHE
+        .get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+        .client_key()
+        .clone();
+    let ck2 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+
+    let ksk = KeySwitchingKey::new_keyswitch_only((&ck1, &ck2), ksk_params);
+    assert_eq!(ksk.cast_rshift(), 0);
+
+    for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+        let cipher = ck1.encrypt(msg);
+
+        let output_of_cast = ksk.cast(&cipher);
+        assert_eq!(ck2.decrypt(&output_of_cast), msg);
+        assert_eq!(output_of_cast.degree, cipher.degree);
+        assert_eq!(output_of_cast.message_modulus, cipher.message_modulus);
+        assert_eq!(output_of_cast.carry_modulus, cipher.carry_modulus);
+
+        let mut output_of_cast = ksk.cast(&ck1.encrypt(0));
+        ksk.cast_into(&cipher, &mut output_of_cast);
+        assert_eq!(ck2.decrypt(&output_of_cast), msg);
+
+        assert!(ksk.try_cast(&cipher).is_ok());
+    }
+
+    // There are no ServerKeys to return
+    assert_eq!(
+        ksk.clone().try_into_raw_parts().unwrap_err(),
+        KeySwitchError::ServerKeysNotStored
+    );
+    assert_eq!(
+        ksk.try_split_material().unwrap_err(),
+        KeySwitchError::ServerKeysNotStored
+    );
+}
+
+#[test]
+#[should_panic(expected = "requires the same moduli on both sides")]
+fn gen_multi_keys_test_keyswitch_only_moduli_mismatch_ci_run_filter() {
+    let ck1 = KEY_CACHE
+        .get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS)
+        .client_key()
+        .clone();
+    let ck2 = KEY_CACHE
+        .get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+        .client_key()
+        .clone();
+
+    let _ =
+        KeySwitchingKey::new_keyswitch_only((&ck1, &ck2), PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS);
+}
+
+#[test]
+#[should_panic(expected = "was built without ServerKeys")]
+fn gen_multi_keys_test_keyswitch_only_bootstrap_ci_run_filter() {
+    let ck1 = KEY_CACHE
+        .get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+        .client_key()
+        .clone();
+    let ck2 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+
+    let ksk = KeySwitchingKey::new_keyswitch_only((&ck1, &ck2), ksk_params);
+
+    let cipher = ck1.encrypt(1);
+    let mut output_of_cast = ksk.cast(&cipher);
+    ksk.cast_into_with_bootstrap(&cipher, &mut output_of_cast);
+}
+
+#[test]
+fn gen_multi_keys_test_without_dest_server_key_ci_run_filter() {
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+    );
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        ksk_params,
+    ));
+    let (ck1, sk1) = (keys.client_key_1(), keys.server_key_1());
+    let ck2 = keys.client_key_2();
+    let full_ksk = keys.key_switching_key();
+
+    let ksk = KeySwitchingKey::new_without_dest_server_key((ck1, sk1), ck2, ksk_params);
+    assert_eq!(ksk.cast_rshift(), full_ksk.cast_rshift());
+
+    // Only the source ServerKey is stored
+    assert!(ksk.serialized_size_bytes() < full_ksk.serialized_size_bytes());
+
+    for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+        let cipher = ck1.encrypt(msg);
+
+        let output_of_cast = ksk.cast(&cipher);
+        let expected = full_ksk.cast(&cipher);
+        assert_eq!(
+            ck2.decrypt_message_and_carry(&output_of_cast),
+            ck2.decrypt_message_and_carry(&expected),
+        );
+        assert_eq!(output_of_cast.degree, expected.degree);
+        assert_eq!(
+            output_of_cast.message_modulus,
+            ck2.parameters.message_modulus()
+        );
+        assert_eq!(output_of_cast.carry_modulus, ck2.parameters.carry_modulus());
+    }
+
+    // There is no destination ServerKey to return
+    assert_eq!(
+        ksk.clone().try_into_raw_parts().unwrap_err(),
+        KeySwitchError::DestServerKeyNotStored
+    );
+    assert_eq!(
+        ksk.try_split_material().unwrap_err(),
+        KeySwitchError::DestServerKeyNotStored
+    );
+}
+
+#[test]
+fn gen_multi_keys_test_without_dest_server_key_upcast_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let (ck1, sk1) = (keys.client_key_1(), keys.server_key_1());
+    let ck2 = keys.client_key_2();
+
+    assert_eq!(
+        KeySwitchingKey::try_new_without_dest_server_key(
+            (ck1, sk1),
+            ck2,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        )
+        .unwrap_err(),
+        KeySwitchError::MissingDestServerKey { cast_rshift: 2 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "was built without a destination ServerKey")]
+fn gen_multi_keys_test_without_dest_server_key_bootstrap_ci_run_filter() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (ck1, sk1) = (keys.client_key(), keys.server_key());
+    let ck2 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+
+    let ksk = KeySwitchingKey::new_without_dest_server_key((ck1, sk1), &ck2, ksk_params);
+
+    let cipher = ck1.encrypt(1);
+    let mut output_of_cast = ksk.cast(&cipher);
+    assert_eq!(ck2.decrypt(&output_of_cast), 1);
+
+    ksk.cast_into_with_bootstrap(&cipher, &mut output_of_cast);
+}
+
+#[test]
+fn gen_multi_keys_test_to_from_bytes_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let (ck1, sk1) = (keys.client_key_1(), keys.server_key_1());
+    let (ck2, sk2) = (keys.client_key_2(), keys.server_key_2());
+    let ksk = keys.key_switching_key();
+
+    let bytes = ksk.to_bytes();
+
+    // Check the documented header
+    let ksk_raw = &ksk.key_switching_key;
+    assert_eq!(&bytes[0..4], b"TKSK");
+    assert_eq!(bytes[4], 1);
+    assert_eq!(bytes[5] as i8, ksk.cast_rshift());
+    assert_eq!(
+        u64::from_le_bytes(bytes[6..14].try_into().unwrap()),
+        ksk_raw.decomposition_base_log().0 as u64
+    );
+    assert_eq!(
+        u64::from_le_bytes(bytes[14..22].try_into().unwrap()),
+        ksk_raw.decomposition_level_count().0 as u64
+    );
+    assert_eq!(
+        u64::from_le_bytes(bytes[22..30].try_into().unwrap()),
+        ksk_raw.input_key_lwe_dimension().0 as u64
+    );
+    assert_eq!(
+        u64::from_le_bytes(bytes[30..38].try_into().unwrap()),
+        ksk_raw.output_key_lwe_dimension().0 as u64
+    );
+    assert_eq!(u128::from_le_bytes(bytes[38..54].try_into().unwrap()), 0);
+    assert_eq!(bytes.len(), 54 + 8 * ksk_raw.as_ref().len());
+    assert_eq!(
+        u64::from_le_bytes(bytes[54..62].try_into().unwrap()),
+        ksk_raw.as_ref()[0]
+    );
+
+    let ksk_2 = KeySwitchingKey::from_bytes(&bytes, sk2.clone(), sk1.clone()).unwrap();
+    assert_eq!(&ksk_2, ksk);
+
+    let cipher = ck1.encrypt(1);
+    assert_eq!(ck2.decrypt(&ksk_2.cast(&cipher)), 1);
+
+    // Malformed inputs
+    assert_eq!(
+        KeySwitchingKey::from_bytes(&bytes[..bytes.len() - 1], sk2.clone(), sk1.clone()),
+        Err(KeySwitchingKeyBytesError::InvalidLength {
+            expected: bytes.len(),
+            got: bytes.len() - 1,
+        })
+    );
+
+    let mut bad_magic = bytes.clone();
+    bad_magic[0] ^= 1;
+    assert_eq!(
+        KeySwitchingKey::from_bytes(&bad_magic, sk2.clone(), sk1.clone()),
+        Err(KeySwitchingKeyBytesError::InvalidMagic)
+    );
+
+    let mut bad_version = bytes;
+    bad_version[4] = 2;
+    assert_eq!(
+        KeySwitchingKey::from_bytes(&bad_version, sk2.clone(), sk1.clone()),
+        Err(KeySwitchingKeyBytesError::UnsupportedVersion(2))
+    );
+}
+
+#[test]
+fn gen_multi_keys_test_from_bytes_malformed_ci_run_filter() {
+    const NB_TESTS: usize = 200;
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+    );
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        ksk_params,
+    ));
+    let (sk1, sk2) = (keys.server_key_1(), keys.server_key_2());
+    let ksk = keys.key_switching_key();
+
+    let bytes = ksk.to_bytes();
+    let from_bytes = |bytes: &[u8]| KeySwitchingKey::from_bytes(bytes, sk2.clone(), sk1.clone());
+
+    let with_u64_at = |offset: usize, value: u64| {
+        let mut bytes = bytes.clone();
+        bytes[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+        bytes
+    };
+
+    // Truncated headers
+    for len in 0..54 {
+        assert_eq!(
+            from_bytes(&bytes[..len]),
+            Err(KeySwitchingKeyBytesError::InvalidLength {
+                expected: 54,
+                got: len,
+            })
+        );
+    }
+
+    // Truncated or extended data
+    let mut extended = bytes.clone();
+    extended.extend_from_slice(&[0; 8]);
+    for malformed in [&bytes[..54], &bytes[..bytes.len() - 8], &extended] {
+        assert_eq!(
+            from_bytes(malformed),
+            Err(KeySwitchingKeyBytesError::InvalidLength {
+                expected: bytes.len(),
+                got: malformed.len(),
+            })
+        );
+    }
+
+    // Dimensions and decomposition parameters for which the data length overflows or which are
+    // invalid, they must be rejected before allocating anything
+    for malformed in [
+        with_u64_at(22, u64::MAX),
+        with_u64_at(30, u64::MAX),
+        with_u64_at(22, 1 << 62),
+        with_u64_at(30, 1 << 62),
+        with_u64_at(22, 0),
+        with_u64_at(30, 0),
+        with_u64_at(6, 0),
+        with_u64_at(14, 0),
+        with_u64_at(6, u64::MAX),
+        with_u64_at(14, 64),
+    ] {
+        assert_eq!(
+            from_bytes(&malformed),
+            Err(KeySwitchingKeyBytesError::InvalidParameters)
+        );
+    }
+
+    // Huge dimensions whose data length does not overflow
+    let huge = with_u64_at(22, 1 << 40);
+    assert!(matches!(
+        from_bytes(&huge),
+        Err(KeySwitchingKeyBytesError::InvalidLength { .. })
+    ));
+
+    // Well formed bytes which do not match the ServerKeys are an error instead of a panic
+    let mut bad_cast_rshift = bytes.clone();
+    bad_cast_rshift[5] = 1;
+    assert_eq!(
+        from_bytes(&bad_cast_rshift),
+        Err(KeySwitchingKeyBytesError::IncompatibleKeys(
+            KeySwitchError::CastRshiftMismatch {
+                cast_rshift: 1,
+                expected: 0,
+            }
+        ))
+    );
+
+    let mut bad_ciphertext_modulus = bytes.clone();
+    bad_ciphertext_modulus[38..54].copy_from_slice(&(1u128 << 63).to_le_bytes());
+    assert!(matches!(
+        from_bytes(&bad_ciphertext_modulus),
+        Err(KeySwitchingKeyBytesError::IncompatibleKeys(
+            KeySwitchError::KeyswitchKeyCiphertextModulusMismatch { .. }
+        ))
+    ));
+
+    // Random header corruptions must never panic, corruptions keeping the header well formed
+    // (e.g. of the decomposition base log) are only detected by the integrity digest
+    let mut rng = rand::thread_rng();
+    for _ in 0..NB_TESTS {
+        let mut corrupted = bytes.clone();
+        let nb_corrupted_bytes = rng.gen_range(1..=8);
+        for _ in 0..nb_corrupted_bytes {
+            corrupted[rng.gen_range(0..54)] ^= rng.gen_range(1..=u8::MAX);
+        }
+
+        if from_bytes(&corrupted).is_ok() {
+            assert_ne!(
+                KeySwitchingKey::integrity_digest_of_bytes(&corrupted),
+                ksk.integrity_digest()
+            );
+        }
+    }
+
+    // Fully random headers
+    for _ in 0..NB_TESTS {
+        let mut corrupted = bytes.clone();
+        rng.fill(&mut corrupted[5..54]);
+
+        let _ = from_bytes(&corrupted);
+    }
+}
+
+#[test]
+fn test_key_switching_parameters_try_for_ci_run_filter() {
+    let ksk_params = ShortintKeySwitchingParameters::try_for(
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    )
+    .unwrap();
+    assert_eq!(
+        ksk_params,
+        ShortintKeySwitchingParameters::new(
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+        )
+    );
+
+    assert_eq!(
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS.check_for(
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS
+        ),
+        Ok(())
+    );
+
+    let empty_ksk_params =
+        ShortintKeySwitchingParameters::new(DecompositionBaseLog(0), DecompositionLevelCount(4));
+    assert!(matches!(
+        empty_ksk_params.check_for(
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS
+        ),
+        Err(ShortintKeySwitchingParametersError::EmptyDecomposition { .. })
+    ));
+
+    let large_ksk_params =
+        ShortintKeySwitchingParameters::new(DecompositionBaseLog(8), DecompositionLevelCount(8));
+    assert_eq!(
+        large_ksk_params.check_for(
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS
+        ),
+        Err(ShortintKeySwitchingParametersError::DecompositionTooLarge {
+            decomposed_bits: 64,
+            max_decomposed_bits: 63,
+        })
+    );
+
+    // A custom power of two destination modulus limits the decomposition
+    let mut param_2_32 = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    param_2_32.ciphertext_modulus = CiphertextModulus::try_new_power_of_2(32).unwrap();
+    let ksk_params =
+        ShortintKeySwitchingParameters::new(DecompositionBaseLog(4), DecompositionLevelCount(9));
+    assert_eq!(
+        ksk_params.check_for(PARAM_MESSAGE_1_CARRY_1_KS_PBS, param_2_32),
+        Err(ShortintKeySwitchingParametersError::DecompositionTooLarge {
+            decomposed_bits: 36,
+            max_decomposed_bits: 32,
+        })
+    );
+
+    // Ciphertexts encrypted under the small key do not match the keyswitching key dimensions
+    let mut param_small = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    param_small.encryption_key_choice = EncryptionKeyChoice::Small;
+    let large_lwe_dimension = PARAM_MESSAGE_2_CARRY_2_KS_PBS
+        .glwe_dimension
+        .to_equivalent_lwe_dimension(PARAM_MESSAGE_2_CARRY_2_KS_PBS.polynomial_size);
+    assert_eq!(
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS
+            .check_for(param_small, PARAM_MESSAGE_2_CARRY_2_KS_PBS),
+        Err(
+            ShortintKeySwitchingParametersError::SrcLweDimensionMismatch {
+                ciphertext_lwe_dimension: param_small.lwe_dimension,
+                input_lwe_dimension: large_lwe_dimension,
+            }
+        )
+    );
+    assert_eq!(
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS
+            .check_for(PARAM_MESSAGE_1_CARRY_1_KS_PBS, param_small),
+        Err(
+            ShortintKeySwitchingParametersError::DestLweDimensionMismatch {
+                ciphertext_lwe_dimension: param_small.lwe_dimension,
+                output_lwe_dimension: large_lwe_dimension,
+            }
+        )
+    );
+
+    let mut param_empty = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    param_empty.glwe_dimension = GlweDimension(0);
+    assert_eq!(
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS
+            .check_for(PARAM_MESSAGE_1_CARRY_1_KS_PBS, param_empty),
+        Err(ShortintKeySwitchingParametersError::EmptyLweDimension {
+            input_lwe_dimension: PARAM_MESSAGE_1_CARRY_1_KS_PBS
+                .glwe_dimension
+                .to_equivalent_lwe_dimension(PARAM_MESSAGE_1_CARRY_1_KS_PBS.polynomial_size),
+            output_lwe_dimension: LweDimension(0),
+        })
+    );
+}
+
+#[test]
+fn gen_multi_keys_test_cast_roundtrip_random_ci_run_filter() {
+    const NB_TESTS: usize = 30;
+
+    let mut rng = rand::thread_rng();
+
+    // Same bit size, extension and truncation between power of two parameter sets
+    for (param_1, param_2) in [
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        ),
+    ] {
+        let ksk_params = ShortintKeySwitchingParameters::try_for(param_1, param_2).unwrap();
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let ck1 = keys.client_key_1();
+        let ck2 = keys.client_key_2();
+        let ksk = keys.key_switching_key();
+
+        let src_full_message_modulus =
+            (ck1.parameters.message_modulus().0 * ck1.parameters.carry_modulus().0) as u64;
+        let dest_full_message_modulus =
+            (ck2.parameters.message_modulus().0 * ck2.parameters.carry_modulus().0) as u64;
+
+        for _ in 0..NB_TESTS {
+            let msg = rng.gen_range(0..src_full_message_modulus);
+
+            let cipher = ck1.unchecked_encrypt(msg);
+            let output_of_cast = ksk.cast(&cipher);
+
+            // Bits that do not fit in the destination are truncated
+            assert_eq!(
+                ck2.decrypt_message_and_carry(&output_of_cast),
+                msg % dest_full_message_modulus,
+                "Invalid cast of {msg} from {param_1:?} to {param_2:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_cast_cost_ci_run_filter() {
+    // Same bit size, extension and truncation
+    for (param_1, param_2, ksk_params, expected_cost) in [
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+            ),
+            CastCost::KeyswitchOnly,
+        ),
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+            CastCost::DestPbs,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+            ),
+            CastCost::SrcPbs,
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let ksk = keys.key_switching_key();
+
+        let cost = ksk.cast_cost();
+        assert_eq!(cost, expected_cost);
+        assert_eq!(ksk.as_view().cast_cost(), expected_cost);
+        assert_eq!(cost.pbs_count(), usize::from(ksk.cast_rshift() != 0));
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_cast_into_dirty_destination_ci_run_filter() {
+    let mut rng = rand::thread_rng();
+
+    // Same bit size, extension and truncation
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+            ),
+        ),
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+            ),
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+        let sk2 = keys.server_key_2();
+        let ksk = keys.key_switching_key();
+
+        let dest_msg_modulus = ck2.parameters.message_modulus().0 as u64;
+
+        for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+            let cipher = ck1.encrypt(msg);
+
+            // A destination holding another message, with a larger degree and noise level than a
+            // fresh one and a garbage mask and body
+            let other_msg = (msg + 1) % dest_msg_modulus;
+            let mut dirty = ck2.encrypt(other_msg);
+            sk2.unchecked_add_assign(&mut dirty, &ck2.encrypt(other_msg));
+            dirty.ct.as_mut().iter_mut().for_each(|x| *x = rng.gen());
+
+            ksk.cast_into(&cipher, &mut dirty);
+
+            // The cast is deterministic, nothing from the previous contents remains
+            assert_eq!(dirty, ksk.cast(&cipher));
+            assert_eq!(ck2.decrypt(&dirty), msg % dest_msg_modulus);
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_cast_into_sets_dest_metadata_ci_run_filter() {
+    // Same bit size, extension and truncation
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+            ),
+        ),
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+            ),
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+        let (sk1, sk2) = (keys.server_key_1(), keys.server_key_2());
+        let ksk = keys.key_switching_key();
+
+        // Blocks of a radix ciphertext, with different degrees and noise levels
+        let blocks = [
+            ck1.encrypt(0),
+            ck1.encrypt(1),
+            sk1.unchecked_add(&ck1.encrypt(1), &ck1.encrypt(1)),
+        ];
+
+        // Destinations with the right LWE size but the metadata of other parameters
+        let mut cts_dest: Vec<_> = blocks
+            .iter()
+            .map(|_| {
+                let mut ct_dest = sk2.create_trivial(0);
+                ct_dest.message_modulus = ck1.parameters.message_modulus();
+                ct_dest.carry_modulus = ck1.parameters.carry_modulus();
+                ct_dest
+            })
+            .collect();
+
+        ksk.cast_into_slice(&blocks, &mut cts_dest);
+
+        for (block, ct_dest) in blocks.iter().zip(cts_dest.iter()) {
+            let (degree, noise_level) = ksk.cast_output_degree_and_noise_level(block);
+
+            assert_eq!(ct_dest.degree, degree);
+            assert_eq!(ct_dest.noise_level(), noise_level);
+            assert_eq!(ct_dest.message_modulus, ck2.parameters.message_modulus());
+            assert_eq!(ct_dest.carry_modulus, ck2.parameters.carry_modulus());
+            assert_eq!(ct_dest, &ksk.cast(block));
+            // The carries are cast along with the message
+            assert_eq!(
+                ck2.decrypt(ct_dest),
+                ck1.decrypt_message_and_carry(block) % ck2.parameters.message_modulus().0 as u64
+            );
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_debug_check_cast_ci_run_filter() {
+    // Extension: all messages fit in the destination
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+    let ksk = keys.key_switching_key();
+
+    for msg in 0..4 {
+        assert!(ksk.debug_check_cast(ck1, ck2, msg));
+    }
+
+    // Truncation: the messages which do not fit in the destination message modulus are reported
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        ShortintKeySwitchingParameters::new(
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+        ),
+    ));
+    let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+    let ksk = keys.key_switching_key();
+
+    for msg in 0..4 {
+        assert_eq!(ksk.debug_check_cast(ck1, ck2, msg), msg < 2);
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_serialized_size_bytes_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let full_ksk = keys.key_switching_key();
+
+    assert_eq!(
+        full_ksk.serialized_size_bytes(),
+        bincode::serialize(full_ksk).unwrap().len()
+    );
+
+    // A shared ServerKey is only stored once
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (ck, sk) = (keys.client_key(), keys.server_key());
+    let ck2 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+
+    let ksk = KeySwitchingKey::new_with_shared_server_key(ck, &ck2, sk, ksk_params);
+    assert_eq!(
+        ksk.serialized_size_bytes(),
+        bincode::serialize(&ksk).unwrap().len()
+    );
+
+    // Without ServerKeys only the keyswitching key is stored
+    let ksk_only = KeySwitchingKey::new_keyswitch_only((ck, &ck2), ksk_params);
+    assert_eq!(
+        ksk_only.serialized_size_bytes(),
+        bincode::serialize(&ksk_only).unwrap().len()
+    );
+    assert!(ksk_only.serialized_size_bytes() < ksk.serialized_size_bytes());
+}
+
+#[test]
+fn gen_multi_keys_test_serialization_versions_ci_run_filter() {
+    // The definition of the key when it was serialized without a version tag
+    #[derive(serde::Serialize)]
+    struct UnversionedKeySwitchingKey {
+        key_switching_key: LweKeyswitchKeyOwned<u64>,
+        dest_server_key: ServerKey,
+        src_server_key: ServerKey,
+        cast_rshift: i8,
+    }
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+    );
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        ksk_params,
+    ));
+    let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+    let ksk = keys.key_switching_key();
+
+    let (key_switching_key, dest_server_key, src_server_key, cast_rshift) =
+        ksk.clone().into_raw_parts();
+    let unversioned_bytes = bincode::serialize(&UnversionedKeySwitchingKey {
+        key_switching_key,
+        dest_server_key,
+        src_server_key,
+        cast_rshift,
+    })
+    .unwrap();
+
+    // An untagged key is rejected instead of being misread
+    assert!(bincode::deserialize::<KeySwitchingKey>(&unversioned_bytes).is_err());
+
+    let old_ksk: KeySwitchingKeyV0 = bincode::deserialize(&unversioned_bytes).unwrap();
+    assert_eq!(bincode::serialize(&old_ksk).unwrap(), unversioned_bytes);
+    drop(unversioned_bytes);
+
+    let ksk_2 = KeySwitchingKey::try_from(old_ksk.clone()).unwrap();
+    assert_eq!(&ksk_2, ksk);
+    assert_eq!(ck2.decrypt(&ksk_2.cast(&ck1.encrypt(1))), 1);
+    drop(ksk_2);
+
+    // The integer key has the layout of the shortint key it wraps
+    let integer_ksk =
+        crate::integer::key_switching_key::KeySwitchingKey::try_from(old_ksk.clone()).unwrap();
+    assert_eq!(&integer_ksk.into_raw_parts(), ksk);
+
+    // The old key is checked when converting it
+    let mut old_ksk = old_ksk;
+    old_ksk.cast_rshift = 1;
+    assert_eq!(
+        KeySwitchingKey::try_from(old_ksk),
+        Err(KeySwitchError::CastRshiftMismatch {
+            cast_rshift: 1,
+            expected: 0,
+        })
+    );
+
+    // The versioned layout round trips whatever ServerKeys are stored
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let (ck, sk) = (keys.client_key(), keys.server_key());
+    for ksk in [
+        ksk.clone(),
+        KeySwitchingKey::new_with_shared_server_key(ck, ck2, sk, ksk_params),
+        KeySwitchingKey::new_keyswitch_only((ck, ck2), ksk_params),
+        KeySwitchingKey::new_without_dest_server_key((ck, sk), ck2, ksk_params),
+    ] {
+        let bytes = bincode::serialize(&ksk).unwrap();
+        let ksk_2: KeySwitchingKey = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(ksk_2, ksk);
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_builder_ci_run_filter() {
+    // Extension and truncation, cast_rshift is recovered from the ServerKeys
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+            ),
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+        let ksk = keys.key_switching_key();
+
+        let (raw_ksk, dest_sk, src_sk, _) = ksk.clone().into_raw_parts();
+
+        let built = KeySwitchingKeyBuilder::new()
+            .key_switching_key(raw_ksk)
+            .src_server_key(src_sk)
+            .dest_server_key(dest_sk)
+            .build()
+            .unwrap();
+
+        assert_eq!(&built, ksk);
+
+        let msg = 1;
+        let cipher = ck1.encrypt(msg);
+        assert_eq!(ck2.decrypt(&built.cast(&cipher)), msg);
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_builder_errors_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ksk = keys.key_switching_key();
+
+    let (raw_ksk, dest_sk, src_sk, _) = ksk.clone().into_raw_parts();
+
+    assert_eq!(
+        KeySwitchingKeyBuilder::new()
+            .src_server_key(src_sk.clone())
+            .dest_server_key(dest_sk.clone())
+            .build(),
+        Err(KeySwitchingKeyBuilderError::MissingKeySwitchingKey)
+    );
+    assert_eq!(
+        KeySwitchingKeyBuilder::new()
+            .key_switching_key(raw_ksk.clone())
+            .dest_server_key(dest_sk.clone())
+            .build(),
+        Err(KeySwitchingKeyBuilderError::MissingSrcServerKey)
+    );
+    assert_eq!(
+        KeySwitchingKeyBuilder::new()
+            .key_switching_key(raw_ksk.clone())
+            .src_server_key(src_sk.clone())
+            .build(),
+        Err(KeySwitchingKeyBuilderError::MissingDestServerKey)
+    );
+
+    // The parts are checked as in from_raw_parts
+    let mut dest_sk_non_power_of_two = dest_sk.clone();
+    dest_sk_non_power_of_two.ciphertext_modulus =
+        CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+    assert_eq!(
+        KeySwitchingKeyBuilder::new()
+            .key_switching_key(raw_ksk.clone())
+            .src_server_key(src_sk.clone())
+            .dest_server_key(dest_sk_non_power_of_two.clone())
+            .build(),
+        Err(KeySwitchingKeyBuilderError::InvalidParts(
+            KeySwitchError::IncompatibleCiphertextModuli {
+                src_server_key: src_sk.ciphertext_modulus,
+                dest_server_key: dest_sk_non_power_of_two.ciphertext_modulus,
+            }
+        ))
+    );
+
+    assert_eq!(
+        KeySwitchingKeyBuilder::new()
+            .key_switching_key(raw_ksk.clone())
+            .src_server_key(dest_sk.clone())
+            .dest_server_key(src_sk)
+            .build(),
+        Err(KeySwitchingKeyBuilderError::InvalidParts(
+            KeySwitchError::KeyswitchKeyInputLweDimensionMismatch {
+                src_server_key: dest_sk.ciphertext_lwe_dimension(),
+                key_switching_key: raw_ksk.input_key_lwe_dimension(),
+            }
+        ))
+    );
+}
+
+#[test]
+fn gen_multi_keys_test_validate_raw_parts_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ksk = keys.key_switching_key();
+
+    let (raw_ksk, dest_sk, src_sk, _) = ksk.clone().into_raw_parts();
+
+    assert_eq!(
+        KeySwitchingKey::validate_raw_parts(&raw_ksk, &dest_sk, &src_sk),
+        Ok(())
+    );
+
+    assert_eq!(
+        KeySwitchingKey::validate_raw_parts(&raw_ksk, &src_sk, &dest_sk),
+        Err(KeySwitchError::KeyswitchKeyInputLweDimensionMismatch {
+            src_server_key: dest_sk.ciphertext_lwe_dimension(),
+            key_switching_key: raw_ksk.input_key_lwe_dimension(),
+        })
+    );
+
+    assert_eq!(
+        KeySwitchingKey::validate_raw_parts(&raw_ksk, &src_sk, &src_sk),
+        Err(KeySwitchError::KeyswitchKeyOutputLweDimensionMismatch {
+            dest_server_key: src_sk.ciphertext_lwe_dimension(),
+            key_switching_key: raw_ksk.output_key_lwe_dimension(),
+        })
+    );
+
+    // Power of two moduli can be mixed but the LweKeyswitchKey must use the destination one
+    let mut dest_sk_2_62 = dest_sk.clone();
+    dest_sk_2_62.ciphertext_modulus = CiphertextModulus::try_new_power_of_2(62).unwrap();
+    assert_eq!(
+        KeySwitchingKey::validate_raw_parts(&raw_ksk, &dest_sk_2_62, &src_sk),
+        Err(KeySwitchError::KeyswitchKeyCiphertextModulusMismatch {
+            dest_server_key: dest_sk_2_62.ciphertext_modulus,
+            key_switching_key: raw_ksk.ciphertext_modulus(),
+        })
+    );
+
+    // but the destination one cannot be bigger than the source one
+    let mut src_sk_2_62 = src_sk.clone();
+    src_sk_2_62.ciphertext_modulus = CiphertextModulus::try_new_power_of_2(62).unwrap();
+    assert_eq!(
+        KeySwitchingKey::validate_raw_parts(&raw_ksk, &dest_sk, &src_sk_2_62),
+        Err(KeySwitchError::IncompatibleCiphertextModuli {
+            src_server_key: src_sk_2_62.ciphertext_modulus,
+            dest_server_key: dest_sk.ciphertext_modulus,
+        })
+    );
+
+    let mut dest_sk_non_power_of_two = dest_sk;
+    dest_sk_non_power_of_two.ciphertext_modulus =
+        CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+    assert_eq!(
+        KeySwitchingKey::validate_raw_parts(&raw_ksk, &dest_sk_non_power_of_two, &src_sk),
+        Err(KeySwitchError::IncompatibleCiphertextModuli {
+            src_server_key: src_sk.ciphertext_modulus,
+            dest_server_key: dest_sk_non_power_of_two.ciphertext_modulus,
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "and the LweKeyswitchKey input LweDimension")]
+fn gen_multi_keys_test_from_raw_parts_invalid_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+
+    let (raw_ksk, dest_sk, src_sk, cast_rshift) = keys.key_switching_key().clone().into_raw_parts();
+
+    let _ = KeySwitchingKey::from_raw_parts(raw_ksk, src_sk, dest_sk, cast_rshift);
+}
+
+#[test]
+fn gen_multi_keys_test_from_raw_parts_cast_rshift_magnitude_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+
+    let (raw_ksk, dest_sk, src_sk, cast_rshift) = keys.key_switching_key().clone().into_raw_parts();
+    assert_eq!(cast_rshift, 2);
+
+    let try_from_raw_parts = |cast_rshift| {
+        KeySwitchingKey::try_from_raw_parts(
+            raw_ksk.clone(),
+            dest_sk.clone(),
+            src_sk.clone(),
+            cast_rshift,
+        )
+    };
+
+    assert!(try_from_raw_parts(cast_rshift).is_ok());
+
+    // In range shifts must still match the moduli of the ServerKeys
+    for wrong_cast_rshift in [-1, 0, 1, 3] {
+        assert_eq!(
+            try_from_raw_parts(wrong_cast_rshift),
+            Err(KeySwitchError::CastRshiftMismatch {
+                cast_rshift: wrong_cast_rshift,
+                expected: cast_rshift,
+            })
+        );
+    }
+
+    // Positive shifts are applied in the 4 bits dest full message modulus, negative ones in the 2
+    // bits source full message modulus
+
+    for (cast_rshift, full_message_modulus_nb_bits) in [(4, 4), (i8::MAX, 4), (-2, 2), (i8::MIN, 2)]
+    {
+        assert_eq!(
+            try_from_raw_parts(cast_rshift),
+            Err(KeySwitchError::CastRshiftOutOfRange {
+                cast_rshift,
+                full_message_modulus_nb_bits,
+            })
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "of the full message modulus it is applied in")]
+fn gen_multi_keys_test_from_raw_parts_cast_rshift_too_large_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+
+    let (raw_ksk, dest_sk, src_sk, _) = keys.key_switching_key().clone().into_raw_parts();
+
+    let _ = KeySwitchingKey::from_raw_parts(raw_ksk, dest_sk, src_sk, 8);
+}
+
+#[test]
+fn gen_multi_keys_test_split_material_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ck1 = keys.client_key_1();
+    let ck2 = keys.client_key_2();
+    let ksk = keys.key_switching_key();
+
+    let (material, dest_sk, src_sk) = ksk.clone().split_material();
+    assert_eq!(material.cast_rshift, ksk.cast_rshift());
+    assert_eq!(material.key_switching_key(), &ksk.key_switching_key);
+
+    // The material alone is much smaller than the full key
+    let serialized_material = bincode::serialize(&material).unwrap();
+    assert!(serialized_material.len() < bincode::serialize(ksk).unwrap().len());
+
+    let material: KeySwitchingKeyMaterial = bincode::deserialize(&serialized_material).unwrap();
+
+    // Swapped ServerKeys are rejected
+    assert!(matches!(
+        KeySwitchingKey::try_from_material(material.clone(), src_sk.clone(), dest_sk.clone()),
+        Err(KeySwitchError::KeyswitchKeyInputLweDimensionMismatch { .. })
+    ));
+
+    let reassembled_ksk = KeySwitchingKey::from_material(material, dest_sk, src_sk);
+    assert_eq!(&reassembled_ksk, ksk);
+
+    for msg in 0..2 {
+        let cipher = ck1.encrypt(msg);
+        assert_eq!(ck2.decrypt(&reassembled_ksk.cast(&cipher)), msg);
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_chain_ci_run_filter() {
+    let keys_1_2 = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ksk_params_2_3 = ShortintKeySwitchingParameters::try_for(
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    )
+    .unwrap();
+    let keys_2_3 = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+        ksk_params_2_3,
+    ));
+    let ck1 = keys_1_2.client_key_1();
+    let ck2 = keys_1_2.client_key_2();
+    let ck3 = keys_2_3.client_key_2();
+
+    let chain = KeySwitchingKeyChain::new(vec![
+        keys_1_2.key_switching_key().clone(),
+        keys_2_3.key_switching_key().clone(),
+    ])
+    .unwrap();
+    assert_eq!(chain.keys().len(), 2);
+
+    let src_full_message_modulus =
+        (ck1.parameters.message_modulus().0 * ck1.parameters.carry_modulus().0) as u64;
+
+    for msg in 0..src_full_message_modulus {
+        let cipher = ck1.unchecked_encrypt(msg);
+
+        let output_of_cast = chain.cast(&cipher);
+        assert_eq!(ck3.decrypt_message_and_carry(&output_of_cast), msg);
+
+        let output_of_cast = chain.try_cast(&cipher).unwrap();
+        assert_eq!(ck3.decrypt_message_and_carry(&output_of_cast), msg);
+    }
+
+    // The input must match the source of the first key
+    assert!(matches!(
+        chain.try_cast(&ck2.encrypt(0)),
+        Err(KeySwitchError::LweDimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn gen_multi_keys_test_chain_incompatible_keys_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ksk = keys.key_switching_key();
+
+    assert_eq!(
+        KeySwitchingKeyChain::new(vec![]),
+        Err(KeySwitchingKeyChainError::EmptyChain)
+    );
+
+    // The output of a 1_1 -> 2_2 key cannot be cast by another 1_1 -> 2_2 key
+    assert_eq!(
+        KeySwitchingKeyChain::new(vec![ksk.clone(), ksk.clone()]),
+        Err(KeySwitchingKeyChainError::LweDimensionMismatch {
+            step: 0,
+            output: keys.server_key_2().ciphertext_lwe_dimension(),
+            expected: keys.server_key_1().ciphertext_lwe_dimension(),
+        })
+    );
+
+    // A key built without ServerKeys is checked on its moduli
+    let ck2 = keys.client_key_2();
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+    let keyswitch_only_ksk = KeySwitchingKey::new_keyswitch_only((ck2, ck2), ksk_params);
+
+    let chain = KeySwitchingKeyChain::new(vec![ksk.clone(), keyswitch_only_ksk]).unwrap();
+    let cipher = keys.client_key_1().encrypt(1);
+    assert_eq!(ck2.decrypt(&chain.cast(&cipher)), 1);
+}
+
+#[test]
+fn gen_multi_keys_test_chain_auto_refresh_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ksk_1_2 = keys.key_switching_key();
+    let ck1 = keys.client_key_1();
+    let (ck2, sk2) = (keys.client_key_2(), keys.server_key_2());
+
+    // The ServerKey is shared to limit the memory used by the chains
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+    let ksk_2_2 = KeySwitchingKey::new_with_shared_server_key(ck2, ck2, sk2, ksk_params);
+    assert_eq!(ksk_2_2.cast_cost(), CastCost::KeyswitchOnly);
+
+    let chain = KeySwitchingKeyChain::new(vec![ksk_2_2.clone(); 4]).unwrap();
+    assert!(chain.refresh_steps().is_empty());
+
+    for msg in 0..ck2.parameters.message_modulus().0 as u64 {
+        // A noise level above nominal, kept by the keyswitch only casts
+        let cipher = sk2.unchecked_add(&ck2.encrypt(msg), &ck2.encrypt(0));
+
+        let output_of_cast = chain.cast(&cipher);
+        assert_eq!(output_of_cast.noise_level(), NoiseLevel::NOMINAL * 2);
+        assert_eq!(ck2.decrypt(&output_of_cast), msg);
+    }
+
+    // Long enough to trigger a refresh in the middle and at the end of the chain
+    let chain = chain.with_auto_refresh(1).unwrap();
+    assert_eq!(chain.refresh_steps(), &[1, 3]);
+
+    for msg in 0..ck2.parameters.message_modulus().0 as u64 {
+        for _ in 0..2 {
+            let cipher = sk2.unchecked_add(&ck2.encrypt(msg), &ck2.encrypt(0));
+
+            let output_of_cast = chain.cast(&cipher);
+            assert_eq!(output_of_cast.noise_level(), NoiseLevel::NOMINAL);
+            assert_eq!(ck2.decrypt(&output_of_cast), msg);
+        }
+    }
+
+    // A new budget replaces the previous refresh steps
+    let chain = chain.with_auto_refresh(0).unwrap();
+    assert_eq!(chain.refresh_steps(), &[0, 1, 2, 3]);
+    let chain = chain.with_auto_refresh(2).unwrap();
+    assert_eq!(chain.refresh_steps(), &[2]);
+    let chain = chain.with_auto_refresh(4).unwrap();
+    assert!(chain.refresh_steps().is_empty());
+    drop(chain);
+
+    // The bootstrap of the cast to a bigger full message modulus resets the count
+    let mixed_keys = vec![ksk_1_2.clone(), ksk_2_2.clone(), ksk_2_2.clone(), ksk_2_2];
+    let chain = KeySwitchingKeyChain::new(mixed_keys).unwrap();
+    let chain = chain.with_auto_refresh(0).unwrap();
+    assert_eq!(chain.refresh_steps(), &[1, 2, 3]);
+    let chain = chain.with_auto_refresh(2).unwrap();
+    assert_eq!(chain.refresh_steps(), &[3]);
+
+    for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+        let output_of_cast = chain.try_cast(&ck1.encrypt(msg)).unwrap();
+        assert_eq!(output_of_cast.noise_level(), NoiseLevel::NOMINAL);
+        assert_eq!(ck2.decrypt(&output_of_cast), msg);
+    }
+    drop(chain);
+
+    // A refresh needs the destination ServerKey
+    let keyswitch_only_ksk = KeySwitchingKey::new_keyswitch_only((ck2, ck2), ksk_params);
+    let chain = KeySwitchingKeyChain::new(vec![ksk_1_2.clone(), keyswitch_only_ksk]).unwrap();
+    assert_eq!(
+        chain.clone().with_auto_refresh(0),
+        Err(KeySwitchingKeyChainError::MissingDestServerKey { step: 1 })
+    );
+    let chain = chain.with_auto_refresh(1).unwrap();
+    assert!(chain.refresh_steps().is_empty());
+}
+
+#[test]
+fn gen_multi_keys_test_new_with_engine_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+    let (sk1, sk2) = (keys.server_key_1(), keys.server_key_2());
+
+    let new_ksk = |seed| {
+        let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(Seed(seed));
+        let mut engine = ShortintEngine::new_from_seeder(&mut seeder);
+
+        KeySwitchingKey::new_with_engine(
+            &mut engine,
+            (ck1, sk1),
+            (ck2, sk2),
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        )
+    };
+
+    let ksk = new_ksk(0);
+
+    // The casting key only depends on the seed of the engine
+    assert_eq!(ksk, new_ksk(0));
+    assert_ne!(ksk, new_ksk(1));
+
+    for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+        let cipher = ck1.encrypt(msg);
+        assert_eq!(ck2.decrypt(&ksk.cast(&cipher)), msg);
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_try_constructors_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let (ck1, sk1) = (keys.client_key_1(), keys.server_key_1());
+    let (ck2, sk2) = (keys.client_key_2(), keys.server_key_2());
+
+    assert_eq!(
+        KeySwitchingKey::try_new_keyswitch_only(
+            (ck1, ck2),
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS
+        )
+        .unwrap_err(),
+        KeySwitchError::KeyswitchOnlyModuliMismatch {
+            src: (
+                ck1.parameters.message_modulus(),
+                ck1.parameters.carry_modulus()
+            ),
+            dest: (
+                ck2.parameters.message_modulus(),
+                ck2.parameters.carry_modulus()
+            ),
+        }
+    );
+
+    assert_eq!(
+        KeySwitchingKey::try_new_with_shared_server_key(
+            ck1,
+            ck2,
+            sk2,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        )
+        .unwrap_err(),
+        KeySwitchError::SharedServerKeyParametersMismatch
+    );
+
+    assert_eq!(
+        KeySwitchingKey::try_new_refresh(
+            (ck1, sk1),
+            (ck2, sk2),
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        )
+        .unwrap_err(),
+        KeySwitchError::RefreshParametersMismatch
+    );
+
+    // Mismatched key pair with the same ClientKey parameters on both sides
+    assert_eq!(
+        KeySwitchingKey::try_new_refresh(
+            (ck1, sk1),
+            (ck1, sk2),
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        )
+        .unwrap_err(),
+        KeySwitchError::DestKeyPairMismatch
+    );
+
+    // Mismatched source key pair
+    assert_eq!(
+        KeySwitchingKey::try_new_without_dest_server_key(
+            (ck1, sk2),
+            ck2,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        )
+        .unwrap_err(),
+        KeySwitchError::SrcKeyPairMismatch
+    );
+
+    let (raw_ksk, dest_sk, src_sk, cast_rshift) = keys.key_switching_key().clone().into_raw_parts();
+
+    // Swapped ServerKeys
+    assert!(matches!(
+        KeySwitchingKey::try_from_raw_parts(
+            raw_ksk.clone(),
+            src_sk.clone(),
+            dest_sk.clone(),
+            cast_rshift
+        ),
+        Err(KeySwitchError::KeyswitchKeyInputLweDimensionMismatch { .. })
+    ));
+
+    let ksk = KeySwitchingKey::try_from_raw_parts(raw_ksk, dest_sk, src_sk, cast_rshift).unwrap();
+    assert_eq!(&ksk, keys.key_switching_key());
+
+    // Valid inputs build the same keys as the panicking constructors
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+    let ksk = KeySwitchingKey::try_new_keyswitch_only((ck2, ck2), ksk_params).unwrap();
+    let cipher = ck2.encrypt(3);
+    assert_eq!(ck2.decrypt(&ksk.cast(&cipher)), 3);
+
+    let ksk = KeySwitchingKey::try_new_with_shared_server_key(ck2, ck2, sk2, ksk_params).unwrap();
+    assert_eq!(ck2.decrypt(&ksk.cast(&cipher)), 3);
+}
+
+#[test]
+fn gen_multi_keys_test_cast_blocks_degree_and_noise_level_ci_run_filter() {
+    // Same bit size, extension and truncation
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+            ),
+        ),
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+            ),
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let (ck1, sk1) = (keys.client_key_1(), keys.server_key_1());
+        let ksk = keys.key_switching_key();
+
+        // A fresh block, and blocks with a larger degree and noise level
+        let fresh = ck1.encrypt(1);
+        let added = sk1.unchecked_add(&fresh, &ck1.encrypt(1));
+        let added_twice = sk1.unchecked_add(&added, &ck1.encrypt(0));
+        let blocks = [fresh, added, added_twice];
+
+        let metadata: Vec<_> = blocks
+            .iter()
+            .map(|block| (block.degree, block.noise_level()))
+            .collect();
+        let cast_metadata = ksk.cast_blocks_degree_and_noise_level(&metadata);
+
+        assert_eq!(cast_metadata.len(), blocks.len());
+        for (block, metadata) in blocks.iter().zip(cast_metadata) {
+            let cast_block = ksk.cast(block);
+            assert_eq!((cast_block.degree, cast_block.noise_level()), metadata);
+            assert_eq!(ksk.cast_output_degree_and_noise_level(block), metadata);
+        }
+    }
+
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    assert!(keys
+        .key_switching_key()
+        .cast_blocks_degree_and_noise_level(&[])
+        .is_empty());
+}
+
+#[test]
+#[should_panic(expected = "The source ServerKey was not generated from the source ClientKey")]
+fn gen_multi_keys_test_mismatched_key_pair_ci_run_filter() {
+    let keys1 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let keys2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (ck1, sk1) = (keys1.client_key(), keys1.server_key());
+    let (ck2, sk2) = (keys2.client_key(), keys2.server_key());
+
+    // The server keys are swapped between the two pairs
+    let _ = KeySwitchingKey::new(
+        (ck1, sk2),
+        (ck2, sk1),
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    );
+}
+
+#[test]
+fn gen_multi_keys_test_cast_compressed_ci_run_filter() {
+    // Same bit size, extension and truncation
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+            ),
+        ),
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+            ),
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+        let ksk = keys.key_switching_key();
+
+        let dest_msg_modulus = ck2.parameters.message_modulus().0 as u64;
+
+        for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+            let compressed_cipher = ck1.encrypt_compressed(msg);
+
+            let output_of_cast = ksk.cast_compressed(&compressed_cipher);
+            assert_eq!(ck2.decrypt(&output_of_cast), msg % dest_msg_modulus);
+
+            // Same output as casting the decompressed ciphertext
+            assert_eq!(output_of_cast, ksk.cast(&compressed_cipher.decompress()));
+        }
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_cast_lookup_table_cache_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+
+    // Start from a key whose lookup table was never generated
+    let (raw_ksk, dest_sk, src_sk, cast_rshift) = keys.key_switching_key().clone().into_raw_parts();
+    let ksk = KeySwitchingKey::from_raw_parts(raw_ksk, dest_sk, src_sk, cast_rshift);
+    assert!(ksk.cast_lookup_table.0.get().is_none());
+
+    // The first casts happen concurrently, all of them must use the same lookup table
+    let msgs: Vec<u64> = (0..8).map(|i| i % 2).collect();
+    let outputs: Vec<Ciphertext> = std::thread::scope(|s| {
+        let handles: Vec<_> = msgs
+            .iter()
+            .map(|&msg| {
+                let ksk = &ksk;
+                s.spawn(move || ksk.cast(&ck1.encrypt(msg)))
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    for (output, msg) in outputs.iter().zip(msgs) {
+        assert_eq!(ck2.decrypt(output), msg);
+    }
+
+    let cached = ksk.cast_lookup_table.0.get().unwrap().as_ref().unwrap();
+    assert_eq!(cached, &ksk.as_view().generate_cast_lookup_table().unwrap());
+
+    // Later casts reuse the cached table
+    let output = ksk.par_cast(&[ck1.encrypt(1)]);
+    assert_eq!(ck2.decrypt(&output[0]), 1);
+    assert!(std::ptr::eq(
+        ksk.cast_lookup_table.0.get().unwrap().as_ref().unwrap(),
+        cached
+    ));
+
+    // The cache is not part of the key value
+    assert_eq!(&ksk, keys.key_switching_key());
+    let serialized = bincode::serialize(&ksk).unwrap();
+    let deserialized: KeySwitchingKey = bincode::deserialize(&serialized).unwrap();
+    assert!(deserialized.cast_lookup_table.0.get().is_none());
+    assert_eq!(deserialized, ksk);
+
+    // A keyswitch only cast caches the absence of lookup table
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        ck2.parameters.ks_base_log(),
+        ck2.parameters.ks_level(),
+    );
+    let keyswitch_only_ksk = KeySwitchingKey::new_keyswitch_only((ck2, ck2), ksk_params);
+    let _ = keyswitch_only_ksk.cast(&ck2.encrypt(1));
+    assert!(keyswitch_only_ksk
+        .cast_lookup_table
+        .0
+        .get()
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn gen_multi_keys_test_cast_lwe_ciphertext_ci_run_filter() {
+    // Same bit size, extension and truncation
+    for (param_1, param_2, ksk_params) in [
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+            ),
+        ),
+        (
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            ShortintKeySwitchingParameters::new(
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+                PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+            ),
+        ),
+    ] {
+        let keys = KEY_CACHE_KSK.get_from_param((param_1, param_2, ksk_params));
+        let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+        let (sk1, sk2) = (keys.server_key_1(), keys.server_key_2());
+        let ksk = keys.key_switching_key();
+
+        let rshift = ksk.cast_rshift();
+        let dest_full_message_modulus =
+            (ck2.parameters.message_modulus().0 * ck2.parameters.carry_modulus().0) as u64;
+        let dest_msg_modulus = ck2.parameters.message_modulus().0 as u64;
+
+        // The shift is applied with the keys of the side having the bigger full message modulus
+        let (bootstrap_server_key, accumulator) = if rshift >= 0 {
+            (sk2, sk2.generate_lookup_table(|x| x >> rshift))
+        } else {
+            (
+                sk1,
+                sk1.generate_lookup_table(|x| (x % dest_full_message_modulus) << -rshift),
+            )
+        };
+        let ShortintBootstrappingKey::Classic(fourier_bsk) =
+            &bootstrap_server_key.bootstrapping_key
+        else {
+            unreachable!()
+        };
+
+        for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+            let cipher = ck1.encrypt(msg);
+
+            let mut output_of_cast = sk2.create_trivial(0);
+            ksk.cast_lwe_ciphertext(
+                &cipher.ct,
+                &mut output_of_cast.ct,
+                &bootstrap_server_key.key_switching_key,
+                fourier_bsk,
+                &accumulator.acc,
+            );
+
+            assert_eq!(ck2.decrypt(&output_of_cast), msg % dest_msg_modulus);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Mismatch between the input ciphertext moduli")]
+fn gen_multi_keys_test_cast_mismatched_input_moduli_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ck1 = keys.client_key_1();
+    let ksk = keys.key_switching_key();
+
+    // Same LWE dimension as the source ciphertexts, only the moduli differ
+    let mut cipher = ck1.encrypt(1);
+    cipher.message_modulus = MessageModulus(4);
+
+    let _ = ksk.cast(&cipher);
+}
+
+#[test]
+fn gen_multi_keys_test_cast_lookup_table_ci_run_filter() {
+    // Same bit size: only a keyswitch
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        ShortintKeySwitchingParameters::new(
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level,
+        ),
+    ));
+    assert_eq!(keys.key_switching_key().cast_rshift(), 0);
+    assert!(keys.key_switching_key().cast_lookup_table().is_none());
+
+    // Extension: decoding of the source messages applied with the dest server key
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let ksk = keys.key_switching_key();
+    assert_eq!(ksk.cast_rshift(), 2);
+    let lookup_table = ksk.cast_lookup_table().unwrap();
+    assert_eq!(lookup_table.degree.get(), 3);
+
+    let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+    for msg in 0..4 {
+        let cipher = ck1.unchecked_encrypt(msg);
+        let mut cipher_2 = keys.server_key_2().create_trivial(0);
+        keyswitch_lwe_ciphertext(&ksk.key_switching_key, &cipher.ct, &mut cipher_2.ct);
+        keys.server_key_2()
+            .apply_lookup_table_assign(&mut cipher_2, lookup_table);
+
+        assert_eq!(ck2.decrypt_message_and_carry(&cipher_2), msg);
+    }
+
+    // Truncation: reduction and multiplication applied with the src server key
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        ShortintKeySwitchingParameters::new(
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+        ),
+    ));
+    let ksk = keys.key_switching_key();
+    assert_eq!(ksk.cast_rshift(), -2);
+    assert_eq!(
+        ksk.cast_lookup_table(),
+        Some(&keys.server_key_1().generate_lookup_table(|n| (n % 4) << 2))
+    );
+
+    // The returned lookup table is the one applied by the cast
+    let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+    for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+        let mut cipher = ck1.encrypt(msg);
+        keys.server_key_1()
+            .apply_lookup_table_assign(&mut cipher, ksk.cast_lookup_table().unwrap());
+
+        let mut cipher_2 = keys.server_key_2().create_trivial(0);
+        keyswitch_lwe_ciphertext(&ksk.key_switching_key, &cipher.ct, &mut cipher_2.ct);
+
+        assert_eq!(
+            ck2.decrypt(&cipher_2),
+            ck2.decrypt(&ksk.cast(&ck1.encrypt(msg)))
+        );
+    }
+}
+
+#[test]
+fn gen_multi_keys_test_integrity_digest_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let (sk1, sk2) = (keys.server_key_1(), keys.server_key_2());
+    let ksk = keys.key_switching_key();
+
+    let digest = ksk.integrity_digest();
+    let bytes = ksk.to_bytes();
+
+    // Same digest for the key and its serialized form, kept through a round trip
+    assert_eq!(KeySwitchingKey::integrity_digest_of_bytes(&bytes), digest);
+    let ksk_2 = KeySwitchingKey::from_bytes(&bytes, sk2.clone(), sk1.clone()).unwrap();
+    assert_eq!(ksk_2.integrity_digest(), digest);
+
+    // FNV-1a 64 reference values
+    assert_eq!(
+        KeySwitchingKey::integrity_digest_of_bytes(b""),
+        0xcbf2_9ce4_8422_2325
+    );
+    assert_eq!(
+        KeySwitchingKey::integrity_digest_of_bytes(b"a"),
+        0xaf63_dc4c_8601_ec8c
+    );
+
+    // A change in the header or in the keyswitching material changes the digest
+    let mut corrupted_header = bytes.clone();
+    corrupted_header[5] ^= 1;
+    assert_ne!(
+        KeySwitchingKey::integrity_digest_of_bytes(&corrupted_header),
+        digest
+    );
+
+    let mut corrupted_bytes = bytes;
+    let last = corrupted_bytes.len() - 1;
+    corrupted_bytes[last] ^= 0x80;
+    assert_ne!(
+        KeySwitchingKey::integrity_digest_of_bytes(&corrupted_bytes),
+        digest
+    );
+}
+
+#[test]
+fn test_full_message_modulus_helpers_large_moduli_ci_run_filter() {
+    use super::{full_message_modulus, full_message_modulus_nb_bits, scale_and_round};
+
+    assert_eq!(
+        full_message_modulus(MessageModulus(1 << 20), CarryModulus(1 << 20)),
+        1 << 40
+    );
+    assert_eq!(full_message_modulus_nb_bits(1 << 40), 40);
+    // Rounded up for moduli which are not a power of 2
+    assert_eq!(full_message_modulus_nb_bits(3), 2);
+    assert_eq!(full_message_modulus_nb_bits(1), 0);
+
+    // Would overflow a u64 intermediate value
+    let (src, dest) = (1u64 << 40, 1u64 << 50);
+    for value in [0, 1, (1 << 40) - 1] {
+        assert_eq!(scale_and_round(value, dest, src), value << 10);
+        assert_eq!(scale_and_round(value << 10, src, dest), value);
+    }
+    // Rounding to the closest value
+    assert_eq!(scale_and_round((3 << 10) + 511, src, dest), 3);
+    assert_eq!(scale_and_round((3 << 10) + 512, src, dest), 4);
+}
+
+#[test]
+fn test_try_cast_rshift_from_moduli_boundary_ci_run_filter() {
+    use super::try_cast_rshift_from_moduli;
+
+    let small = (MessageModulus(1), CarryModulus(1));
+    // 2^63 is the largest power of 2 full message modulus fitting in a u64
+    let largest = (MessageModulus(1 << 32), CarryModulus(1 << 31));
+    let too_large = (MessageModulus(1 << 32), CarryModulus(1 << 32));
+
+    assert_eq!(try_cast_rshift_from_moduli(small, largest), Ok(63));
+    assert_eq!(try_cast_rshift_from_moduli(largest, small), Ok(-63));
+    assert_eq!(try_cast_rshift_from_moduli(largest, largest), Ok(0));
+
+    let dest_err = try_cast_rshift_from_moduli(small, too_large).unwrap_err();
+    assert_eq!(
+        dest_err,
+        KeySwitchError::DestFullMessageModulusOverflow {
+            message_modulus: too_large.0,
+            carry_modulus: too_large.1,
+        }
+    );
+    assert!(dest_err.to_string().contains("destination"));
+
+    let src_err = try_cast_rshift_from_moduli(too_large, small).unwrap_err();
+    assert_eq!(
+        src_err,
+        KeySwitchError::SrcFullMessageModulusOverflow {
+            message_modulus: too_large.0,
+            carry_modulus: too_large.1,
+        }
+    );
+    assert!(src_err.to_string().contains("source"));
+
+    // The source is checked first
+    assert_eq!(
+        try_cast_rshift_from_moduli(too_large, too_large),
+        Err(src_err)
+    );
+}
+
+#[test]
+#[should_panic(expected = "does not fit in a u64")]
+fn test_full_message_modulus_overflow_ci_run_filter() {
+    let _ = super::full_message_modulus(MessageModulus(1 << 40), CarryModulus(1 << 40));
+}
+
+#[test]
+fn gen_multi_keys_test_key_switching_key_pair_ci_run_filter() {
+    let keys = KEY_CACHE_KSK.get_from_param((
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    ));
+    let (ck1, ck2) = (keys.client_key_1(), keys.client_key_2());
+    let (sk1, sk2) = (keys.server_key_1(), keys.server_key_2());
+
+    let ksk_pair = KeySwitchingKeyPair::new(
+        (ck1, sk1),
+        (ck2, sk2),
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+        ShortintKeySwitchingParameters::new(
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+        ),
+    );
+
+    assert_eq!(ksk_pair.a_to_b().cast_rshift(), 2);
+    assert_eq!(ksk_pair.b_to_a().cast_rshift(), -2);
+
+    // Round trip through the parameters b
+    for msg in 0..ck1.parameters.message_modulus().0 as u64 {
+        let cipher = ck1.encrypt(msg);
+        let cipher_2 = ksk_pair.cast_a_to_b(&cipher);
+        assert_eq!(ck2.decrypt(&cipher_2), msg);
+
+        let cipher_1 = ksk_pair.cast_b_to_a(&cipher_2);
+        assert_eq!(ck1.decrypt(&cipher_1), msg);
+    }
+
+    // Only the bits fitting in the full message modulus of a are kept when casting from b
+    for msg in 0..ck2.parameters.message_modulus().0 as u64 {
+        let cipher = ck2.encrypt(msg);
+        let cipher_1 = ksk_pair.cast_b_to_a(&cipher);
+        assert_eq!(ck1.decrypt_message_and_carry(&cipher_1), msg % 4);
+    }
+
+    // Both keys share the same ServerKeys, also after a serialization round trip
+    let assert_shared_server_keys = |ksk_pair: &KeySwitchingKeyPair| match (
+        &ksk_pair.a_to_b().server_keys,
+        &ksk_pair.b_to_a().server_keys,
+    ) {
+        (
+            CastServerKeys::Distinct { src, dest },
+            CastServerKeys::Distinct {
+                src: src_2,
+                dest: dest_2,
+            },
+        ) => {
+            assert!(Arc::ptr_eq(src, dest_2));
+            assert!(Arc::ptr_eq(dest, src_2));
+        }
+        _ => panic!("Expected both keys to store distinct ServerKeys"),
+    };
+    assert_shared_server_keys(&ksk_pair);
+
+    let bytes = bincode::serialize(&ksk_pair).unwrap();
+    let unshared_size = bincode::serialize(ksk_pair.a_to_b()).unwrap().len()
+        + bincode::serialize(ksk_pair.b_to_a()).unwrap().len();
+    assert!(bytes.len() < unshared_size);
+
+    let ksk_pair_2: KeySwitchingKeyPair = bincode::deserialize(&bytes).unwrap();
+    assert_shared_server_keys(&ksk_pair_2);
+    assert_eq!(ksk_pair_2, ksk_pair);
+
+    let cipher_2 = ksk_pair_2.cast_a_to_b(&ck1.encrypt(1));
+    assert_eq!(ck1.decrypt(&ksk_pair_2.cast_b_to_a(&cipher_2)), 1);
+
+    // The keyswitching key from b to a must match the ServerKeys of the key from a to b
+    let wrong_bytes =
+        bincode::serialize(&(ksk_pair.a_to_b(), &ksk_pair.a_to_b().key_switching_key)).unwrap();
+    assert!(bincode::deserialize::<KeySwitchingKeyPair>(&wrong_bytes).is_err());
+}
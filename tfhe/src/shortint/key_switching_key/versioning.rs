@@ -0,0 +1,130 @@
+//! This module defines the serialized layouts of KeySwitchingKey
+//!
+//! - [KeySwitchingKey](`super::KeySwitchingKey`) is serialized with a version tag, so that its
+//!   layout can change without breaking the keys serialized by previous versions.
+//! - [KeySwitchingKeyV0] is the untagged layout of the keys serialized before the tag was added.
+
+use super::{CastServerKeys, KeySwitchError, KeySwitchingKey};
+use crate::core_crypto::prelude::LweKeyswitchKeyOwned;
+use crate::shortint::ServerKey;
+use serde::{Deserialize, Serialize};
+
+/// The layout of a [`KeySwitchingKey`] serialized by versions of this crate which always stored
+/// both [`ServerKey`]s and did not tag the serialized key with a version.
+///
+/// As there is no tag such a key cannot be deserialized as a [`KeySwitchingKey`] directly: it is
+/// deserialized as a [`KeySwitchingKeyV0`] and then converted, which checks the parts with
+/// [`KeySwitchingKey::try_from_raw_parts`]. An integer
+/// [`KeySwitchingKey`](`crate::integer::key_switching_key::KeySwitchingKey`) serialized by those
+/// versions has the same layout.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::key_switching_key::KeySwitchingKeyV0;
+/// use tfhe::shortint::parameters::{
+///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+/// };
+/// use tfhe::shortint::{gen_keys, KeySwitchingKey};
+///
+/// // Generate the client keys and server keys:
+/// let (ck1, sk1) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+/// let (ck2, sk2) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// let ksk = KeySwitchingKey::new(
+///     (&ck1, &sk1),
+///     (&ck2, &sk2),
+///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+/// );
+///
+/// // The bytes a previous version would have produced for this key
+/// let (key_switching_key, dest_server_key, src_server_key, cast_rshift) =
+///     ksk.clone().into_raw_parts();
+/// let old_bytes = bincode::serialize(&KeySwitchingKeyV0 {
+///     key_switching_key,
+///     dest_server_key,
+///     src_server_key,
+///     cast_rshift,
+/// })
+/// .unwrap();
+///
+/// let old_ksk: KeySwitchingKeyV0 = bincode::deserialize(&old_bytes).unwrap();
+/// let ksk_2 = KeySwitchingKey::try_from(old_ksk).unwrap();
+///
+/// assert_eq!(ksk_2, ksk);
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KeySwitchingKeyV0 {
+    pub key_switching_key: LweKeyswitchKeyOwned<u64>,
+    pub dest_server_key: ServerKey,
+    pub src_server_key: ServerKey,
+    pub cast_rshift: i8,
+}
+
+impl TryFrom<KeySwitchingKeyV0> for KeySwitchingKey {
+    type Error = KeySwitchError;
+
+    fn try_from(value: KeySwitchingKeyV0) -> Result<Self, Self::Error> {
+        let KeySwitchingKeyV0 {
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+            cast_rshift,
+        } = value;
+
+        Self::try_from_raw_parts(
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+            cast_rshift,
+        )
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "KeySwitchingKeyVersions")]
+enum KeySwitchingKeyVersionsRef<'a> {
+    V1 {
+        key_switching_key: &'a LweKeyswitchKeyOwned<u64>,
+        server_keys: &'a CastServerKeys,
+    },
+}
+
+/// The versions of the serialized layout of a [`KeySwitchingKey`], the variants must stay in the
+/// same order for the tags of already serialized keys to keep their meaning.
+#[derive(Deserialize)]
+enum KeySwitchingKeyVersions {
+    V1 {
+        key_switching_key: LweKeyswitchKeyOwned<u64>,
+        server_keys: CastServerKeys,
+    },
+}
+
+impl Serialize for KeySwitchingKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        KeySwitchingKeyVersionsRef::V1 {
+            key_switching_key: &self.key_switching_key,
+            server_keys: &self.server_keys,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KeySwitchingKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match KeySwitchingKeyVersions::deserialize(deserializer)? {
+            KeySwitchingKeyVersions::V1 {
+                key_switching_key,
+                server_keys,
+            } => Self::try_from_server_keys(key_switching_key, server_keys)
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
@@ -62,7 +62,12 @@ pub mod wopbs;
 
 pub use ciphertext::{Ciphertext, CompressedCiphertext, PBSOrder};
 pub use client_key::ClientKey;
-pub use key_switching_key::KeySwitchingKey;
+pub use key_switching_key::{
+    CastBuffer, CastCost, CastLookupTableCache, KeySwitchError, KeySwitchingKey,
+    KeySwitchingKeyBuilder, KeySwitchingKeyBuilderError, KeySwitchingKeyBytesError,
+    KeySwitchingKeyChain, KeySwitchingKeyChainError, KeySwitchingKeyMaterial, KeySwitchingKeyPair,
+    KeySwitchingKeyV0, KeySwitchingKeyView, SeededKeySwitchingKey,
+};
 pub use parameters::{
     CarryModulus, CiphertextModulus, ClassicPBSParameters, EncryptionKeyChoice, MessageModulus,
     MultiBitPBSParameters, PBSParameters, ShortintParameterSet, WopbsParameters,
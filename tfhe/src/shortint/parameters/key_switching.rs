@@ -1,4 +1,7 @@
-use crate::shortint::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use crate::shortint::parameters::{
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, EncryptionKeyChoice,
+    LweDimension, ShortintParameterSet,
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -24,6 +27,249 @@ impl ShortintKeySwitchingParameters {
             ks_level,
         }
     }
+
+    /// Return the keyswitch parameters of the destination parameter set, after checking they can
+    /// be used to cast from `src_params` to `dest_params`, see
+    /// [`ShortintKeySwitchingParameters::check_for`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    ///
+    /// let ksk_params = ShortintKeySwitchingParameters::try_for(
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(ksk_params.ks_base_log, PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_base_log);
+    /// assert_eq!(ksk_params.ks_level, PARAM_MESSAGE_2_CARRY_2_KS_PBS.ks_level);
+    /// ```
+    pub fn try_for<P1, P2>(
+        src_params: P1,
+        dest_params: P2,
+    ) -> Result<Self, ShortintKeySwitchingParametersError>
+    where
+        P1: Into<ShortintParameterSet>,
+        P2: Into<ShortintParameterSet>,
+    {
+        let dest_params: ShortintParameterSet = dest_params.into();
+        let ksk_params = Self::new(dest_params.ks_base_log(), dest_params.ks_level());
+
+        ksk_params.check_for(src_params, dest_params)?;
+
+        Ok(ksk_params)
+    }
+
+    /// Check that these parameters yield a valid keyswitching key to cast from `src_params` to
+    /// `dest_params`.
+    ///
+    /// The keyswitching key is encrypted under the destination
+    /// [`CiphertextModulus`](`crate::shortint::parameters::CiphertextModulus`), the decomposition
+    /// must therefore be non empty and fit in it, and both moduli must be powers of two if they
+    /// differ.
+    ///
+    /// The keyswitching key switches from the large LWE secret key of the source to the one of the
+    /// destination, i.e. from and to the [`LweDimension`] `glwe_dimension * polynomial_size` of
+    /// each parameter set. Both must be non zero and the ciphertexts of each side must be
+    /// encrypted under that key, with [`EncryptionKeyChoice::Big`].
+    pub fn check_for<P1, P2>(
+        &self,
+        src_params: P1,
+        dest_params: P2,
+    ) -> Result<(), ShortintKeySwitchingParametersError>
+    where
+        P1: Into<ShortintParameterSet>,
+        P2: Into<ShortintParameterSet>,
+    {
+        let src_params: ShortintParameterSet = src_params.into();
+        let dest_params: ShortintParameterSet = dest_params.into();
+        let src_ciphertext_modulus = src_params.ciphertext_modulus();
+        let dest_ciphertext_modulus = dest_params.ciphertext_modulus();
+
+        if self.ks_base_log.0 == 0 || self.ks_level.0 == 0 {
+            return Err(ShortintKeySwitchingParametersError::EmptyDecomposition {
+                ks_base_log: self.ks_base_log,
+                ks_level: self.ks_level,
+            });
+        }
+
+        if !dest_ciphertext_modulus.is_compatible_with_native_modulus()
+            || (src_ciphertext_modulus != dest_ciphertext_modulus
+                && !src_ciphertext_modulus.is_compatible_with_native_modulus())
+        {
+            return Err(
+                ShortintKeySwitchingParametersError::UnsupportedCiphertextModulus {
+                    src_ciphertext_modulus,
+                    dest_ciphertext_modulus,
+                },
+            );
+        }
+
+        // The decomposer requires strictly less decomposed bits than the integer size
+        let max_decomposed_bits = if dest_ciphertext_modulus.is_native_modulus() {
+            u64::BITS as usize - 1
+        } else {
+            dest_ciphertext_modulus.get_custom_modulus().ilog2() as usize
+        };
+        let decomposed_bits = self.ks_base_log.0 * self.ks_level.0;
+
+        if decomposed_bits > max_decomposed_bits {
+            return Err(ShortintKeySwitchingParametersError::DecompositionTooLarge {
+                decomposed_bits,
+                max_decomposed_bits,
+            });
+        }
+
+        let input_lwe_dimension = large_lwe_dimension(&src_params);
+        let output_lwe_dimension = large_lwe_dimension(&dest_params);
+
+        if input_lwe_dimension.0 == 0 || output_lwe_dimension.0 == 0 {
+            return Err(ShortintKeySwitchingParametersError::EmptyLweDimension {
+                input_lwe_dimension,
+                output_lwe_dimension,
+            });
+        }
+
+        let src_ciphertext_lwe_dimension = ciphertext_lwe_dimension(&src_params);
+        if src_ciphertext_lwe_dimension != input_lwe_dimension {
+            return Err(
+                ShortintKeySwitchingParametersError::SrcLweDimensionMismatch {
+                    ciphertext_lwe_dimension: src_ciphertext_lwe_dimension,
+                    input_lwe_dimension,
+                },
+            );
+        }
+
+        let dest_ciphertext_lwe_dimension = ciphertext_lwe_dimension(&dest_params);
+        if dest_ciphertext_lwe_dimension != output_lwe_dimension {
+            return Err(
+                ShortintKeySwitchingParametersError::DestLweDimensionMismatch {
+                    ciphertext_lwe_dimension: dest_ciphertext_lwe_dimension,
+                    output_lwe_dimension,
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// [`LweDimension`] of the large LWE secret key of a parameter set, which the keyswitching key of
+/// a cast switches from or to.
+fn large_lwe_dimension(params: &ShortintParameterSet) -> LweDimension {
+    params
+        .glwe_dimension()
+        .to_equivalent_lwe_dimension(params.polynomial_size())
+}
+
+/// [`LweDimension`] of the ciphertexts encrypted with a parameter set.
+fn ciphertext_lwe_dimension(params: &ShortintParameterSet) -> LweDimension {
+    match params.encryption_key_choice() {
+        EncryptionKeyChoice::Big => large_lwe_dimension(params),
+        EncryptionKeyChoice::Small => params.lwe_dimension(),
+    }
+}
+
+/// Error returned when [`ShortintKeySwitchingParameters`] cannot be used to cast between two
+/// parameter sets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShortintKeySwitchingParametersError {
+    /// The decomposition base log or level count is 0
+    EmptyDecomposition {
+        ks_base_log: DecompositionBaseLog,
+        ks_level: DecompositionLevelCount,
+    },
+    /// The keyswitch does not support the source and destination ciphertext moduli
+    UnsupportedCiphertextModulus {
+        src_ciphertext_modulus: CiphertextModulus,
+        dest_ciphertext_modulus: CiphertextModulus,
+    },
+    /// The decomposition has more bits than the destination ciphertext modulus supports
+    DecompositionTooLarge {
+        decomposed_bits: usize,
+        max_decomposed_bits: usize,
+    },
+    /// The input or output [`LweDimension`] of the keyswitching key is 0
+    EmptyLweDimension {
+        input_lwe_dimension: LweDimension,
+        output_lwe_dimension: LweDimension,
+    },
+    /// The source ciphertexts do not have the input [`LweDimension`] of the keyswitching key
+    SrcLweDimensionMismatch {
+        ciphertext_lwe_dimension: LweDimension,
+        input_lwe_dimension: LweDimension,
+    },
+    /// The destination ciphertexts do not have the output [`LweDimension`] of the keyswitching key
+    DestLweDimensionMismatch {
+        ciphertext_lwe_dimension: LweDimension,
+        output_lwe_dimension: LweDimension,
+    },
+}
+
+impl std::error::Error for ShortintKeySwitchingParametersError {}
+
+impl std::fmt::Display for ShortintKeySwitchingParametersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyDecomposition {
+                ks_base_log,
+                ks_level,
+            } => write!(
+                f,
+                "Empty keyswitch decomposition: {ks_base_log:?} and {ks_level:?} must both be \
+                greater than 0"
+            ),
+            Self::UnsupportedCiphertextModulus {
+                src_ciphertext_modulus,
+                dest_ciphertext_modulus,
+            } => write!(
+                f,
+                "Keyswitching from CiphertextModulus {src_ciphertext_modulus:?} to \
+                CiphertextModulus {dest_ciphertext_modulus:?} is not supported, \
+                only power of two moduli are supported"
+            ),
+            Self::DecompositionTooLarge {
+                decomposed_bits,
+                max_decomposed_bits,
+            } => write!(
+                f,
+                "The keyswitch decomposition uses {decomposed_bits} bits (base log * level), \
+                at most {max_decomposed_bits} bits are supported for the destination \
+                CiphertextModulus"
+            ),
+            Self::EmptyLweDimension {
+                input_lwe_dimension,
+                output_lwe_dimension,
+            } => write!(
+                f,
+                "The keyswitching key would switch from {input_lwe_dimension:?} to \
+                {output_lwe_dimension:?}, both must be greater than 0"
+            ),
+            Self::SrcLweDimensionMismatch {
+                ciphertext_lwe_dimension,
+                input_lwe_dimension,
+            } => write!(
+                f,
+                "The source ciphertexts have {ciphertext_lwe_dimension:?} while the keyswitching \
+                key takes inputs of {input_lwe_dimension:?}, the source parameters must encrypt \
+                with EncryptionKeyChoice::Big"
+            ),
+            Self::DestLweDimensionMismatch {
+                ciphertext_lwe_dimension,
+                output_lwe_dimension,
+            } => write!(
+                f,
+                "The destination ciphertexts have {ciphertext_lwe_dimension:?} while the \
+                keyswitching key outputs {output_lwe_dimension:?}, the destination parameters \
+                must encrypt with EncryptionKeyChoice::Big"
+            ),
+        }
+    }
 }
 
 pub const PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS: ShortintKeySwitchingParameters =
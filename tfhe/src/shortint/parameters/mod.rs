@@ -24,7 +24,10 @@ pub mod parameters_wopbs_message_carry;
 pub(crate) mod parameters_wopbs_prime_moduli;
 
 pub use crate::core_crypto::commons::parameters::EncryptionKeyChoice;
-pub use key_switching::ShortintKeySwitchingParameters;
+pub use key_switching::{
+    ShortintKeySwitchingParameters, ShortintKeySwitchingParametersError,
+    PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+};
 pub use multi_bit::*;
 pub use parameters_compact_pk::*;
 pub use parameters_wopbs::WopbsParameters;